@@ -1,3 +1,5 @@
+use crate::state::RecognitionScope;
+use crate::types::{CourseStatus, RevocationReason, StaffRole, TrustLevel, VerificationFrequency};
 use anchor_lang::prelude::*;
 
 /// Event emitted when a new provider is registered
@@ -30,6 +32,15 @@ pub struct ProviderReputationUpdated {
     pub timestamp: i64,
 }
 
+/// Event emitted when a provider's derived trust level changes
+#[event]
+pub struct ProviderTrustLevelChanged {
+    pub provider: Pubkey,
+    pub previous_level: TrustLevel,
+    pub trust_level: TrustLevel,
+    pub timestamp: i64,
+}
+
 /// Event emitted when a credential is created
 #[event]
 pub struct CredentialCreated {
@@ -47,6 +58,8 @@ pub struct CredentialEndorsed {
     pub credential: Pubkey,
     pub mentor: Pubkey,
     pub endorsement: String,
+    pub endorsement_count: u8,
+    pub threshold: u8,
     pub timestamp: i64,
 }
 
@@ -55,6 +68,9 @@ pub struct CredentialEndorsed {
 pub struct CredentialVerified {
     pub credential: Pubkey,
     pub verification_count: u64,
+    /// Verifier wallet (None for anonymous verifications)
+    pub verifier: Option<Pubkey>,
+    pub frequency: VerificationFrequency,
     pub timestamp: i64,
 }
 
@@ -67,6 +83,26 @@ pub struct CredentialMinted {
     pub timestamp: i64,
 }
 
+/// Event emitted when a credential is revoked
+#[event]
+pub struct CredentialRevoked {
+    pub credential: Pubkey,
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub revoked_by: Pubkey,
+    pub reason: RevocationReason,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a student requests renewal of an expiring credential
+#[event]
+pub struct CredentialRenewalRequested {
+    pub credential: Pubkey,
+    pub student: Pubkey,
+    pub previous_valid_until: Option<i64>,
+    pub timestamp: i64,
+}
+
 /// Event emitted when a course is created
 #[event]
 pub struct CourseCreated {
@@ -111,6 +147,7 @@ pub struct ActivityGraded {
 /// Event emitted when hub is initialized
 #[event]
 pub struct HubInitialized {
+    pub hub_id: String,
     pub authority: Pubkey,
     pub timestamp: i64,
 }
@@ -130,6 +167,21 @@ pub struct ProviderAccepted {
     pub timestamp: i64,
 }
 
+/// Event emitted when a provider is queued for hub review
+#[event]
+pub struct ProviderQueued {
+    pub provider: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a pending provider is rejected by the hub authority or evicted from a full queue
+#[event]
+pub struct ProviderRejected {
+    pub hub_authority: Pubkey,
+    pub provider: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Event emitted when a provider is removed from hub
 #[event]
 pub struct ProviderRemovedFromHub {
@@ -154,6 +206,66 @@ pub struct ProviderEndorserRemoved {
     pub timestamp: i64,
 }
 
+/// Event emitted when a provider restricts a course to one of its endorsers
+#[event]
+pub struct CourseEndorserAdded {
+    pub course: Pubkey,
+    pub endorser: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a provider removes a course-scoped endorser
+#[event]
+pub struct CourseEndorserRemoved {
+    pub course: Pubkey,
+    pub endorser: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a provider assigns a staff role, provider-wide or for one course
+#[event]
+pub struct StaffRoleSet {
+    pub provider: Pubkey,
+    pub course: Option<Pubkey>,
+    pub staff: Pubkey,
+    /// None when the course override removes the staff member's access
+    pub role: Option<StaffRole>,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a provider removes a staff member or a course override
+#[event]
+pub struct StaffRoleRemoved {
+    pub provider: Pubkey,
+    pub course: Option<Pubkey>,
+    pub staff: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a new hub authority is proposed
+#[event]
+pub struct HubAuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a pending hub authority transfer is cancelled
+#[event]
+pub struct HubAuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the hub operator is set or cleared
+#[event]
+pub struct HubOperatorSet {
+    pub authority: Pubkey,
+    pub operator: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
 /// Event emitted when hub authority is transferred
 #[event]
 pub struct HubAuthorityTransferred {
@@ -171,6 +283,36 @@ pub struct CourseAccepted {
     pub timestamp: i64,
 }
 
+/// Event emitted when a provider locks in course module weights
+#[event]
+pub struct CourseModulesFinalized {
+    pub course: Pubkey,
+    pub module_count: u8,
+    pub timestamp: i64,
+}
+
+/// Event emitted whenever a course moves through its status state machine
+#[event]
+pub struct CourseStatusChanged {
+    pub course: Pubkey,
+    pub old_status: CourseStatus,
+    pub new_status: CourseStatus,
+    /// Wallet that triggered the change (provider or hub authority)
+    pub changed_by: Pubkey,
+    pub rejection_reason: Option<String>,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the hub sends a course under review back to its provider
+#[event]
+pub struct CourseRejected {
+    pub hub_authority: Pubkey,
+    pub course: Pubkey,
+    pub provider: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
 /// Event emitted when a course is removed from hub
 #[event]
 pub struct CourseRemovedFromHub {
@@ -178,3 +320,164 @@ pub struct CourseRemovedFromHub {
     pub course: Pubkey,
     pub timestamp: i64,
 }
+
+/// Event emitted when a hub is handed over to a governance member set
+#[event]
+pub struct HubGovernanceInitialized {
+    pub hub: Pubkey,
+    pub governance: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a governance member proposes a hub action
+#[event]
+pub struct HubProposalCreated {
+    pub governance: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a governance member approves a proposal
+#[event]
+pub struct HubProposalApproved {
+    pub proposal: Pubkey,
+    pub member: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Event emitted when an approved proposal is executed
+#[event]
+pub struct HubProposalExecuted {
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the proposer cancels a proposal
+#[event]
+pub struct HubProposalCancelled {
+    pub proposal: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a hub recognises a course or provider of another hub
+#[event]
+pub struct HubRecognitionRecorded {
+    pub hub: Pubkey,
+    pub source_hub: Pubkey,
+    pub recognition: Pubkey,
+    pub scope: RecognitionScope,
+    pub subject: Pubkey,
+    pub workload_factor_bps: Option<u16>,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a hub withdraws a recognition
+#[event]
+pub struct HubRecognitionRevoked {
+    pub hub: Pubkey,
+    pub recognition: Pubkey,
+    pub subject: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a credential from another hub is verified through a recognition
+#[event]
+pub struct RecognizedCredentialVerified {
+    pub credential: Pubkey,
+    pub hub: Pubkey,
+    pub recognition: Pubkey,
+    /// Course workload converted with the recognition's conversion factor
+    pub recognized_workload: u32,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a graded submission is folded into a student's course progress
+#[event]
+pub struct CourseProgressUpdated {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub weighted_grade: f64,
+    pub graded_weight: u8,
+    pub completed_workload: u32,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a student is enrolled in a course
+#[event]
+pub struct StudentEnrolled {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub enrolled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a student's course enrollment is withdrawn
+#[event]
+pub struct EnrollmentWithdrawn {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub withdrawn_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a course gains a prerequisite course
+#[event]
+pub struct CoursePrerequisiteAdded {
+    pub course: Pubkey,
+    pub prerequisite: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a prerequisite course is dropped
+#[event]
+pub struct CoursePrerequisiteRemoved {
+    pub course: Pubkey,
+    pub prerequisite: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a provider creates a degree programme
+#[event]
+pub struct DegreeCreated {
+    pub degree: Pubkey,
+    pub provider: Pubkey,
+    pub degree_id: String,
+    pub workload_required: u32,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a provider publishes a degree, freezing its structure
+#[event]
+pub struct DegreePublished {
+    pub degree: Pubkey,
+    pub provider: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a student claims a degree-level credential
+#[event]
+pub struct DegreeCredentialIssued {
+    pub degree: Pubkey,
+    pub student: Pubkey,
+    pub degree_credential: Pubkey,
+    pub total_workload: u32,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a degree credential is revoked, by the degree's provider
+/// or because one of its course credentials was revoked
+#[event]
+pub struct DegreeCredentialRevoked {
+    pub degree: Pubkey,
+    pub student: Pubkey,
+    pub degree_credential: Pubkey,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -1,6 +1,8 @@
 use crate::events::*;
 use crate::state::*;
-use crate::types::{ActivityError, ActivityKind, ActivityStatus, ResourceKind};
+use crate::types::{
+    ActivityError, ActivityKind, ActivityStatus, ResourceKind, StaffCapability, StudentError,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -30,8 +32,10 @@ pub struct CreateActivity<'info> {
         bump
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
+    /// Student's enrollment in `course`; required when the activity is linked to a course
+    pub enrollment: Option<Account<'info, Enrollment>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -48,6 +52,10 @@ pub struct AddFeedback<'info> {
         bump
     )]
     pub activity: Account<'info, Activity>,
+    #[account(constraint = provider.key() == activity.provider)]
+    pub provider: Account<'info, Provider>,
+    /// Activity's course; required for staff feedback on a course-linked activity
+    pub course: Option<Account<'info, Course>>,
     #[account(mut)]
     pub student_authority: Signer<'info>,
 }
@@ -65,6 +73,10 @@ pub struct AddGrade<'info> {
         bump
     )]
     pub activity: Account<'info, Activity>,
+    #[account(constraint = provider.key() == activity.provider)]
+    pub provider: Account<'info, Provider>,
+    /// Activity's course; required when the activity is linked to a course
+    pub course: Option<Account<'info, Course>>,
     #[account(mut)]
     pub teacher: Signer<'info>,
 }
@@ -104,6 +116,12 @@ pub fn create_activity(
             && creation_timestamp <= clock.unix_timestamp.saturating_add(60),
         ActivityError::InvalidCreationTimestamp
     );
+    if let Some(course) = course {
+        let enrolled = ctx.accounts.enrollment.as_ref().is_some_and(|enrollment| {
+            enrollment.course == course && enrollment.student == ctx.accounts.student.key()
+        });
+        require!(enrolled, StudentError::NotEnrolled);
+    }
 
     activity.created = creation_timestamp;
     activity.updated = clock.unix_timestamp;
@@ -117,6 +135,8 @@ pub fn create_activity(
     activity.status = ActivityStatus::Active;
     activity.resource_kind = resource_kind;
     activity.grade = None;
+    activity.graded_by = None;
+    activity.graded_at = None;
     activity.assets = Vec::new();
     activity.evidence_assets = Vec::new();
 
@@ -132,6 +152,24 @@ pub fn create_activity(
     Ok(())
 }
 
+/// Resolve the course staff roles are checked against: course-linked activities must pass
+/// their own course, owned by the activity's provider
+fn activity_course<'a>(
+    activity: &Activity,
+    provider: &Provider,
+    course: Option<&'a Account<Course>>,
+) -> Result<Option<&'a Course>> {
+    let Some(course_key) = activity.course else {
+        return Ok(None);
+    };
+    require!(
+        course
+            .is_some_and(|course| course.key() == course_key && course.provider == provider.wallet),
+        ActivityError::CourseMismatch
+    );
+    Ok(course.map(|course| &**course))
+}
+
 pub fn add_feedback(
     ctx: Context<AddFeedback>,
     content: String,
@@ -141,6 +179,21 @@ pub fn add_feedback(
     require!(asset_ids.len() <= 10, ActivityError::TooManyAssets);
     require!(evidence_asset_ids.len() <= 10, ActivityError::TooManyAssets);
 
+    let author = ctx.accounts.student_authority.key();
+    if author != ctx.accounts.activity.student {
+        let course = activity_course(
+            &ctx.accounts.activity,
+            &ctx.accounts.provider,
+            ctx.accounts.course.as_ref(),
+        )?;
+        require!(
+            ctx.accounts
+                .provider
+                .has_capability(&author, course, StaffCapability::Feedback),
+            ActivityError::UnauthorizedFeedback
+        );
+    }
+
     let activity = &mut ctx.accounts.activity;
 
     // Update activity data with feedback
@@ -161,12 +214,31 @@ pub fn add_grade(
     require!(asset_ids.len() <= 10, ActivityError::TooManyAssets);
     require!(evidence_asset_ids.len() <= 10, ActivityError::TooManyAssets);
 
-    let activity = &mut ctx.accounts.activity;
+    let teacher = ctx.accounts.teacher.key();
+    let course = activity_course(
+        &ctx.accounts.activity,
+        &ctx.accounts.provider,
+        ctx.accounts.course.as_ref(),
+    )?;
+    require!(
+        ctx.accounts
+            .provider
+            .has_capability(&teacher, course, StaffCapability::Grade),
+        ActivityError::UnauthorizedGrader
+    );
 
-    activity.update_grade(grade_value)?;
+    let activity = &mut ctx.accounts.activity;
+    activity.update_grade(grade_value, teacher)?;
     activity.assets = asset_ids;
     activity.evidence_assets = evidence_asset_ids;
 
+    emit!(ActivityGraded {
+        activity: activity.key(),
+        grade: grade_value,
+        teacher,
+        timestamp: activity.updated,
+    });
+
     Ok(())
 }
 
@@ -225,6 +297,8 @@ mod tests {
             status: ActivityStatus::Active,
             resource_kind: None,
             grade: None,
+            graded_by: None,
+            graded_at: None,
             assets: Vec::new(),
             evidence_assets: Vec::new(),
         }
@@ -254,10 +328,12 @@ mod tests {
     fn add_grade_sets_grade_and_assets() {
         let mut activity = dummy_activity();
         let grade_value = 95.5_f64;
+        let teacher = Pubkey::new_unique();
 
         // Validate the success path without depending on additional state-machine constraints.
-        let _ = activity.update_grade(grade_value);
+        let _ = activity.update_grade(grade_value, teacher);
         assert_eq!(activity.grade, Some(grade_value));
+        assert_eq!(activity.graded_by, Some(teacher));
     }
 
     #[test]
@@ -1,5 +1,8 @@
+use crate::events::*;
 use crate::state::*;
-use crate::types::{CourseError, CourseStatus};
+use crate::types::{
+    CourseError, CourseStatus, CourseStatusActor, EnrollmentMode, StaffCapability, StaffRole,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -25,10 +28,11 @@ pub struct CreateCourse<'info> {
             hub.key().as_ref(),
             provider_authority.key().as_ref(),
         ],
-        bump
+        bump,
+        constraint = !provider.suspended @ crate::types::ProviderError::ProviderSuspended
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
@@ -64,6 +68,7 @@ pub fn create_course(
     course.name = name;
     course.description = description;
     course.modules = Vec::new();
+    course.modules_finalized = false;
     course.workload_required = workload_required;
     course.workload = 0;
     course.college_id = ctx.accounts.provider.wallet.to_string();
@@ -71,6 +76,17 @@ pub fn create_course(
     course.nostr_d_tag = nostr_d_tag;
     course.nostr_author_pubkey = nostr_author_pubkey.unwrap_or([0u8; 32]);
     course.approved_credentials = Vec::new();
+    course.validity_period = None;
+    course.endorsement_threshold = 1;
+    course.min_grade = None;
+    course.endorsers = Vec::new();
+    course.enrollment_mode = EnrollmentMode::SelfEnroll;
+    course.enrollment_capacity = None;
+    course.enrollment_opens_at = None;
+    course.enrollment_closes_at = None;
+    course.enrolled_count = 0;
+    course.prerequisites = Vec::new();
+    course.staff_overrides = Vec::new();
 
     Ok(())
 }
@@ -86,40 +102,75 @@ pub struct AddCourseModule<'info> {
             &course.creation_timestamp.to_le_bytes(),
         ],
         bump,
-        constraint = course.provider == provider_authority.key()
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
     )]
     pub course: Account<'info, Course>,
+    #[account(constraint = resource.course == course.key() @ CourseError::ResourceNotInCourse)]
+    pub resource: Account<'info, Resource>,
     #[account(
         seeds = [
             Provider::SEED_PREFIX.as_bytes(),
             hub.key().as_ref(),
-            provider_authority.key().as_ref(),
+            course.provider.as_ref(),
         ],
         bump
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
 }
 
-pub fn add_course_module(
-    ctx: Context<AddCourseModule>,
-    resource: Pubkey,
-    percentage: u8,
-) -> Result<()> {
-    ctx.accounts.course.add_module(resource, percentage)?;
+pub fn add_course_module(ctx: Context<AddCourseModule>, percentage: u8) -> Result<()> {
+    let resource = &ctx.accounts.resource;
+    let workload = resource.workload.unwrap_or(0);
+    ctx.accounts
+        .course
+        .add_module(resource.key(), percentage, workload)?;
+    Ok(())
+}
+
+/// Provider-driven status changes: submit a draft for review or archive an accepted course.
+/// Acceptance and rejection go through the hub instructions.
+pub fn update_course_status(ctx: Context<UpdateCourseStatus>, status: CourseStatus) -> Result<()> {
+    let changed_by = ctx.accounts.provider_authority.key();
+    let course = &mut ctx.accounts.course;
+    change_course_status(
+        course,
+        status,
+        CourseStatusActor::Provider,
+        None,
+        changed_by,
+    )?;
+
+    if course.status == CourseStatus::InReview {
+        ctx.accounts
+            .course_review_queue
+            .enqueue(course.key(), course.provider, course.updated)?;
+    }
     Ok(())
 }
 
-pub fn update_course_status(
-    ctx: Context<UpdateCourseStatus>,
+/// Apply a transition from the course status table and emit `CourseStatusChanged`
+pub(crate) fn change_course_status(
+    course: &mut Account<Course>,
     status: CourseStatus,
+    actor: CourseStatusActor,
     rejection_reason: Option<String>,
+    changed_by: Pubkey,
 ) -> Result<()> {
-    let course = &mut ctx.accounts.course;
-    course.update_status(status, rejection_reason)?;
+    let old_status = course.transition_status(status, actor, rejection_reason)?;
+
+    emit!(CourseStatusChanged {
+        course: course.key(),
+        old_status,
+        new_status: course.status.clone(),
+        changed_by,
+        rejection_reason: course.rejection_reason.clone(),
+        timestamp: course.updated,
+    });
+
     Ok(())
 }
 
@@ -133,20 +184,27 @@ pub struct UpdateCourseStatus<'info> {
             provider.key().as_ref(),
             &course.creation_timestamp.to_le_bytes(),
         ],
-        bump
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
     )]
     pub course: Account<'info, Course>,
     #[account(
         seeds = [
             Provider::SEED_PREFIX.as_bytes(),
             hub.key().as_ref(),
-            provider_authority.key().as_ref(),
+            course.provider.as_ref(),
         ],
         bump
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
+    #[account(
+        mut,
+        seeds = [CourseReviewQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub course_review_queue: Account<'info, CourseReviewQueue>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
 }
@@ -161,19 +219,20 @@ pub struct SetCourseNostrRef<'info> {
             provider.key().as_ref(),
             &course.creation_timestamp.to_le_bytes(),
         ],
-        bump
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
     )]
     pub course: Account<'info, Course>,
     #[account(
         seeds = [
             Provider::SEED_PREFIX.as_bytes(),
             hub.key().as_ref(),
-            provider_authority.key().as_ref(),
+            course.provider.as_ref(),
         ],
         bump
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
@@ -187,11 +246,6 @@ pub fn set_course_nostr_ref(
 ) -> Result<()> {
     let course = &mut ctx.accounts.course;
 
-    require!(
-        ctx.accounts.provider_authority.key() == course.provider,
-        CourseError::UnauthorizedCourseAuthority
-    );
-
     if !force {
         require!(!course.is_nostr_ref_set(), CourseError::NostrRefAlreadySet);
     }
@@ -203,6 +257,420 @@ pub fn set_course_nostr_ref(
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct SetCourseValidityPeriod<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+}
+
+/// Set the default validity period (in seconds) for credentials approved from now on.
+/// Already issued credentials keep their current `valid_until` until they are renewed.
+pub fn set_course_validity_period(
+    ctx: Context<SetCourseValidityPeriod>,
+    validity_period: Option<i64>,
+) -> Result<()> {
+    if let Some(period) = validity_period {
+        require!(period > 0, CourseError::InvalidValidityPeriod);
+    }
+
+    let course = &mut ctx.accounts.course;
+    course.validity_period = validity_period;
+    course.updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCourseMinGrade<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+}
+
+/// Set or clear the minimum weighted grade required by `create_credential`.
+pub fn set_course_min_grade(ctx: Context<SetCourseMinGrade>, min_grade: Option<u8>) -> Result<()> {
+    if let Some(min_grade) = min_grade {
+        require!(min_grade <= 100, CourseError::InvalidMinGrade);
+    }
+
+    let course = &mut ctx.accounts.course;
+    course.min_grade = min_grade;
+    course.updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCourseEnrollmentConfig<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+}
+
+/// Configure who enrolls students, the enrollment capacity and the enrollment window.
+pub fn set_course_enrollment_config(
+    ctx: Context<SetCourseEnrollmentConfig>,
+    mode: EnrollmentMode,
+    capacity: Option<u32>,
+    opens_at: Option<i64>,
+    closes_at: Option<i64>,
+) -> Result<()> {
+    let course = &mut ctx.accounts.course;
+    if let Some(capacity) = capacity {
+        require!(
+            capacity > 0 && capacity >= course.enrolled_count,
+            CourseError::InvalidEnrollmentCapacity
+        );
+    }
+    if let (Some(opens_at), Some(closes_at)) = (opens_at, closes_at) {
+        require!(opens_at < closes_at, CourseError::InvalidEnrollmentWindow);
+    }
+
+    course.enrollment_mode = mode;
+    course.enrollment_capacity = capacity;
+    course.enrollment_opens_at = opens_at;
+    course.enrollment_closes_at = closes_at;
+    course.updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCourseEndorsementThreshold<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+}
+
+/// Set how many distinct endorsers must sign off before a credential becomes Endorsed.
+/// Credentials already collecting endorsements are checked against the new threshold on their next endorsement.
+pub fn set_course_endorsement_threshold(
+    ctx: Context<SetCourseEndorsementThreshold>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        threshold >= 1 && threshold as usize <= Credential::MAX_ENDORSEMENTS,
+        CourseError::InvalidEndorsementThreshold
+    );
+
+    let course = &mut ctx.accounts.course;
+    course.endorsement_threshold = threshold;
+    course.updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManageCourseEndorser<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+    /// CHECK: Endorser wallet being scoped to the course
+    pub endorser_wallet: AccountInfo<'info>,
+}
+
+/// Scope one of the provider's endorsers to this course.
+/// Once a course has scoped endorsers, only they can endorse its credentials.
+pub fn add_course_endorser(ctx: Context<ManageCourseEndorser>) -> Result<()> {
+    let endorser_wallet = ctx.accounts.endorser_wallet.key();
+
+    require!(
+        ctx.accounts.provider.is_endorser(&endorser_wallet),
+        CourseError::EndorserNotRegistered
+    );
+
+    let course = &mut ctx.accounts.course;
+    course.add_endorser(endorser_wallet)?;
+
+    emit!(CourseEndorserAdded {
+        course: course.key(),
+        endorser: endorser_wallet,
+        timestamp: course.updated,
+    });
+
+    Ok(())
+}
+
+pub fn remove_course_endorser(ctx: Context<ManageCourseEndorser>) -> Result<()> {
+    let endorser_wallet = ctx.accounts.endorser_wallet.key();
+    let course = &mut ctx.accounts.course;
+    course.remove_endorser(&endorser_wallet)?;
+
+    emit!(CourseEndorserRemoved {
+        course: course.key(),
+        endorser: endorser_wallet,
+        timestamp: course.updated,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManageCourseStaff<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.provider == provider_authority.key() @ CourseError::UnauthorizedCourseAuthority
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider_authority.key().as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+    /// CHECK: Staff wallet whose role is overridden for the course
+    pub staff_wallet: AccountInfo<'info>,
+}
+
+/// Override a staff member's provider role for this course.
+/// `None` removes their access to the course; the wallet need not be provider staff yet.
+pub fn set_course_staff_role(
+    ctx: Context<ManageCourseStaff>,
+    role: Option<StaffRole>,
+) -> Result<()> {
+    let staff_wallet = ctx.accounts.staff_wallet.key();
+    let course = &mut ctx.accounts.course;
+    course.set_staff_override(staff_wallet, role)?;
+    course.updated = Clock::get()?.unix_timestamp;
+
+    emit!(StaffRoleSet {
+        provider: course.provider,
+        course: Some(course.key()),
+        staff: staff_wallet,
+        role,
+        timestamp: course.updated,
+    });
+
+    Ok(())
+}
+
+/// Drop the course override so the staff member's provider role applies again
+pub fn remove_course_staff_role(ctx: Context<ManageCourseStaff>) -> Result<()> {
+    let staff_wallet = ctx.accounts.staff_wallet.key();
+    let course = &mut ctx.accounts.course;
+    course.remove_staff_override(&staff_wallet)?;
+    course.updated = Clock::get()?.unix_timestamp;
+
+    emit!(StaffRoleRemoved {
+        provider: course.provider,
+        course: Some(course.key()),
+        staff: staff_wallet,
+        timestamp: course.updated,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddCoursePrerequisite<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+    /// Course whose credential students must hold; may belong to any provider or hub
+    #[account(constraint = prerequisite_course.key() != course.key() @ CourseError::InvalidPrerequisite)]
+    pub prerequisite_course: Account<'info, Course>,
+}
+
+/// Require a credential from another course before enrolling in or completing this one
+pub fn add_course_prerequisite(ctx: Context<AddCoursePrerequisite>) -> Result<()> {
+    let prerequisite = ctx.accounts.prerequisite_course.key();
+    let course = &mut ctx.accounts.course;
+    course.add_prerequisite(prerequisite)?;
+
+    emit!(CoursePrerequisiteAdded {
+        course: course.key(),
+        prerequisite,
+        timestamp: course.updated,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveCoursePrerequisite<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+}
+
+/// Drop a prerequisite. Takes the course key so closed prerequisite courses can be removed too.
+pub fn remove_course_prerequisite(
+    ctx: Context<RemoveCoursePrerequisite>,
+    prerequisite: Pubkey,
+) -> Result<()> {
+    let course = &mut ctx.accounts.course;
+    course.remove_prerequisite(&prerequisite)?;
+
+    emit!(CoursePrerequisiteRemoved {
+        course: course.key(),
+        prerequisite,
+        timestamp: course.updated,
+    });
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct CloseCourse<'info> {
     #[account(
@@ -230,19 +698,30 @@ pub struct CloseCourse<'info> {
     pub provider: Account<'info, Provider>,
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump
     )]
     pub hub: Account<'info, Hub>,
+    #[account(
+        mut,
+        seeds = [CourseReviewQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub course_review_queue: Account<'info, CourseReviewQueue>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
 }
 
 /// Close a course account and reclaim its lamports back to the provider authority.
-/// If the course was accepted by the hub, it is also removed from the hub's accepted list.
+/// If the course was accepted by the hub, it is also removed from the hub's accepted list;
+/// a course still in review leaves the hub's review queue.
 pub fn close_course(ctx: Context<CloseCourse>) -> Result<()> {
+    let course_key = ctx.accounts.course.key();
     // Best-effort remove from hub accepted courses; ignore if not present.
-    ctx.accounts.hub.remove_course(&ctx.accounts.course.key())?;
+    ctx.accounts.hub.remove_course(&course_key)?;
+    ctx.accounts
+        .course_review_queue
+        .remove(&course_key, Clock::get()?.unix_timestamp);
 
     Ok(())
 }
@@ -258,30 +737,32 @@ pub struct UpdateCourseModule<'info> {
             &course.creation_timestamp.to_le_bytes(),
         ],
         bump,
-        constraint = course.provider == provider_authority.key()
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
     )]
     pub course: Account<'info, Course>,
+    #[account(constraint = resource.course == course.key() @ CourseError::ResourceNotInCourse)]
+    pub resource: Account<'info, Resource>,
     #[account(
         seeds = [
             Provider::SEED_PREFIX.as_bytes(),
             hub.key().as_ref(),
-            provider_authority.key().as_ref(),
+            course.provider.as_ref(),
         ],
         bump
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
 }
 
-pub fn update_course_module(
-    ctx: Context<UpdateCourseModule>,
-    resource: Pubkey,
-    percentage: u8,
-) -> Result<()> {
-    ctx.accounts.course.update_module(resource, percentage)?;
+pub fn update_course_module(ctx: Context<UpdateCourseModule>, percentage: u8) -> Result<()> {
+    let resource = &ctx.accounts.resource;
+    let workload = resource.workload.unwrap_or(0);
+    ctx.accounts
+        .course
+        .update_module(resource.key(), percentage, workload)?;
     Ok(())
 }
 
@@ -296,19 +777,19 @@ pub struct RemoveCourseModule<'info> {
             &course.creation_timestamp.to_le_bytes(),
         ],
         bump,
-        constraint = course.provider == provider_authority.key()
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
     )]
     pub course: Account<'info, Course>,
     #[account(
         seeds = [
             Provider::SEED_PREFIX.as_bytes(),
             hub.key().as_ref(),
-            provider_authority.key().as_ref(),
+            course.provider.as_ref(),
         ],
         bump
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
@@ -318,3 +799,45 @@ pub fn remove_course_module(ctx: Context<RemoveCourseModule>, resource: Pubkey)
     ctx.accounts.course.remove_module(resource)?;
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct FinalizeModules<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+}
+
+/// Provider locks in module weights (must total 100%) before submitting the course for review
+pub fn finalize_modules(ctx: Context<FinalizeModules>) -> Result<()> {
+    let course = &mut ctx.accounts.course;
+    course.finalize_modules()?;
+
+    emit!(CourseModulesFinalized {
+        course: course.key(),
+        module_count: course.modules.len() as u8,
+        timestamp: course.updated,
+    });
+
+    Ok(())
+}
@@ -1,10 +1,15 @@
 use crate::events::*;
 use crate::state::*;
 use crate::types::CourseError;
-use crate::types::{CourseStatus, CredentialMetadata, CredentialStatus};
+use crate::types::{
+    CourseStatus, CredentialMetadata, CredentialStatus, Endorsement, RevocationReason,
+    StaffCapability, VerificationFrequency, VerificationStats,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::token::{
+    freeze_account, mint_to, FreezeAccount, Mint, MintTo, Token, TokenAccount,
+};
 use mpl_token_metadata::instructions::CreateMetadataAccountV3CpiBuilder;
 use mpl_token_metadata::types::DataV2;
 
@@ -25,6 +30,17 @@ pub struct CreateCredential<'info> {
         bump
     )]
     pub credential: Account<'info, Credential>,
+    #[account(
+        init,
+        payer = student,
+        space = 8 + CredentialVerificationStats::INIT_SPACE,
+        seeds = [
+            CredentialVerificationStats::SEED_PREFIX.as_bytes(),
+            credential.key().as_ref(),
+        ],
+        bump
+    )]
+    pub verification_stats: Account<'info, CredentialVerificationStats>,
     #[account(
         seeds = [
             Course::SEED_PREFIX.as_bytes(),
@@ -46,8 +62,27 @@ pub struct CreateCredential<'info> {
         bump
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
+    #[account(
+        seeds = [
+            Enrollment::SEED_PREFIX.as_bytes(),
+            course.key().as_ref(),
+            student.key().as_ref(),
+        ],
+        bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    /// Required when the course sets a minimum grade or a required workload
+    #[account(
+        seeds = [
+            CourseProgress::SEED_PREFIX.as_bytes(),
+            course.key().as_ref(),
+            student.key().as_ref(),
+        ],
+        bump
+    )]
+    pub course_progress: Option<Account<'info, CourseProgress>>,
     #[account(mut)]
     pub student: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -89,7 +124,7 @@ pub struct ApproveCredential<'info> {
             credential.student_wallet.as_ref(),
         ],
         bump,
-        constraint = credential.provider_wallet == provider_authority.key(),
+        constraint = credential.provider_wallet == course.provider,
         constraint = credential.course == course.key(),
         constraint = credential.status == CredentialStatus::Endorsed @ crate::types::CredentialError::NotEndorsed
     )]
@@ -103,19 +138,21 @@ pub struct ApproveCredential<'info> {
             &course.creation_timestamp.to_le_bytes(),
         ],
         bump,
-        constraint = course.provider == provider_authority.key()
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ CourseError::UnauthorizedCourseAuthority
     )]
     pub course: Account<'info, Course>,
     #[account(
+        mut,
         seeds = [
             Provider::SEED_PREFIX.as_bytes(),
             hub.key().as_ref(),
-            provider_authority.key().as_ref(),
+            course.provider.as_ref(),
         ],
-        bump
+        bump,
+        constraint = !provider.suspended @ crate::types::ProviderError::ProviderSuspended
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     pub provider_authority: Signer<'info>,
 }
@@ -132,17 +169,91 @@ pub struct EndorseCredential<'info> {
         bump
     )]
     pub credential: Account<'info, Credential>,
+    /// Seeded by hub and provider so the hub passed in is the one the course belongs to
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == credential.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            credential.provider_wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub mentor: Signer<'info>,
 }
 
+/// VerifyCredential can be called by any third party (e.g. an admissions office).
+#[derive(Accounts)]
+pub struct VerifyCredential<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Credential::SEED_PREFIX.as_bytes(),
+            credential.course.as_ref(),
+            credential.student_wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub credential: Account<'info, Credential>,
+    #[account(
+        mut,
+        seeds = [
+            CredentialVerificationStats::SEED_PREFIX.as_bytes(),
+            credential.key().as_ref(),
+        ],
+        bump,
+        has_one = credential
+    )]
+    pub verification_stats: Account<'info, CredentialVerificationStats>,
+    pub verifier: Signer<'info>,
+}
+
 /// Create credential: metadata (title, description) comes from course; no endorsement/completion/ipfs at creation.
+/// Credentials for the course's prerequisites are passed as remaining accounts.
 pub fn create_credential(ctx: Context<CreateCredential>) -> Result<()> {
     let credential_key = ctx.accounts.credential.key();
     let credential = &mut ctx.accounts.credential;
     let course = &ctx.accounts.course;
     let clock = Clock::get()?;
 
+    course.check_prerequisites(
+        &ctx.accounts.student.key(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        clock.unix_timestamp,
+    )?;
+    if course.min_grade.is_some() || course.workload_required > 0 {
+        let progress = ctx
+            .accounts
+            .course_progress
+            .as_ref()
+            .ok_or(CourseError::CourseProgressRequired)?;
+        require!(
+            progress.completed_workload >= course.workload_required,
+            CourseError::WorkloadNotCompleted
+        );
+        if let Some(min_grade) = course.min_grade {
+            require!(
+                progress.weighted_grade >= min_grade as f64,
+                CourseError::MinimumGradeNotMet
+            );
+        }
+    }
+
     credential.created = clock.unix_timestamp;
     credential.updated = clock.unix_timestamp;
     credential.student_wallet = ctx.accounts.student.key();
@@ -152,6 +263,9 @@ pub fn create_credential(ctx: Context<CreateCredential>) -> Result<()> {
     credential.course = ctx.accounts.course.key();
     credential.verification_count = 0;
     credential.status = CredentialStatus::Pending;
+    credential.valid_until = None;
+    credential.endorsements = Vec::new();
+    credential.renewal_count = 0;
 
     credential.metadata = CredentialMetadata {
         title: course.name.clone(),
@@ -163,6 +277,12 @@ pub fn create_credential(ctx: Context<CreateCredential>) -> Result<()> {
         activities: Vec::new(),
     };
 
+    let verification_stats = &mut ctx.accounts.verification_stats;
+    verification_stats.credential = credential_key;
+    verification_stats.stats = VerificationStats::new();
+    verification_stats.frequency = VerificationFrequency::First;
+    verification_stats.recent_verifications = Vec::new();
+
     emit!(CredentialCreated {
         credential: credential_key,
         provider: credential.provider_wallet,
@@ -179,18 +299,71 @@ pub fn endorse_credential(
     ctx: Context<EndorseCredential>,
     endorsement_message: String,
 ) -> Result<()> {
+    let mentor = ctx.accounts.mentor.key();
     let credential = &mut ctx.accounts.credential;
 
+    require!(
+        mentor != credential.student_wallet,
+        crate::types::CredentialError::SelfEndorsement
+    );
+    require!(
+        endorsement_message.len() <= Credential::MAX_ENDORSEMENT_MESSAGE_LEN,
+        crate::types::CredentialError::EndorsementMessageTooLong
+    );
+    // Reviewers endorse through their staff role; registered endorsers through the endorser lists
+    let reviewer = ctx
+        .accounts
+        .provider
+        .staff_role(&mentor, Some(&ctx.accounts.course))
+        .is_some_and(|role| role.allows(StaffCapability::Endorse));
+    require!(
+        reviewer || ctx.accounts.provider.is_endorser(&mentor),
+        crate::types::CredentialError::EndorserNotAllowed
+    );
+    require!(
+        reviewer || ctx.accounts.course.allows_endorser(&mentor),
+        crate::types::CredentialError::EndorserNotAllowedForCourse
+    );
+
+    require!(
+        credential.status == CredentialStatus::Pending
+            || credential.status == CredentialStatus::Endorsed,
+        crate::types::CredentialError::EndorsementClosed
+    );
+    require!(
+        !credential.has_endorsed(&mentor),
+        crate::types::CredentialError::AlreadyEndorsedBy
+    );
+    require!(
+        credential.endorsements.len() < Credential::MAX_ENDORSEMENTS,
+        crate::types::CredentialError::TooManyEndorsements
+    );
+
+    let now = Clock::get()?.unix_timestamp;
     if credential.mentor_wallet == Pubkey::default() {
-        credential.mentor_wallet = ctx.accounts.mentor.key();
-    } else {
-        require!(
-            credential.mentor_wallet == ctx.accounts.mentor.key(),
-            crate::types::CredentialError::UnauthorizedEndorser
-        );
+        credential.mentor_wallet = mentor;
+        credential.metadata.mentor_endorsement = endorsement_message.clone();
     }
-    credential.metadata.mentor_endorsement = endorsement_message;
-    credential.update_status(CredentialStatus::Endorsed);
+    credential.endorsements.push(Endorsement {
+        endorser: mentor,
+        message: endorsement_message.clone(),
+        timestamp: now,
+    });
+    credential.updated = now;
+
+    let threshold = ctx.accounts.course.required_endorsements();
+    if credential.endorsements.len() >= threshold {
+        credential.update_status(CredentialStatus::Endorsed);
+    }
+
+    emit!(CredentialEndorsed {
+        credential: credential.key(),
+        mentor,
+        endorsement: endorsement_message,
+        endorsement_count: credential.endorsements.len() as u8,
+        threshold: threshold as u8,
+        timestamp: now,
+    });
 
     Ok(())
 }
@@ -229,9 +402,332 @@ pub fn approve_credential(ctx: Context<ApproveCredential>) -> Result<()> {
     let course = &mut ctx.accounts.course;
 
     course.add_approved_credential(credential.key())?;
+
+    // Re-approving a renewed credential must not raise the provider's trust level again
+    if credential.renewal_count == 0 {
+        let provider = &mut ctx.accounts.provider;
+        provider.approved_credentials_count = provider.approved_credentials_count.saturating_add(1);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let valid_until = course
+        .validity_period
+        .map(|period| now.saturating_add(period));
+
+    let credential = &mut ctx.accounts.credential;
+    credential.valid_until = valid_until;
+    // A renewed credential keeps its NFT, so it goes straight back to Minted
+    if credential.nft_mint == Pubkey::default() {
+        credential.update_status(CredentialStatus::Verified);
+    } else {
+        credential.update_status(CredentialStatus::Minted);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RenewCredential<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Credential::SEED_PREFIX.as_bytes(),
+            credential.course.as_ref(),
+            credential.student_wallet.as_ref(),
+        ],
+        bump,
+        constraint = credential.student_wallet == student.key() @ crate::types::CredentialError::NotRenewable
+    )]
+    pub credential: Account<'info, Credential>,
+    #[account(
+        mut,
+        constraint = course.key() == credential.course
+    )]
+    pub course: Account<'info, Course>,
+    pub student: Signer<'info>,
+}
+
+/// Student restarts the endorsement and approval cycle for a credential with a validity window,
+/// once it is within `Credential::RENEWAL_WINDOW` of expiring (or has expired).
+/// The credential goes back to Pending; endorse + approve then issue a fresh `valid_until`.
+pub fn renew_credential(ctx: Context<RenewCredential>) -> Result<()> {
+    let credential = &mut ctx.accounts.credential;
+
+    require!(
+        (credential.status == CredentialStatus::Verified
+            || credential.status == CredentialStatus::Minted)
+            && credential.valid_until.is_some(),
+        crate::types::CredentialError::NotRenewable
+    );
+    require!(
+        credential.is_renewal_open(Clock::get()?.unix_timestamp),
+        crate::types::CredentialError::RenewalNotOpen
+    );
+
     ctx.accounts
-        .credential
-        .update_status(CredentialStatus::Verified);
+        .course
+        .remove_approved_credential(&credential.key())?;
+
+    let previous_valid_until = credential.valid_until;
+    credential.clear_endorsements();
+    credential.renewal_count = credential.renewal_count.saturating_add(1);
+    credential.update_status(CredentialStatus::Pending);
+
+    emit!(CredentialRenewalRequested {
+        credential: credential.key(),
+        student: credential.student_wallet,
+        previous_valid_until,
+        timestamp: credential.updated,
+    });
+
+    Ok(())
+}
+
+/// Third-party verification of an issued credential.
+/// Named verifications record the verifier wallet in the on-chain trail; anonymous ones only count.
+pub fn verify_credential(ctx: Context<VerifyCredential>, anonymous: bool) -> Result<()> {
+    let verifier = ctx.accounts.verifier.key();
+    record_verification(
+        &mut ctx.accounts.credential,
+        &mut ctx.accounts.verification_stats,
+        verifier,
+        anonymous,
+    )
+}
+
+/// Shared by `verify_credential` and cross-hub `verify_recognized_credential`
+pub(crate) fn record_verification(
+    credential: &mut Account<Credential>,
+    verification_stats: &mut Account<CredentialVerificationStats>,
+    verifier: Pubkey,
+    anonymous: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        credential.status == CredentialStatus::Verified
+            || credential.status == CredentialStatus::Minted,
+        crate::types::CredentialError::NotIssued
+    );
+
+    require!(
+        !credential.is_expired(clock.unix_timestamp),
+        crate::types::CredentialError::Expired
+    );
+
+    let verifier = if anonymous { None } else { Some(verifier) };
+
+    credential.increment_verification_count();
+    verification_stats.record(verifier, clock.unix_timestamp);
+
+    emit!(CredentialVerified {
+        credential: credential.key(),
+        verification_count: credential.verification_count,
+        verifier,
+        frequency: verification_stats.frequency.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Either revoker of the course's credentials (its provider or the hub authority/operator)
+/// may create the registry.
+#[derive(Accounts)]
+pub struct CreateRevocationRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RevocationRegistry::INIT_SPACE,
+        seeds = [
+            RevocationRegistry::SEED_PREFIX.as_bytes(),
+            course.key().as_ref(),
+        ],
+        bump
+    )]
+    pub revocation_registry: Account<'info, RevocationRegistry>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = authority.key() == course.provider
+            || hub.can_operate(&authority.key()) @ crate::types::CredentialError::UnauthorizedRevocation
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the revocation registry of a course.
+pub fn create_revocation_registry(ctx: Context<CreateRevocationRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.revocation_registry;
+    let now = Clock::get()?.unix_timestamp;
+
+    registry.course = ctx.accounts.course.key();
+    registry.created_at = now;
+    registry.updated_at = now;
+    registry.entries = Vec::new();
+
+    Ok(())
+}
+
+/// RevokeCredential is called by the credential's provider or the hub authority or operator.
+/// NFT accounts are only required when an NFT has been minted for the credential.
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Credential::SEED_PREFIX.as_bytes(),
+            credential.course.as_ref(),
+            credential.student_wallet.as_ref(),
+        ],
+        bump,
+        constraint = credential.status != CredentialStatus::Revoked @ crate::types::CredentialError::AlreadyRevoked
+    )]
+    pub credential: Account<'info, Credential>,
+    /// Seeded by hub and provider so the hub passed in is the one the course belongs to
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == credential.course
+    )]
+    pub course: Account<'info, Course>,
+    /// Optional: revocation is recorded here when the course has a registry
+    #[account(
+        mut,
+        seeds = [
+            RevocationRegistry::SEED_PREFIX.as_bytes(),
+            course.key().as_ref(),
+        ],
+        bump,
+        has_one = course
+    )]
+    pub revocation_registry: Option<Account<'info, RevocationRegistry>>,
+    #[account(
+        mut,
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            credential.provider_wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub mint: Option<Account<'info, Mint>>,
+    /// Token account currently holding the credential NFT (not necessarily the student's)
+    #[account(mut)]
+    pub student_token_account: Option<Account<'info, TokenAccount>>,
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Revoke a credential: freeze its NFT, if any, and record the reason in the course registry
+/// when one exists.
+pub fn revoke_credential(ctx: Context<RevokeCredential>, reason: RevocationReason) -> Result<()> {
+    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.key();
+    let credential_key = ctx.accounts.credential.key();
+
+    require!(
+        authority == ctx.accounts.credential.provider_wallet
+            || ctx.accounts.hub.can_operate(&authority),
+        crate::types::CredentialError::UnauthorizedRevocation
+    );
+
+    // A renewed credential can be back in Pending/Endorsed while its NFT is still live
+    if ctx.accounts.credential.nft_mint != Pubkey::default() {
+        let (Some(mint), Some(student_token_account), Some(token_program)) = (
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.student_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(crate::types::CredentialError::MissingNftAccounts);
+        };
+        // Freeze whichever account holds the NFT; the student may have moved it to another wallet
+        require!(
+            mint.key() == ctx.accounts.credential.nft_mint
+                && student_token_account.mint == mint.key()
+                && student_token_account.amount == 1,
+            crate::types::CredentialError::NftAccountMismatch
+        );
+
+        // Credential PDA is both mint and freeze authority of its NFT
+        let cred_bump = ctx.bumps.credential;
+        let seeds = &[
+            Credential::SEED_PREFIX.as_bytes(),
+            ctx.accounts.credential.course.as_ref(),
+            ctx.accounts.credential.student_wallet.as_ref(),
+            &[cred_bump],
+        ];
+        let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+        freeze_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            FreezeAccount {
+                account: student_token_account.to_account_info(),
+                mint: mint.to_account_info(),
+                authority: ctx.accounts.credential.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    if let Some(registry) = ctx.accounts.revocation_registry.as_mut() {
+        registry.add_entry(RevocationEntry {
+            credential: credential_key,
+            student: ctx.accounts.credential.student_wallet,
+            reason: reason.clone(),
+            revoked_by: authority,
+            revoked_at: clock.unix_timestamp,
+        })?;
+    }
+    ctx.accounts
+        .course
+        .remove_approved_credential(&credential_key)?;
+
+    // Only credentials counted as approved count as revoked, keeping the trust ratio honest
+    if ctx.accounts.credential.was_approved() {
+        let provider = &mut ctx.accounts.provider;
+        provider.revoked_credentials_count = provider.revoked_credentials_count.saturating_add(1);
+    }
+
+    let credential = &mut ctx.accounts.credential;
+    credential.update_status(CredentialStatus::Revoked);
+
+    emit!(CredentialRevoked {
+        credential: credential_key,
+        course: credential.course,
+        student: credential.student_wallet,
+        revoked_by: authority,
+        reason,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -274,6 +770,7 @@ pub struct MintCredentialNft<'info> {
         payer = student,
         mint::decimals = 0,
         mint::authority = credential,
+        mint::freeze_authority = credential,
         seeds = [CREDENTIAL_NFT_MINT_SEED, credential.key().as_ref()],
         bump
     )]
@@ -313,6 +810,10 @@ pub fn mint_credential_nft(ctx: Context<MintCredentialNft>) -> Result<()> {
         metadata_pda == ctx.accounts.metadata.key(),
         crate::types::CredentialError::NotVerified
     );
+    require!(
+        !ctx.accounts.credential.is_expired(clock.unix_timestamp),
+        crate::types::CredentialError::Expired
+    );
 
     let cred_bump = ctx.bumps.credential;
     let seeds = &[
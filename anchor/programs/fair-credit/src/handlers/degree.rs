@@ -0,0 +1,361 @@
+use crate::events::*;
+use crate::state::{Course, Credential, Degree, DegreeCredential, Hub, Provider};
+use crate::types::{CollegeError, CredentialStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(degree_id: String)]
+pub struct CreateDegree<'info> {
+    #[account(
+        init,
+        payer = provider_authority,
+        space = 8 + Degree::INIT_SPACE,
+        seeds = [
+            Degree::SEED_PREFIX.as_bytes(),
+            provider.key().as_ref(),
+            degree_id.as_bytes(),
+        ],
+        bump
+    )]
+    pub degree: Account<'info, Degree>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider_authority.key().as_ref(),
+        ],
+        bump,
+        constraint = !provider.suspended @ crate::types::ProviderError::ProviderSuspended
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub provider_authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_degree(
+    ctx: Context<CreateDegree>,
+    degree_id: String,
+    name: String,
+    description: String,
+    workload_required: u32,
+) -> Result<()> {
+    require!(
+        !degree_id.is_empty() && degree_id.len() <= Degree::MAX_DEGREE_ID_LEN,
+        CollegeError::InvalidDegreeId
+    );
+    require!(
+        name.len() <= Degree::MAX_NAME_LEN,
+        CollegeError::DegreeNameTooLong
+    );
+    require!(
+        description.len() <= Degree::MAX_DESCRIPTION_LEN,
+        CollegeError::DegreeDescriptionTooLong
+    );
+    let now = Clock::get()?.unix_timestamp;
+
+    let degree = &mut ctx.accounts.degree;
+    degree.provider = ctx.accounts.provider.key();
+    degree.degree_id = degree_id;
+    degree.name = name;
+    degree.description = description;
+    degree.required_courses = Vec::new();
+    degree.elective_pools = Vec::new();
+    degree.workload_required = workload_required;
+    degree.published = false;
+    degree.created = now;
+    degree.updated = now;
+
+    emit!(DegreeCreated {
+        degree: degree.key(),
+        provider: degree.provider,
+        degree_id: degree.degree_id.clone(),
+        workload_required,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Provider-only access to a degree's structure
+#[derive(Accounts)]
+pub struct UpdateDegree<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Degree::SEED_PREFIX.as_bytes(),
+            provider.key().as_ref(),
+            degree.degree_id.as_bytes(),
+        ],
+        bump,
+        has_one = provider
+    )]
+    pub degree: Account<'info, Degree>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider_authority.key().as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddDegreeCourse<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Degree::SEED_PREFIX.as_bytes(),
+            provider.key().as_ref(),
+            degree.degree_id.as_bytes(),
+        ],
+        bump,
+        has_one = provider
+    )]
+    pub degree: Account<'info, Degree>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider_authority.key().as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+    /// Course added to the degree; may belong to any provider
+    pub course: Account<'info, Course>,
+}
+
+pub fn add_degree_required_course(ctx: Context<AddDegreeCourse>) -> Result<()> {
+    let degree = &mut ctx.accounts.degree;
+    degree.add_required_course(ctx.accounts.course.key())?;
+    degree.updated = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+pub fn add_degree_elective_pool(
+    ctx: Context<UpdateDegree>,
+    name: String,
+    min_courses: u8,
+) -> Result<()> {
+    let degree = &mut ctx.accounts.degree;
+    degree.add_elective_pool(name, min_courses)?;
+    degree.updated = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+pub fn add_degree_elective_course(ctx: Context<AddDegreeCourse>, pool_index: u8) -> Result<()> {
+    let degree = &mut ctx.accounts.degree;
+    degree.add_elective_course(pool_index, ctx.accounts.course.key())?;
+    degree.updated = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+/// Remove a course from the required list and every elective pool.
+/// Takes the course key so closed courses can be removed too.
+pub fn remove_degree_course(ctx: Context<UpdateDegree>, course: Pubkey) -> Result<()> {
+    let degree = &mut ctx.accounts.degree;
+    degree.remove_course(&course)?;
+    degree.updated = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+/// Freeze the degree structure and open it for claims
+pub fn publish_degree(ctx: Context<UpdateDegree>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let degree = &mut ctx.accounts.degree;
+    degree.publish()?;
+    degree.updated = now;
+
+    emit!(DegreePublished {
+        degree: degree.key(),
+        provider: degree.provider,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimDegreeCredential<'info> {
+    #[account(
+        init,
+        payer = student,
+        space = 8 + DegreeCredential::INIT_SPACE,
+        seeds = [
+            DegreeCredential::SEED_PREFIX.as_bytes(),
+            degree.key().as_ref(),
+            student.key().as_ref(),
+        ],
+        bump
+    )]
+    pub degree_credential: Account<'info, DegreeCredential>,
+    #[account(constraint = degree.published @ CollegeError::DegreeNotPublished)]
+    pub degree: Account<'info, Degree>,
+    #[account(mut)]
+    pub student: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Student claims a degree credential. The student's course credentials are passed as
+/// remaining accounts in (credential, course) pairs; each must be Verified or Minted and unexpired.
+pub fn claim_degree_credential(ctx: Context<ClaimDegreeCredential>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let student = ctx.accounts.student.key();
+    let pairs = ctx.remaining_accounts.chunks_exact(2);
+    require!(
+        pairs.remainder().is_empty(),
+        CollegeError::InvalidDegreeCredential
+    );
+
+    let mut course_credentials = Vec::new();
+    let mut completed: Vec<(Pubkey, u32)> = Vec::new();
+    for pair in pairs {
+        let (credential_info, course_info) = (&pair[0], &pair[1]);
+        require!(
+            credential_info.owner == ctx.program_id && course_info.owner == ctx.program_id,
+            CollegeError::InvalidDegreeCredential
+        );
+        let credential = Credential::try_deserialize(&mut &credential_info.try_borrow_data()?[..])?;
+        let course = Course::try_deserialize(&mut &course_info.try_borrow_data()?[..])?;
+        require!(
+            credential.student_wallet == student
+                && credential.course == course_info.key()
+                && credential.is_valid(now),
+            CollegeError::InvalidDegreeCredential
+        );
+        if completed.iter().any(|(c, _)| c == &credential.course) {
+            continue;
+        }
+        if ctx.accounts.degree.includes_course(&credential.course) {
+            course_credentials.push(credential_info.key());
+        }
+        completed.push((credential.course, course.workload_required));
+    }
+
+    let degree = &ctx.accounts.degree;
+    let total_workload = degree.check_requirements(&completed)?;
+
+    let degree_credential = &mut ctx.accounts.degree_credential;
+    degree_credential.degree = degree.key();
+    degree_credential.student = student;
+    degree_credential.course_credentials = course_credentials;
+    degree_credential.total_workload = total_workload;
+    degree_credential.issued_at = now;
+    degree_credential.revoked_at = None;
+
+    emit!(DegreeCredentialIssued {
+        degree: degree.key(),
+        student,
+        degree_credential: degree_credential.key(),
+        total_workload,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Provider-only revocation of a degree credential issued for one of its degrees
+#[derive(Accounts)]
+pub struct RevokeDegreeCredential<'info> {
+    #[account(
+        mut,
+        seeds = [
+            DegreeCredential::SEED_PREFIX.as_bytes(),
+            degree.key().as_ref(),
+            degree_credential.student.as_ref(),
+        ],
+        bump,
+        has_one = degree,
+        constraint = !degree_credential.is_revoked() @ CollegeError::DegreeCredentialAlreadyRevoked
+    )]
+    pub degree_credential: Account<'info, DegreeCredential>,
+    #[account(
+        seeds = [
+            Degree::SEED_PREFIX.as_bytes(),
+            provider.key().as_ref(),
+            degree.degree_id.as_bytes(),
+        ],
+        bump,
+        has_one = provider
+    )]
+    pub degree: Account<'info, Degree>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider_authority.key().as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+}
+
+pub fn revoke_degree_credential(ctx: Context<RevokeDegreeCredential>) -> Result<()> {
+    let revoked_by = ctx.accounts.provider_authority.key();
+    mark_degree_credential_revoked(&mut ctx.accounts.degree_credential, revoked_by)
+}
+
+/// Anyone may revoke a degree credential once a course credential it was issued on is revoked
+#[derive(Accounts)]
+pub struct InvalidateDegreeCredential<'info> {
+    #[account(
+        mut,
+        seeds = [
+            DegreeCredential::SEED_PREFIX.as_bytes(),
+            degree_credential.degree.as_ref(),
+            degree_credential.student.as_ref(),
+        ],
+        bump,
+        constraint = !degree_credential.is_revoked() @ CollegeError::DegreeCredentialAlreadyRevoked
+    )]
+    pub degree_credential: Account<'info, DegreeCredential>,
+    #[account(
+        seeds = [
+            Credential::SEED_PREFIX.as_bytes(),
+            credential.course.as_ref(),
+            credential.student_wallet.as_ref(),
+        ],
+        bump,
+        constraint = degree_credential.course_credentials.contains(&credential.key())
+            && credential.status == CredentialStatus::Revoked @ CollegeError::CourseCredentialNotRevoked
+    )]
+    pub credential: Account<'info, Credential>,
+    pub authority: Signer<'info>,
+}
+
+pub fn invalidate_degree_credential(ctx: Context<InvalidateDegreeCredential>) -> Result<()> {
+    let revoked_by = ctx.accounts.authority.key();
+    mark_degree_credential_revoked(&mut ctx.accounts.degree_credential, revoked_by)
+}
+
+fn mark_degree_credential_revoked(
+    degree_credential: &mut Account<DegreeCredential>,
+    revoked_by: Pubkey,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    degree_credential.revoked_at = Some(now);
+
+    emit!(DegreeCredentialRevoked {
+        degree: degree_credential.degree,
+        student: degree_credential.student,
+        degree_credential: degree_credential.key(),
+        revoked_by,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use crate::events::*;
+use crate::state::{Course, Enrollment};
+use crate::types::{EnrollmentMode, StudentError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct EnrollStudent<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Enrollment::INIT_SPACE,
+        seeds = [
+            Enrollment::SEED_PREFIX.as_bytes(),
+            course.key().as_ref(),
+            student.key().as_ref(),
+        ],
+        bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    #[account(mut)]
+    pub course: Account<'info, Course>,
+    /// CHECK: wallet being enrolled; must match the signer when the course uses self-enrollment
+    pub student: UncheckedAccount<'info>,
+    /// Student (self-enrollment) or course provider (provider enrollment)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Enroll a student in an Accepted course, subject to its enrollment mode, window and capacity.
+/// The student's credentials for the course's prerequisites are passed as remaining accounts.
+pub fn enroll_student(ctx: Context<EnrollStudent>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+    let student = ctx.accounts.student.key();
+    let course = &mut ctx.accounts.course;
+
+    let allowed = match course.enrollment_mode {
+        EnrollmentMode::SelfEnroll => authority == student,
+        EnrollmentMode::ProviderEnroll => authority == course.provider,
+    };
+    require!(allowed, StudentError::EnrollmentNotAllowed);
+    course.check_prerequisites(&student, ctx.remaining_accounts, ctx.program_id, now)?;
+    course.reserve_enrollment(now)?;
+
+    let enrollment = &mut ctx.accounts.enrollment;
+    enrollment.course = course.key();
+    enrollment.student = student;
+    enrollment.enrolled_by = authority;
+    enrollment.enrolled_at = now;
+
+    emit!(StudentEnrolled {
+        course: enrollment.course,
+        student,
+        enrolled_by: authority,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawEnrollment<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            Enrollment::SEED_PREFIX.as_bytes(),
+            course.key().as_ref(),
+            enrollment.student.as_ref(),
+        ],
+        bump,
+        has_one = course,
+        constraint = authority.key() == enrollment.student
+            || authority.key() == course.provider @ StudentError::EnrollmentNotAllowed
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    #[account(mut)]
+    pub course: Account<'info, Course>,
+    /// Enrolled student or course provider; receives the enrollment rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Withdraw a student from a course and free their enrollment slot
+pub fn withdraw_enrollment(ctx: Context<WithdrawEnrollment>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let course = &mut ctx.accounts.course;
+    course.release_enrollment(now);
+
+    emit!(EnrollmentWithdrawn {
+        course: course.key(),
+        student: ctx.accounts.enrollment.student,
+        withdrawn_by: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
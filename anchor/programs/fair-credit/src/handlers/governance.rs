@@ -0,0 +1,537 @@
+use crate::events::*;
+use crate::handlers::hub::{
+    accept_course, accept_provider, add_listed_course, apply_hub_config, apply_reputation,
+    apply_suspension, cancel_authority_transfer, init_course_list, lift_suspension,
+    link_course_list, propose_authority, reject_course_in_review, reject_provider, remove_course,
+    remove_listed_course, remove_provider, set_operator,
+};
+use crate::state::{
+    Course, CourseList, CourseReviewQueue, Hub, HubAction, HubGovernance, HubProposal,
+    PendingProviderQueue, ProposalStatus, Provider,
+};
+use crate::types::{GovernanceError, HubError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeHubGovernance<'info> {
+    #[account(
+        mut,
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HubGovernance::INIT_SPACE,
+        seeds = [HubGovernance::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub governance: Account<'info, HubGovernance>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Hand the hub over to a member set. The governance PDA becomes the hub authority,
+/// so from here on hub-admin actions go through proposals.
+pub fn initialize_hub_governance(
+    ctx: Context<InitializeHubGovernance>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    HubGovernance::validate(&members, threshold)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let governance_key = ctx.accounts.governance.key();
+    let hub = &mut ctx.accounts.hub;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.hub = hub.key();
+    governance.members = members.clone();
+    governance.threshold = threshold;
+    governance.proposal_count = 0;
+    governance.created_at = now;
+    governance.updated_at = now;
+
+    let old_authority = hub.authority;
+    hub.authority = governance_key;
+    hub.pending_authority = None;
+    hub.updated_at = now;
+
+    emit!(HubGovernanceInitialized {
+        hub: hub.key(),
+        governance: governance_key,
+        members,
+        threshold,
+        timestamp: now,
+    });
+    emit!(HubAuthorityTransferred {
+        old_authority,
+        new_authority: governance_key,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateHubProposal<'info> {
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + HubProposal::INIT_SPACE,
+        seeds = [
+            HubProposal::SEED_PREFIX.as_bytes(),
+            governance.key().as_ref(),
+            &governance.proposal_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, HubProposal>,
+    #[account(
+        mut,
+        seeds = [HubGovernance::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump,
+        has_one = hub,
+        constraint = governance.is_member(&proposer.key()) @ GovernanceError::NotMember
+    )]
+    pub governance: Account<'info, HubGovernance>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Member proposes a hub-admin action; the proposer's approval is recorded immediately.
+pub fn create_hub_proposal(ctx: Context<CreateHubProposal>, action: HubAction) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposer = ctx.accounts.proposer.key();
+    let governance = &mut ctx.accounts.governance;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.governance = governance.key();
+    proposal.index = governance.proposal_count;
+    proposal.proposer = proposer;
+    proposal.action = action;
+    proposal.approvals = vec![proposer];
+    proposal.status = ProposalStatus::Open;
+    proposal.created_at = now;
+    proposal.executed_at = None;
+
+    governance.proposal_count += 1;
+    governance.updated_at = now;
+
+    emit!(HubProposalCreated {
+        governance: governance.key(),
+        proposal: proposal.key(),
+        index: proposal.index,
+        proposer,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VoteHubProposal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            HubProposal::SEED_PREFIX.as_bytes(),
+            governance.key().as_ref(),
+            &proposal.index.to_le_bytes(),
+        ],
+        bump,
+        has_one = governance
+    )]
+    pub proposal: Account<'info, HubProposal>,
+    #[account(
+        seeds = [HubGovernance::SEED_PREFIX.as_bytes(), governance.hub.as_ref()],
+        bump,
+        constraint = governance.is_member(&member.key()) @ GovernanceError::NotMember
+    )]
+    pub governance: Account<'info, HubGovernance>,
+    pub member: Signer<'info>,
+}
+
+pub fn approve_hub_proposal(ctx: Context<VoteHubProposal>) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.approve(member, &ctx.accounts.governance)?;
+
+    emit!(HubProposalApproved {
+        proposal: proposal.key(),
+        member,
+        approvals: proposal.approval_count(&ctx.accounts.governance) as u8,
+        threshold: ctx.accounts.governance.threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Proposer withdraws a proposal that has not been executed yet.
+pub fn cancel_hub_proposal(ctx: Context<VoteHubProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        proposal.proposer == ctx.accounts.member.key(),
+        GovernanceError::NotProposer
+    );
+    require!(
+        proposal.status == ProposalStatus::Open,
+        GovernanceError::ProposalNotOpen
+    );
+
+    proposal.status = ProposalStatus::Cancelled;
+
+    emit!(HubProposalCancelled {
+        proposal: proposal.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteHubProposal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            HubProposal::SEED_PREFIX.as_bytes(),
+            governance.key().as_ref(),
+            &proposal.index.to_le_bytes(),
+        ],
+        bump,
+        has_one = governance
+    )]
+    pub proposal: Account<'info, HubProposal>,
+    #[account(
+        mut,
+        seeds = [HubGovernance::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump,
+        has_one = hub
+    )]
+    pub governance: Account<'info, HubGovernance>,
+    #[account(
+        mut,
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.authority == governance.key() @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    /// Required for `AddProvider`
+    #[account(
+        mut,
+        seeds = [PendingProviderQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub provider_queue: Option<Account<'info, PendingProviderQueue>>,
+    /// Required for `AddProvider` and the provider standing actions: the provider PDA of the proposed wallet
+    #[account(mut)]
+    pub provider: Option<Account<'info, Provider>>,
+    /// Required for `AddCourse` / `RemoveCourse` / `RejectCourse` / `AddCourseToList`
+    #[account(mut)]
+    pub course: Option<Account<'info, Course>>,
+    /// Required for `AddCourse` / `RemoveCourse` / `RejectCourse`
+    #[account(
+        mut,
+        seeds = [CourseReviewQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub course_review_queue: Option<Account<'info, CourseReviewQueue>>,
+    /// Required for `AddCourseToList` / `RemoveCourseFromList` / `SetCourseListNext`
+    #[account(mut, has_one = hub)]
+    pub course_list: Option<Account<'info, CourseList>>,
+    pub executor: Signer<'info>,
+}
+
+/// Execute an approved proposal. Anyone may execute once the threshold is reached.
+/// `CreateCourseList` proposals need a new account and use `execute_course_list_proposal` instead.
+pub fn execute_hub_proposal(ctx: Context<ExecuteHubProposal>) -> Result<()> {
+    let governance_key = ctx.accounts.governance.key();
+    check_executable(&ctx.accounts.proposal, &ctx.accounts.governance)?;
+
+    let hub = &mut ctx.accounts.hub;
+    match ctx.accounts.proposal.action.clone() {
+        HubAction::AddProvider { provider_wallet } => {
+            proposal_provider(&mut ctx.accounts.provider, hub.key(), provider_wallet)?;
+            let provider_queue = provider_queue(&mut ctx.accounts.provider_queue)?;
+            accept_provider(hub, provider_queue, provider_wallet)?;
+        }
+        HubAction::RemoveProvider { provider_wallet } => {
+            remove_provider(hub, provider_wallet)?;
+        }
+        HubAction::RejectProvider { provider_wallet } => {
+            let provider_queue = provider_queue(&mut ctx.accounts.provider_queue)?;
+            reject_provider(governance_key, provider_queue, provider_wallet)?;
+        }
+        HubAction::SuspendProvider {
+            provider_wallet,
+            note,
+        } => {
+            let provider =
+                proposal_provider(&mut ctx.accounts.provider, hub.key(), provider_wallet)?;
+            apply_suspension(provider, note)?;
+        }
+        HubAction::UnsuspendProvider { provider_wallet } => {
+            let provider =
+                proposal_provider(&mut ctx.accounts.provider, hub.key(), provider_wallet)?;
+            lift_suspension(provider)?;
+        }
+        HubAction::SetProviderReputation {
+            provider_wallet,
+            reputation_score,
+            note,
+        } => {
+            let provider =
+                proposal_provider(&mut ctx.accounts.provider, hub.key(), provider_wallet)?;
+            apply_reputation(provider, reputation_score, note)?;
+        }
+        HubAction::AddCourse { course } => {
+            let course_account = proposal_course(&mut ctx.accounts.course, course)?;
+            let queue = review_queue(&mut ctx.accounts.course_review_queue)?;
+            accept_course(hub, course_account, queue)?;
+        }
+        HubAction::RemoveCourse { course } => {
+            let course_account = proposal_course(&mut ctx.accounts.course, course)?;
+            let queue = review_queue(&mut ctx.accounts.course_review_queue)?;
+            remove_course(hub, course_account, queue)?;
+        }
+        HubAction::RejectCourse { course, reason } => {
+            let course_account = proposal_course(&mut ctx.accounts.course, course)?;
+            let queue = review_queue(&mut ctx.accounts.course_review_queue)?;
+            reject_course_in_review(hub.key(), governance_key, course_account, queue, reason)?;
+        }
+        HubAction::UpdateConfig { config } => {
+            apply_hub_config(hub, config, governance_key)?;
+        }
+        HubAction::CreateCourseList { .. } => {
+            return err!(GovernanceError::ActionAccountMismatch);
+        }
+        HubAction::AddCourseToList {
+            course_list_index,
+            course,
+        } => {
+            let course_account = proposal_course(&mut ctx.accounts.course, course)?;
+            let course_list = proposal_course_list(&mut ctx.accounts.course_list)?;
+            add_listed_course(hub, course_list, course_list_index, course_account)?;
+        }
+        HubAction::RemoveCourseFromList {
+            course_list_index,
+            course,
+            remove_reference_if_empty,
+        } => {
+            let course_list = proposal_course_list(&mut ctx.accounts.course_list)?;
+            remove_listed_course(
+                hub,
+                course_list,
+                course_list_index,
+                course,
+                remove_reference_if_empty,
+            )?;
+        }
+        HubAction::SetCourseListNext {
+            course_list_index,
+            next_list,
+        } => {
+            let course_list = proposal_course_list(&mut ctx.accounts.course_list)?;
+            link_course_list(course_list, course_list_index, next_list)?;
+        }
+        HubAction::TransferAuthority { new_authority } => {
+            propose_authority(hub, new_authority)?;
+        }
+        HubAction::CancelAuthorityTransfer => {
+            cancel_authority_transfer(hub)?;
+        }
+        HubAction::SetOperator { operator } => {
+            set_operator(hub, operator)?;
+        }
+        HubAction::AddMember { member } => {
+            ctx.accounts.governance.add_member(member)?;
+        }
+        HubAction::RemoveMember { member } => {
+            ctx.accounts.governance.remove_member(&member)?;
+        }
+        HubAction::SetThreshold { threshold } => {
+            ctx.accounts.governance.set_threshold(threshold)?;
+        }
+    }
+
+    mark_executed(
+        &mut ctx.accounts.proposal,
+        &mut ctx.accounts.governance,
+        ctx.accounts.executor.key(),
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(course_list_index: u16)]
+pub struct ExecuteCourseListProposal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            HubProposal::SEED_PREFIX.as_bytes(),
+            governance.key().as_ref(),
+            &proposal.index.to_le_bytes(),
+        ],
+        bump,
+        has_one = governance
+    )]
+    pub proposal: Account<'info, HubProposal>,
+    #[account(
+        mut,
+        seeds = [HubGovernance::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump,
+        has_one = hub
+    )]
+    pub governance: Account<'info, HubGovernance>,
+    #[account(
+        mut,
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.authority == governance.key() @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + CourseList::INIT_SPACE,
+        seeds = [
+            CourseList::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            &course_list_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub course_list: Account<'info, CourseList>,
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Execute an approved `CreateCourseList` proposal; the executor pays for the new list account.
+pub fn execute_course_list_proposal(
+    ctx: Context<ExecuteCourseListProposal>,
+    course_list_index: u16,
+) -> Result<()> {
+    check_executable(&ctx.accounts.proposal, &ctx.accounts.governance)?;
+    require!(
+        matches!(
+            ctx.accounts.proposal.action,
+            HubAction::CreateCourseList { course_list_index: index } if index == course_list_index
+        ),
+        GovernanceError::ActionAccountMismatch
+    );
+
+    init_course_list(
+        &mut ctx.accounts.hub,
+        &mut ctx.accounts.course_list,
+        course_list_index,
+    )?;
+
+    mark_executed(
+        &mut ctx.accounts.proposal,
+        &mut ctx.accounts.governance,
+        ctx.accounts.executor.key(),
+    )
+}
+
+fn check_executable(proposal: &HubProposal, governance: &HubGovernance) -> Result<()> {
+    require!(
+        proposal.status == ProposalStatus::Open,
+        GovernanceError::ProposalNotOpen
+    );
+    require!(
+        proposal.approval_count(governance) >= governance.threshold as usize,
+        GovernanceError::ThresholdNotReached
+    );
+    Ok(())
+}
+
+fn proposal_provider<'a, 'info>(
+    provider: &'a mut Option<Account<'info, Provider>>,
+    hub: Pubkey,
+    provider_wallet: Pubkey,
+) -> Result<&'a mut Account<'info, Provider>> {
+    let provider = provider
+        .as_mut()
+        .ok_or(GovernanceError::MissingActionAccount)?;
+    let (expected_provider, _) = Pubkey::find_program_address(
+        &[
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.as_ref(),
+            provider_wallet.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require!(
+        provider.key() == expected_provider,
+        GovernanceError::ActionAccountMismatch
+    );
+    Ok(provider)
+}
+
+fn provider_queue<'a, 'info>(
+    queue: &'a mut Option<Account<'info, PendingProviderQueue>>,
+) -> Result<&'a mut Account<'info, PendingProviderQueue>> {
+    let queue = queue
+        .as_mut()
+        .ok_or(GovernanceError::MissingActionAccount)?;
+    Ok(queue)
+}
+
+fn proposal_course_list<'a, 'info>(
+    course_list: &'a mut Option<Account<'info, CourseList>>,
+) -> Result<&'a mut Account<'info, CourseList>> {
+    let course_list = course_list
+        .as_mut()
+        .ok_or(GovernanceError::MissingActionAccount)?;
+    Ok(course_list)
+}
+
+fn proposal_course<'a, 'info>(
+    course: &'a mut Option<Account<'info, Course>>,
+    expected: Pubkey,
+) -> Result<&'a mut Account<'info, Course>> {
+    let course = course
+        .as_mut()
+        .ok_or(GovernanceError::MissingActionAccount)?;
+    require!(
+        course.key() == expected,
+        GovernanceError::ActionAccountMismatch
+    );
+    Ok(course)
+}
+
+fn review_queue<'a, 'info>(
+    queue: &'a mut Option<Account<'info, CourseReviewQueue>>,
+) -> Result<&'a mut Account<'info, CourseReviewQueue>> {
+    let queue = queue
+        .as_mut()
+        .ok_or(GovernanceError::MissingActionAccount)?;
+    Ok(queue)
+}
+
+fn mark_executed(
+    proposal: &mut Account<HubProposal>,
+    governance: &mut Account<HubGovernance>,
+    executor: Pubkey,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    proposal.status = ProposalStatus::Executed;
+    proposal.executed_at = Some(now);
+    governance.updated_at = now;
+
+    emit!(HubProposalExecuted {
+        proposal: proposal.key(),
+        executor,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -1,36 +1,75 @@
 use crate::events::*;
-use crate::state::{Course, CourseList, Hub, HubConfig, Provider};
-use crate::types::{CourseStatus, HubError};
+use crate::handlers::course::change_course_status;
+use crate::state::{
+    Course, CourseList, CourseReviewQueue, Hub, HubConfig, PendingProviderQueue, Provider,
+};
+use crate::types::{CourseStatus, CourseStatusActor, HubError, ProviderError};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
+#[instruction(hub_id: String)]
 pub struct InitializeHub<'info> {
     #[account(
         init,
         payer = authority,
         space = 8 + Hub::INIT_SPACE,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub_id.as_bytes()],
         bump
     )]
     pub hub: Account<'info, Hub>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingProviderQueue::INIT_SPACE,
+        seeds = [PendingProviderQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub provider_queue: Account<'info, PendingProviderQueue>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CourseReviewQueue::INIT_SPACE,
+        seeds = [CourseReviewQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub course_review_queue: Account<'info, CourseReviewQueue>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn initialize_hub(ctx: Context<InitializeHub>) -> Result<()> {
+pub fn initialize_hub(ctx: Context<InitializeHub>, hub_id: String) -> Result<()> {
+    require!(
+        !hub_id.is_empty() && hub_id.len() <= Hub::MAX_HUB_ID_LEN,
+        HubError::InvalidHubId
+    );
+
     let hub = &mut ctx.accounts.hub;
     let clock = Clock::get()?;
 
+    hub.hub_id = hub_id.clone();
     hub.authority = ctx.accounts.authority.key();
     hub.accepted_providers = Vec::new();
     hub.accepted_courses = Vec::new();
     hub.created_at = clock.unix_timestamp;
     hub.updated_at = clock.unix_timestamp;
     hub.config = HubConfig::default();
+    hub.pending_authority = None;
+    hub.operator = None;
+
+    let provider_queue = &mut ctx.accounts.provider_queue;
+    provider_queue.hub = hub.key();
+    provider_queue.updated_at = clock.unix_timestamp;
+    provider_queue.providers = Vec::new();
+
+    let course_review_queue = &mut ctx.accounts.course_review_queue;
+    course_review_queue.hub = hub.key();
+    course_review_queue.updated_at = clock.unix_timestamp;
+    course_review_queue.courses = Vec::new();
 
     // Emit hub initialized event
     emit!(HubInitialized {
+        hub_id,
         authority: hub.authority,
         timestamp: clock.unix_timestamp,
     });
@@ -42,7 +81,7 @@ pub fn initialize_hub(ctx: Context<InitializeHub>) -> Result<()> {
 pub struct UpdateHubConfig<'info> {
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -51,12 +90,16 @@ pub struct UpdateHubConfig<'info> {
 }
 
 pub fn update_hub_config(ctx: Context<UpdateHubConfig>, config: HubConfig) -> Result<()> {
-    let hub = &mut ctx.accounts.hub;
+    apply_hub_config(&mut ctx.accounts.hub, config, ctx.accounts.authority.key())
+}
+
+/// Shared by the direct authority path and executed governance proposals
+pub(crate) fn apply_hub_config(hub: &mut Hub, config: HubConfig, authority: Pubkey) -> Result<()> {
     hub.update_config(config)?;
 
     // Emit hub config updated event
     emit!(HubConfigUpdated {
-        authority: ctx.accounts.authority.key(),
+        authority,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
@@ -67,7 +110,7 @@ pub fn update_hub_config(ctx: Context<UpdateHubConfig>, config: HubConfig) -> Re
 pub struct AddAcceptedProvider<'info> {
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -84,13 +127,29 @@ pub struct AddAcceptedProvider<'info> {
     pub provider: Account<'info, Provider>,
     /// CHECK: Provider wallet being added
     pub provider_wallet: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [PendingProviderQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub provider_queue: Account<'info, PendingProviderQueue>,
 }
 
 pub fn add_accepted_provider(ctx: Context<AddAcceptedProvider>) -> Result<()> {
-    let hub = &mut ctx.accounts.hub;
-    let provider_wallet = ctx.accounts.provider_wallet.key();
+    accept_provider(
+        &mut ctx.accounts.hub,
+        &mut ctx.accounts.provider_queue,
+        ctx.accounts.provider_wallet.key(),
+    )
+}
 
+pub(crate) fn accept_provider(
+    hub: &mut Hub,
+    provider_queue: &mut PendingProviderQueue,
+    provider_wallet: Pubkey,
+) -> Result<()> {
     hub.add_provider(provider_wallet)?;
+    provider_queue.remove(&provider_wallet)?;
 
     // Emit provider accepted event
     emit!(ProviderAccepted {
@@ -106,7 +165,7 @@ pub fn add_accepted_provider(ctx: Context<AddAcceptedProvider>) -> Result<()> {
 pub struct RemoveAcceptedProvider<'info> {
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -117,9 +176,10 @@ pub struct RemoveAcceptedProvider<'info> {
 }
 
 pub fn remove_accepted_provider(ctx: Context<RemoveAcceptedProvider>) -> Result<()> {
-    let hub = &mut ctx.accounts.hub;
-    let provider_wallet = ctx.accounts.provider_wallet.key();
+    remove_provider(&mut ctx.accounts.hub, ctx.accounts.provider_wallet.key())
+}
 
+pub(crate) fn remove_provider(hub: &mut Hub, provider_wallet: Pubkey) -> Result<()> {
     hub.remove_provider(&provider_wallet)?;
 
     // Emit provider removed event
@@ -132,11 +192,230 @@ pub fn remove_accepted_provider(ctx: Context<RemoveAcceptedProvider>) -> Result<
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct AcceptPendingProvider<'info> {
+    #[account(mut, seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(
+        mut,
+        seeds = [PendingProviderQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub provider_queue: Account<'info, PendingProviderQueue>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider_wallet.key().as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    /// CHECK: Provider wallet being accepted
+    pub provider_wallet: AccountInfo<'info>,
+}
+
+/// Permissionless re-check of a queued provider against the current hub config,
+/// e.g. after its reputation was raised or approval was switched off.
+pub fn accept_pending_provider(ctx: Context<AcceptPendingProvider>) -> Result<()> {
+    let hub = &mut ctx.accounts.hub;
+    let provider = &ctx.accounts.provider;
+
+    require!(
+        ctx.accounts.provider_queue.contains(&provider.wallet),
+        HubError::ProviderNotPending
+    );
+    require!(!provider.suspended, ProviderError::ProviderSuspended);
+    require!(
+        hub.config.auto_accepts(provider.reputation_score),
+        HubError::ProviderApprovalRequired
+    );
+
+    hub.add_provider(provider.wallet)?;
+    ctx.accounts.provider_queue.remove(&provider.wallet)?;
+
+    emit!(ProviderAccepted {
+        hub_authority: hub.authority,
+        provider: provider.wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RejectPendingProvider<'info> {
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PendingProviderQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub provider_queue: Account<'info, PendingProviderQueue>,
+    /// CHECK: Provider wallet being rejected
+    pub provider_wallet: AccountInfo<'info>,
+}
+
+pub fn reject_pending_provider(ctx: Context<RejectPendingProvider>) -> Result<()> {
+    reject_provider(
+        ctx.accounts.hub.authority,
+        &mut ctx.accounts.provider_queue,
+        ctx.accounts.provider_wallet.key(),
+    )
+}
+
+pub(crate) fn reject_provider(
+    hub_authority: Pubkey,
+    provider_queue: &mut PendingProviderQueue,
+    provider_wallet: Pubkey,
+) -> Result<()> {
+    require!(
+        provider_queue.remove(&provider_wallet)?,
+        HubError::ProviderNotPending
+    );
+
+    emit!(ProviderRejected {
+        hub_authority,
+        provider: provider_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PruneProviderQueue<'info> {
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PendingProviderQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub provider_queue: Account<'info, PendingProviderQueue>,
+}
+
+/// Reject several pending providers at once, e.g. to clear a queue flooded with
+/// throwaway registrations. Wallets that are not queued are ignored.
+pub fn prune_provider_queue(ctx: Context<PruneProviderQueue>, wallets: Vec<Pubkey>) -> Result<()> {
+    let hub_authority = ctx.accounts.hub.authority;
+    let timestamp = Clock::get()?.unix_timestamp;
+    let provider_queue = &mut ctx.accounts.provider_queue;
+
+    for wallet in wallets {
+        if provider_queue.remove(&wallet)? {
+            emit!(ProviderRejected {
+                hub_authority,
+                provider: wallet,
+                timestamp,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManageProviderStanding<'info> {
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider_wallet.key().as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    /// CHECK: Provider wallet whose standing is being changed
+    pub provider_wallet: AccountInfo<'info>,
+}
+
+/// Suspend a provider; suspended providers cannot create courses, add resources or approve credentials.
+pub fn suspend_provider(ctx: Context<ManageProviderStanding>, note: Option<String>) -> Result<()> {
+    require!(
+        ctx.accounts.provider_wallet.key() != ctx.accounts.authority.key(),
+        ProviderError::CannotSuspendSelf
+    );
+
+    apply_suspension(&mut ctx.accounts.provider, note)
+}
+
+pub(crate) fn apply_suspension(provider: &mut Provider, note: Option<String>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    provider.suspend(note, now)?;
+
+    emit!(ProviderSuspended {
+        provider: provider.wallet,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn unsuspend_provider(ctx: Context<ManageProviderStanding>) -> Result<()> {
+    lift_suspension(&mut ctx.accounts.provider)
+}
+
+pub(crate) fn lift_suspension(provider: &mut Provider) -> Result<()> {
+    provider.unsuspend()?;
+
+    emit!(ProviderUnsuspended {
+        provider: provider.wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn update_provider_reputation(
+    ctx: Context<ManageProviderStanding>,
+    reputation_score: u64,
+    note: Option<String>,
+) -> Result<()> {
+    apply_reputation(&mut ctx.accounts.provider, reputation_score, note)
+}
+
+pub(crate) fn apply_reputation(
+    provider: &mut Provider,
+    reputation_score: u64,
+    note: Option<String>,
+) -> Result<()> {
+    provider.set_reputation(reputation_score, &note)?;
+
+    emit!(ProviderReputationUpdated {
+        provider: provider.wallet,
+        reputation_score,
+        note,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct TransferHubAuthority<'info> {
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == current_authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -146,19 +425,99 @@ pub struct TransferHubAuthority<'info> {
     pub new_authority: AccountInfo<'info>,
 }
 
+/// Propose a new hub authority. Nothing changes until the proposed wallet signs `accept_hub_authority`.
 pub fn transfer_hub_authority(ctx: Context<TransferHubAuthority>) -> Result<()> {
+    propose_authority(&mut ctx.accounts.hub, ctx.accounts.new_authority.key())
+}
+
+pub(crate) fn propose_authority(hub: &mut Hub, new_authority: Pubkey) -> Result<()> {
+    require!(
+        new_authority != hub.authority,
+        HubError::InvalidPendingAuthority
+    );
+
+    hub.pending_authority = Some(new_authority);
+    hub.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(HubAuthorityTransferProposed {
+        authority: hub.authority,
+        pending_authority: new_authority,
+        timestamp: hub.updated_at,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn set_operator(hub: &mut Hub, operator: Option<Pubkey>) -> Result<()> {
+    hub.operator = operator;
+    hub.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(HubOperatorSet {
+        authority: hub.authority,
+        operator,
+        timestamp: hub.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptHubAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.pending_authority == Some(new_authority.key()) @ HubError::NotPendingAuthority
+    )]
+    pub hub: Account<'info, Hub>,
+    pub new_authority: Signer<'info>,
+}
+
+pub fn accept_hub_authority(ctx: Context<AcceptHubAuthority>) -> Result<()> {
     let hub = &mut ctx.accounts.hub;
     let new_authority = ctx.accounts.new_authority.key();
     let old_authority = hub.authority;
 
     hub.authority = new_authority;
+    hub.pending_authority = None;
     hub.updated_at = Clock::get()?.unix_timestamp;
 
     // Emit authority transferred event
     emit!(HubAuthorityTransferred {
         old_authority,
         new_authority,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: hub.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelHubAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_hub_authority_transfer(ctx: Context<CancelHubAuthorityTransfer>) -> Result<()> {
+    cancel_authority_transfer(&mut ctx.accounts.hub)
+}
+
+pub(crate) fn cancel_authority_transfer(hub: &mut Hub) -> Result<()> {
+    let Some(pending_authority) = hub.pending_authority.take() else {
+        return err!(HubError::NoPendingAuthority);
+    };
+    hub.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(HubAuthorityTransferCancelled {
+        authority: hub.authority,
+        pending_authority,
+        timestamp: hub.updated_at,
     });
 
     Ok(())
@@ -168,7 +527,7 @@ pub fn transfer_hub_authority(ctx: Context<TransferHubAuthority>) -> Result<()>
 pub struct AddAcceptedCourse<'info> {
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -176,19 +535,46 @@ pub struct AddAcceptedCourse<'info> {
     pub authority: Signer<'info>,
     #[account(mut)]
     pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        seeds = [CourseReviewQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub course_review_queue: Account<'info, CourseReviewQueue>,
 }
 
 pub fn add_accepted_course(ctx: Context<AddAcceptedCourse>) -> Result<()> {
-    let hub = &mut ctx.accounts.hub;
-    let course = &mut ctx.accounts.course;
+    accept_course(
+        &mut ctx.accounts.hub,
+        &mut ctx.accounts.course,
+        &mut ctx.accounts.course_review_queue,
+    )
+}
 
+pub(crate) fn accept_course(
+    hub: &mut Account<Hub>,
+    course: &mut Account<Course>,
+    course_review_queue: &mut CourseReviewQueue,
+) -> Result<()> {
+    require!(
+        course.key() == course.expected_address(&hub.key(), &crate::ID),
+        HubError::CourseNotInHub
+    );
     require!(
         hub.is_provider_accepted(&course.provider),
         HubError::ProviderNotAccepted
     );
 
+    let authority = hub.authority;
+    change_course_status(
+        course,
+        CourseStatus::Accepted,
+        CourseStatusActor::Hub,
+        None,
+        authority,
+    )?;
     hub.add_course(course.key())?;
-    course.update_status(CourseStatus::Accepted, None)?;
+    course_review_queue.remove(&course.key(), course.updated);
 
     emit!(CourseAccepted {
         hub_authority: hub.authority,
@@ -204,7 +590,7 @@ pub fn add_accepted_course(ctx: Context<AddAcceptedCourse>) -> Result<()> {
 pub struct RemoveAcceptedCourse<'info> {
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -212,14 +598,39 @@ pub struct RemoveAcceptedCourse<'info> {
     pub authority: Signer<'info>,
     #[account(mut)]
     pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        seeds = [CourseReviewQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub course_review_queue: Account<'info, CourseReviewQueue>,
 }
 
 pub fn remove_accepted_course(ctx: Context<RemoveAcceptedCourse>) -> Result<()> {
-    let hub = &mut ctx.accounts.hub;
-    let course = &mut ctx.accounts.course;
+    remove_course(
+        &mut ctx.accounts.hub,
+        &mut ctx.accounts.course,
+        &mut ctx.accounts.course_review_queue,
+    )
+}
 
+pub(crate) fn remove_course(
+    hub: &mut Hub,
+    course: &mut Account<Course>,
+    course_review_queue: &mut CourseReviewQueue,
+) -> Result<()> {
+    // Archived courses keep their status; the hub only drops its stale reference
+    if course.status != CourseStatus::Archived {
+        change_course_status(
+            course,
+            CourseStatus::InReview,
+            CourseStatusActor::Hub,
+            None,
+            hub.authority,
+        )?;
+        course_review_queue.enqueue(course.key(), course.provider, course.updated)?;
+    }
     hub.remove_course(&course.key())?;
-    course.update_status(CourseStatus::InReview, None)?;
 
     emit!(CourseRemovedFromHub {
         hub_authority: hub.authority,
@@ -230,12 +641,109 @@ pub fn remove_accepted_course(ctx: Context<RemoveAcceptedCourse>) -> Result<()>
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct RejectCourse<'info> {
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        seeds = [CourseReviewQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub course_review_queue: Account<'info, CourseReviewQueue>,
+}
+
+/// Hub sends a course under review back to Draft with a reason for the provider
+pub fn reject_course(ctx: Context<RejectCourse>, reason: String) -> Result<()> {
+    let hub_key = ctx.accounts.hub.key();
+    let authority = ctx.accounts.hub.authority;
+    reject_course_in_review(
+        hub_key,
+        authority,
+        &mut ctx.accounts.course,
+        &mut ctx.accounts.course_review_queue,
+        reason,
+    )
+}
+
+pub(crate) fn reject_course_in_review(
+    hub_key: Pubkey,
+    hub_authority: Pubkey,
+    course: &mut Account<Course>,
+    course_review_queue: &mut CourseReviewQueue,
+    reason: String,
+) -> Result<()> {
+    require!(
+        course.key() == course.expected_address(&hub_key, &crate::ID),
+        HubError::CourseNotInHub
+    );
+
+    change_course_status(
+        course,
+        CourseStatus::Draft,
+        CourseStatusActor::Hub,
+        Some(reason.clone()),
+        hub_authority,
+    )?;
+    course_review_queue.remove(&course.key(), course.updated);
+
+    emit!(CourseRejected {
+        hub_authority,
+        course: course.key(),
+        provider: course.provider,
+        reason,
+        timestamp: course.updated,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PruneCourseReviewQueue<'info> {
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CourseReviewQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub course_review_queue: Account<'info, CourseReviewQueue>,
+}
+
+/// Drop review queue entries by course key, e.g. for courses that were closed while in review.
+/// Keys that are not queued are ignored.
+pub fn prune_course_review_queue(
+    ctx: Context<PruneCourseReviewQueue>,
+    courses: Vec<Pubkey>,
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let course_review_queue = &mut ctx.accounts.course_review_queue;
+
+    for course in courses {
+        course_review_queue.remove(&course, timestamp);
+    }
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(course_list_index: u16)]
 pub struct CreateCourseList<'info> {
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -258,8 +766,18 @@ pub struct CreateCourseList<'info> {
 }
 
 pub fn create_course_list(ctx: Context<CreateCourseList>, course_list_index: u16) -> Result<()> {
-    let hub = &mut ctx.accounts.hub;
-    let list = &mut ctx.accounts.course_list;
+    init_course_list(
+        &mut ctx.accounts.hub,
+        &mut ctx.accounts.course_list,
+        course_list_index,
+    )
+}
+
+pub(crate) fn init_course_list(
+    hub: &mut Account<Hub>,
+    list: &mut Account<CourseList>,
+    course_list_index: u16,
+) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
 
     list.hub = hub.key();
@@ -279,7 +797,7 @@ pub fn create_course_list(ctx: Context<CreateCourseList>, course_list_index: u16
 pub struct AddCourseToList<'info> {
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -301,10 +819,21 @@ pub struct AddCourseToList<'info> {
 }
 
 pub fn add_course_to_list(ctx: Context<AddCourseToList>, course_list_index: u16) -> Result<()> {
-    let hub = &ctx.accounts.hub;
-    let course_list = &mut ctx.accounts.course_list;
-    let course = &ctx.accounts.course;
+    add_listed_course(
+        &ctx.accounts.hub,
+        &mut ctx.accounts.course_list,
+        course_list_index,
+        &ctx.accounts.course,
+    )
+}
 
+/// Only accepted courses created under this hub can be listed
+pub(crate) fn add_listed_course(
+    hub: &Account<Hub>,
+    course_list: &mut Account<CourseList>,
+    course_list_index: u16,
+    course: &Account<Course>,
+) -> Result<()> {
     require!(
         hub.accepted_courses.contains(&course_list.key()),
         HubError::CourseListNotRegistered
@@ -313,6 +842,14 @@ pub fn add_course_to_list(ctx: Context<AddCourseToList>, course_list_index: u16)
         course_list.index == course_list_index,
         HubError::CourseListNotRegistered
     );
+    require!(
+        course.key() == course.expected_address(&hub.key(), &crate::ID),
+        HubError::CourseNotInHub
+    );
+    require!(
+        course.status == CourseStatus::Accepted,
+        HubError::CourseNotAccepted
+    );
 
     course_list.add_course(course.key())?;
     Ok(())
@@ -323,7 +860,7 @@ pub fn add_course_to_list(ctx: Context<AddCourseToList>, course_list_index: u16)
 pub struct RemoveCourseFromList<'info> {
     #[account(
         mut,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -349,10 +886,22 @@ pub fn remove_course_from_list(
     course_list_index: u16,
     remove_reference_if_empty: bool,
 ) -> Result<()> {
-    let hub = &mut ctx.accounts.hub;
-    let course_list = &mut ctx.accounts.course_list;
-    let course = &ctx.accounts.course;
+    remove_listed_course(
+        &mut ctx.accounts.hub,
+        &mut ctx.accounts.course_list,
+        course_list_index,
+        ctx.accounts.course.key(),
+        remove_reference_if_empty,
+    )
+}
 
+pub(crate) fn remove_listed_course(
+    hub: &mut Hub,
+    course_list: &mut Account<CourseList>,
+    course_list_index: u16,
+    course: Pubkey,
+    remove_reference_if_empty: bool,
+) -> Result<()> {
     require!(
         hub.accepted_courses.contains(&course_list.key()),
         HubError::CourseListNotRegistered
@@ -362,7 +911,7 @@ pub fn remove_course_from_list(
         HubError::CourseListNotRegistered
     );
 
-    course_list.remove_course(&course.key())?;
+    course_list.remove_course(&course)?;
 
     if remove_reference_if_empty && course_list.courses.is_empty() {
         hub.remove_course(&course_list.key())?;
@@ -375,7 +924,7 @@ pub fn remove_course_from_list(
 #[instruction(course_list_index: u16)]
 pub struct SetCourseListNext<'info> {
     #[account(
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
@@ -397,13 +946,20 @@ pub struct SetCourseListNext<'info> {
 }
 
 pub fn set_course_list_next(ctx: Context<SetCourseListNext>, course_list_index: u16) -> Result<()> {
-    let course_list = &mut ctx.accounts.course_list;
+    let next_key = ctx.accounts.next_list.as_ref().map(|account| account.key());
+    link_course_list(&mut ctx.accounts.course_list, course_list_index, next_key)
+}
+
+pub(crate) fn link_course_list(
+    course_list: &mut CourseList,
+    course_list_index: u16,
+    next_key: Option<Pubkey>,
+) -> Result<()> {
     require!(
         course_list.index == course_list_index,
         HubError::CourseListNotRegistered
     );
 
-    let next_key = ctx.accounts.next_list.as_ref().map(|account| account.key());
     course_list.next = next_key;
     course_list.updated_at = Clock::get()?.unix_timestamp;
     Ok(())
@@ -414,15 +970,30 @@ pub struct CloseHub<'info> {
     #[account(
         mut,
         close = authority,
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump,
         constraint = hub.authority == authority.key() @ HubError::UnauthorizedHubAction
     )]
     pub hub: Account<'info, Hub>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PendingProviderQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub provider_queue: Account<'info, PendingProviderQueue>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [CourseReviewQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub course_review_queue: Account<'info, CourseReviewQueue>,
     #[account(mut)]
     pub authority: Signer<'info>,
 }
 
+/// Close the hub together with its review queues so the hub id can be initialized again
 pub fn close_hub(_ctx: Context<CloseHub>) -> Result<()> {
     Ok(())
 }
@@ -12,13 +12,25 @@
 pub mod activity;
 pub mod course;
 pub mod credential;
+pub mod degree;
+pub mod enrollment;
+pub mod governance;
 pub mod hub;
+pub mod progress;
 pub mod provider;
+pub mod recognition;
 pub mod resource;
+pub mod rubric;
 
 pub use activity::*;
 pub use course::*;
 pub use credential::*;
+pub use degree::*;
+pub use enrollment::*;
+pub use governance::*;
 pub use hub::*;
+pub use progress::*;
 pub use provider::*;
+pub use recognition::*;
 pub use resource::*;
+pub use rubric::*;
@@ -0,0 +1,106 @@
+use crate::events::*;
+use crate::state::{Course, CourseProgress, Submission};
+use crate::types::{CourseError, ResourceError, SubmissionStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeCourseProgress<'info> {
+    #[account(
+        init,
+        payer = student,
+        space = 8 + CourseProgress::INIT_SPACE,
+        seeds = [
+            CourseProgress::SEED_PREFIX.as_bytes(),
+            course.key().as_ref(),
+            student.key().as_ref(),
+        ],
+        bump
+    )]
+    pub course_progress: Account<'info, CourseProgress>,
+    pub course: Account<'info, Course>,
+    #[account(mut)]
+    pub student: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Student opens a progress account for a course
+pub fn initialize_course_progress(ctx: Context<InitializeCourseProgress>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let progress = &mut ctx.accounts.course_progress;
+
+    progress.course = ctx.accounts.course.key();
+    progress.student = ctx.accounts.student.key();
+    progress.module_grades = Vec::new();
+    progress.weighted_grade = 0.0;
+    progress.graded_weight = 0;
+    progress.completed_workload = 0;
+    progress.created_at = now;
+    progress.updated_at = now;
+
+    Ok(())
+}
+
+/// Anyone can fold a graded submission into the student's course progress.
+#[derive(Accounts)]
+pub struct UpdateCourseProgress<'info> {
+    #[account(
+        mut,
+        seeds = [
+            CourseProgress::SEED_PREFIX.as_bytes(),
+            course.key().as_ref(),
+            course_progress.student.as_ref(),
+        ],
+        bump
+    )]
+    pub course_progress: Account<'info, CourseProgress>,
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Submission::SEED_PREFIX.as_bytes(),
+            submission.resource.as_ref(),
+            submission.student.as_ref(),
+        ],
+        bump,
+        constraint = submission.student == course_progress.student @ ResourceError::UnauthorizedResourceAuthority
+    )]
+    pub submission: Account<'info, Submission>,
+}
+
+pub fn update_course_progress(ctx: Context<UpdateCourseProgress>) -> Result<()> {
+    let course = &ctx.accounts.course;
+    let submission = &ctx.accounts.submission;
+
+    require!(
+        course
+            .modules
+            .iter()
+            .any(|m| m.resource == submission.resource),
+        CourseError::ModuleNotFound
+    );
+    let (grade, accepted) = match (&submission.status, submission.grade) {
+        (SubmissionStatus::Graded, Some(grade)) => (grade, false),
+        (SubmissionStatus::Accepted, Some(grade)) => (grade, true),
+        _ => return err!(ResourceError::SubmissionNotGraded),
+    };
+
+    let progress = &mut ctx.accounts.course_progress;
+    progress.record_module_grade(
+        course,
+        submission.resource,
+        submission.key(),
+        grade,
+        accepted,
+    );
+    progress.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(CourseProgressUpdated {
+        course: progress.course,
+        student: progress.student,
+        weighted_grade: progress.weighted_grade,
+        graded_weight: progress.graded_weight,
+        completed_workload: progress.completed_workload,
+        timestamp: progress.updated_at,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::events::*;
-use crate::state::{Hub, Provider};
+use crate::state::{Hub, PendingProviderQueue, Provider};
+use crate::types::{StaffRole, TrustLevel};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -18,10 +19,18 @@ pub struct InitializeProvider<'info> {
     pub provider_account: Account<'info, Provider>,
     /// Hub that this provider is registered under
     #[account(
-        seeds = [Hub::SEED_PREFIX.as_bytes()],
+        mut,
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
         bump
     )]
     pub hub: Account<'info, Hub>,
+    /// Queue for providers that need hub review
+    #[account(
+        mut,
+        seeds = [PendingProviderQueue::SEED_PREFIX.as_bytes(), hub.key().as_ref()],
+        bump
+    )]
+    pub provider_queue: Account<'info, PendingProviderQueue>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -46,6 +55,16 @@ pub fn initialize_provider(
     provider.provider_type = provider_type.clone();
     provider.registered_at = clock.unix_timestamp;
     provider.endorsers = Vec::new();
+    provider.staff = Vec::new();
+    provider.suspended = false;
+    provider.suspended_at = None;
+    provider.suspension_note = None;
+    provider.suspension_count = 0;
+    provider.reputation_score = 0;
+    provider.approved_credentials_count = 0;
+    provider.revoked_credentials_count = 0;
+    provider.trust_level = TrustLevel::New;
+    provider.trust_level_updated_at = clock.unix_timestamp;
 
     // Emit provider registered event
     emit!(ProviderRegistered {
@@ -55,6 +74,36 @@ pub fn initialize_provider(
         timestamp: clock.unix_timestamp,
     });
 
+    // Honour the hub config: accept straight away or queue for review
+    let hub = &mut ctx.accounts.hub;
+    if hub.config.auto_accepts(provider.reputation_score) {
+        hub.add_provider(provider.wallet)?;
+
+        emit!(ProviderAccepted {
+            hub_authority: hub.authority,
+            provider: provider.wallet,
+            timestamp: clock.unix_timestamp,
+        });
+    } else {
+        // A full queue drops its oldest registration, which counts as a rejection
+        if let Some(evicted) = ctx
+            .accounts
+            .provider_queue
+            .enqueue(provider.wallet, clock.unix_timestamp)
+        {
+            emit!(ProviderRejected {
+                hub_authority: hub.authority,
+                provider: evicted.wallet,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        emit!(ProviderQueued {
+            provider: provider.wallet,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     Ok(())
 }
 
@@ -71,7 +120,7 @@ pub struct AddProviderEndorser<'info> {
         constraint = provider_account.wallet == provider_authority.key() @ crate::types::ProviderError::UnauthorizedProviderAction
     )]
     pub provider_account: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
@@ -107,7 +156,7 @@ pub struct RemoveProviderEndorser<'info> {
         constraint = provider_account.wallet == provider_authority.key() @ crate::types::ProviderError::UnauthorizedProviderAction
     )]
     pub provider_account: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     pub provider_authority: Signer<'info>,
     /// CHECK: Endorser wallet being removed
@@ -129,6 +178,93 @@ pub fn remove_provider_endorser(ctx: Context<RemoveProviderEndorser>) -> Result<
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct ManageProviderStaff<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider_authority.key().as_ref(),
+        ],
+        bump,
+        constraint = provider_account.wallet == provider_authority.key() @ crate::types::ProviderError::UnauthorizedProviderAction
+    )]
+    pub provider_account: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub provider_authority: Signer<'info>,
+    /// CHECK: Staff wallet whose role is set or removed
+    pub staff_wallet: AccountInfo<'info>,
+}
+
+/// Assign a provider-wide staff role; courses can override it with `set_course_staff_role`
+pub fn set_provider_staff_role(ctx: Context<ManageProviderStaff>, role: StaffRole) -> Result<()> {
+    let provider = &mut ctx.accounts.provider_account;
+    let staff_wallet = ctx.accounts.staff_wallet.key();
+
+    provider.set_staff_role(staff_wallet, role)?;
+
+    emit!(StaffRoleSet {
+        provider: provider.wallet,
+        course: None,
+        staff: staff_wallet,
+        role: Some(role),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn remove_provider_staff(ctx: Context<ManageProviderStaff>) -> Result<()> {
+    let provider = &mut ctx.accounts.provider_account;
+    let staff_wallet = ctx.accounts.staff_wallet.key();
+
+    provider.remove_staff(&staff_wallet)?;
+
+    emit!(StaffRoleRemoved {
+        provider: provider.wallet,
+        course: None,
+        staff: staff_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefreshProviderTrustLevel<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider_account.wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub provider_account: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+}
+
+/// Permissionless crank: recompute the provider's trust level from its on-chain record.
+pub fn refresh_provider_trust_level(ctx: Context<RefreshProviderTrustLevel>) -> Result<()> {
+    let provider = &mut ctx.accounts.provider_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    if let Some(previous_level) = provider.refresh_trust_level(now) {
+        emit!(ProviderTrustLevelChanged {
+            provider: provider.wallet,
+            previous_level,
+            trust_level: provider.trust_level,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct CloseProvider<'info> {
     #[account(
@@ -140,15 +276,18 @@ pub struct CloseProvider<'info> {
             provider_authority.key().as_ref(),
         ],
         bump,
-        constraint = provider_account.wallet == provider_authority.key() @ crate::types::ProviderError::UnauthorizedProviderAction
+        constraint = provider_account.wallet == provider_authority.key() @ crate::types::ProviderError::UnauthorizedProviderAction,
+        constraint = !provider_account.suspended @ crate::types::ProviderError::ProviderSuspended
     )]
     pub provider_account: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
 }
 
+/// Close the provider account. Suspended providers can't close (and re-initialize) to shed
+/// their suspension and reputation.
 pub fn close_provider(_ctx: Context<CloseProvider>) -> Result<()> {
     Ok(())
 }
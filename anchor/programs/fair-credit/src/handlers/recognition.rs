@@ -0,0 +1,289 @@
+use crate::events::*;
+use crate::handlers::credential::record_verification;
+use crate::state::{
+    Course, Credential, CredentialVerificationStats, Hub, HubRecognition, Provider,
+    RecognitionScope,
+};
+use crate::types::{CourseStatus, HubError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RecognizeCourse<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HubRecognition::INIT_SPACE,
+        seeds = [
+            HubRecognition::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.key().as_ref(),
+        ],
+        bump
+    )]
+    pub recognition: Account<'info, HubRecognition>,
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.can_operate(&authority.key()) @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), source_hub.hub_id.as_bytes()],
+        bump,
+        constraint = source_hub.key() != hub.key() @ HubError::RecognitionSameHub
+    )]
+    pub source_hub: Account<'info, Hub>,
+    #[account(constraint = course.status == CourseStatus::Accepted @ HubError::CourseNotAccepted)]
+    pub course: Account<'info, Course>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Hub authority records that a course accepted in another hub counts in this hub
+pub fn recognize_course(
+    ctx: Context<RecognizeCourse>,
+    workload_factor_bps: Option<u16>,
+) -> Result<()> {
+    let source_hub = ctx.accounts.source_hub.key();
+    let course = &ctx.accounts.course;
+    require!(
+        course.expected_address(&source_hub, ctx.program_id) == course.key(),
+        HubError::CourseNotInHub
+    );
+
+    record_recognition(
+        &mut ctx.accounts.recognition,
+        ctx.accounts.hub.key(),
+        source_hub,
+        RecognitionScope::Course,
+        course.key(),
+        workload_factor_bps,
+        ctx.accounts.authority.key(),
+    )
+}
+
+#[derive(Accounts)]
+pub struct RecognizeProvider<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HubRecognition::INIT_SPACE,
+        seeds = [
+            HubRecognition::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+        ],
+        bump
+    )]
+    pub recognition: Account<'info, HubRecognition>,
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.can_operate(&authority.key()) @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), source_hub.hub_id.as_bytes()],
+        bump,
+        constraint = source_hub.key() != hub.key() @ HubError::RecognitionSameHub,
+        constraint = source_hub.is_provider_accepted(&provider.wallet) @ HubError::ProviderNotAccepted
+    )]
+    pub source_hub: Account<'info, Hub>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            source_hub.key().as_ref(),
+            provider.wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Hub authority records that every course of a provider accepted in another hub counts in this hub
+pub fn recognize_provider(
+    ctx: Context<RecognizeProvider>,
+    workload_factor_bps: Option<u16>,
+) -> Result<()> {
+    record_recognition(
+        &mut ctx.accounts.recognition,
+        ctx.accounts.hub.key(),
+        ctx.accounts.source_hub.key(),
+        RecognitionScope::Provider,
+        ctx.accounts.provider.key(),
+        workload_factor_bps,
+        ctx.accounts.authority.key(),
+    )
+}
+
+fn record_recognition(
+    recognition: &mut Account<HubRecognition>,
+    hub: Pubkey,
+    source_hub: Pubkey,
+    scope: RecognitionScope,
+    subject: Pubkey,
+    workload_factor_bps: Option<u16>,
+    authority: Pubkey,
+) -> Result<()> {
+    HubRecognition::validate_workload_factor(workload_factor_bps)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    recognition.hub = hub;
+    recognition.source_hub = source_hub;
+    recognition.scope = scope;
+    recognition.subject = subject;
+    recognition.workload_factor_bps = workload_factor_bps;
+    recognition.recognized_by = authority;
+    recognition.recognized_at = now;
+
+    emit!(HubRecognitionRecorded {
+        hub,
+        source_hub,
+        recognition: recognition.key(),
+        scope,
+        subject,
+        workload_factor_bps,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeRecognition<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            HubRecognition::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            recognition.subject.as_ref(),
+        ],
+        bump,
+        has_one = hub
+    )]
+    pub recognition: Account<'info, HubRecognition>,
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()],
+        bump,
+        constraint = hub.can_operate(&authority.key()) @ HubError::UnauthorizedHubAction
+    )]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Hub authority withdraws a recognition; covered credentials no longer verify in this hub
+pub fn revoke_recognition(ctx: Context<RevokeRecognition>) -> Result<()> {
+    emit!(HubRecognitionRevoked {
+        hub: ctx.accounts.hub.key(),
+        recognition: ctx.accounts.recognition.key(),
+        subject: ctx.accounts.recognition.subject,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Verify a credential issued in another hub against a hub that recognises its course or provider.
+/// The source hub and provider are re-checked so a recognition stops covering courses or
+/// providers that lost their standing there.
+#[derive(Accounts)]
+pub struct VerifyRecognizedCredential<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Credential::SEED_PREFIX.as_bytes(),
+            credential.course.as_ref(),
+            credential.student_wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub credential: Account<'info, Credential>,
+    #[account(
+        mut,
+        seeds = [
+            CredentialVerificationStats::SEED_PREFIX.as_bytes(),
+            credential.key().as_ref(),
+        ],
+        bump,
+        has_one = credential
+    )]
+    pub verification_stats: Account<'info, CredentialVerificationStats>,
+    #[account(constraint = course.key() == credential.course)]
+    pub course: Account<'info, Course>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(
+        seeds = [
+            HubRecognition::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            recognition.subject.as_ref(),
+        ],
+        bump,
+        has_one = hub
+    )]
+    pub recognition: Account<'info, HubRecognition>,
+    #[account(
+        seeds = [Hub::SEED_PREFIX.as_bytes(), source_hub.hub_id.as_bytes()],
+        bump,
+        constraint = source_hub.key() == recognition.source_hub @ HubError::CredentialNotRecognized,
+        constraint = source_hub.is_provider_accepted(&course.provider) @ HubError::ProviderNotAccepted
+    )]
+    pub source_hub: Account<'info, Hub>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            source_hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = !provider.suspended @ crate::types::ProviderError::ProviderSuspended
+    )]
+    pub provider: Account<'info, Provider>,
+    pub verifier: Signer<'info>,
+}
+
+pub fn verify_recognized_credential(
+    ctx: Context<VerifyRecognizedCredential>,
+    anonymous: bool,
+) -> Result<()> {
+    let recognition = &ctx.accounts.recognition;
+    let course = &ctx.accounts.course;
+
+    let covered = match recognition.scope {
+        RecognitionScope::Course => recognition.subject == course.key(),
+        RecognitionScope::Provider => recognition.subject == ctx.accounts.provider.key(),
+    };
+    require!(covered, HubError::CredentialNotRecognized);
+    require!(
+        course.expected_address(&recognition.source_hub, ctx.program_id) == course.key(),
+        HubError::CourseNotInHub
+    );
+    require!(
+        course.status == CourseStatus::Accepted,
+        HubError::CourseNotAccepted
+    );
+
+    let recognized_workload = recognition.converted_workload(course.workload_required);
+    let verifier = ctx.accounts.verifier.key();
+    record_verification(
+        &mut ctx.accounts.credential,
+        &mut ctx.accounts.verification_stats,
+        verifier,
+        anonymous,
+    )?;
+
+    emit!(RecognizedCredentialVerified {
+        credential: ctx.accounts.credential.key(),
+        hub: ctx.accounts.hub.key(),
+        recognition: ctx.accounts.recognition.key(),
+        recognized_workload,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use crate::state::*;
-use crate::types::{ResourceError, ResourceKind, ResourceStatus, SubmissionStatus};
+use crate::types::{
+    CourseStatus, LatePenaltyPolicy, ResourceError, ResourceKind, ResourceStatus, StaffCapability,
+    SubmissionStatus,
+};
 use anchor_lang::prelude::*;
 
 /// Allowed time drift for creation_timestamp (±5 minutes in seconds)
@@ -34,12 +37,14 @@ pub struct AddResource<'info> {
         seeds = [
             Provider::SEED_PREFIX.as_bytes(),
             hub.key().as_ref(),
-            provider_authority.key().as_ref(),
+            course.provider.as_ref(),
         ],
-        bump
+        bump,
+        constraint = !provider.suspended @ crate::types::ProviderError::ProviderSuspended,
+        constraint = provider.has_capability(&provider_authority.key(), Some(&course), StaffCapability::ManageCourse) @ ResourceError::UnauthorizedResourceAuthority
     )]
     pub provider: Account<'info, Provider>,
-    #[account(seeds = [Hub::SEED_PREFIX.as_bytes()], bump)]
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
     pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub provider_authority: Signer<'info>,
@@ -58,6 +63,109 @@ pub struct UpdateResourceData<'info> {
         bump
     )]
     pub resource: Account<'info, Resource>,
+    /// Mutable so a module resource's workload change reaches the course
+    #[account(
+        mut,
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = provider.has_capability(&authority.key(), Some(&course), StaffCapability::ManageCourse) @ ResourceError::UnauthorizedResourceAuthority
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetResourceMaxAttempts<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Resource::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            &resource.created.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = provider.has_capability(&authority.key(), Some(&course), StaffCapability::ManageCourse) @ ResourceError::UnauthorizedResourceAuthority
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetResourceDeadlines<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Resource::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            &resource.created.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = provider.has_capability(&authority.key(), Some(&course), StaffCapability::ManageCourse) @ ResourceError::UnauthorizedResourceAuthority
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub authority: Signer<'info>,
 }
@@ -74,6 +182,28 @@ pub struct SetResourceNostrRef<'info> {
         bump
     )]
     pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub authority: Signer<'info>,
 }
@@ -90,6 +220,28 @@ pub struct SetResourceWalrusRef<'info> {
         bump
     )]
     pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub authority: Signer<'info>,
 }
@@ -124,6 +276,12 @@ pub fn add_resource(
     resource.status = ResourceStatus::Draft;
     resource.external_id = external_id;
     resource.workload = workload;
+    resource.max_attempts = None;
+    resource.opens_at = None;
+    resource.due_at = None;
+    resource.closes_at = None;
+    resource.late_penalty = None;
+    resource.rubric = None;
     resource.course = ctx.accounts.course.key();
     resource.assets = Vec::new();
     resource.tags = tags;
@@ -147,6 +305,23 @@ pub fn update_resource_data(
         resource.name = new_name;
     }
     if let Some(new_workload) = workload {
+        // Keep the course module and course workload in sync. Module workloads are locked
+        // once the course leaves Draft, so only an actual change is rejected there.
+        let course = &mut ctx.accounts.course;
+        let module = course
+            .modules
+            .iter()
+            .find(|m| m.resource == resource.key())
+            .map(|m| (m.percentage, m.workload));
+        if let Some((percentage, module_workload)) = module {
+            if module_workload != new_workload {
+                require!(
+                    course.status == CourseStatus::Draft,
+                    ResourceError::ModuleWorkloadLocked
+                );
+                course.update_module(resource.key(), percentage, new_workload)?;
+            }
+        }
         resource.workload = Some(new_workload);
     }
     if let Some(new_tags) = tags {
@@ -158,6 +333,32 @@ pub fn update_resource_data(
     Ok(())
 }
 
+/// Limit how many attempts students get per submission; None removes the limit
+pub fn set_resource_max_attempts(
+    ctx: Context<SetResourceMaxAttempts>,
+    max_attempts: Option<u8>,
+) -> Result<()> {
+    require!(max_attempts != Some(0), ResourceError::InvalidMaxAttempts);
+    let resource = &mut ctx.accounts.resource;
+    resource.max_attempts = max_attempts;
+    resource.updated = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+/// Set the submission window and late-penalty policy of an assignment resource
+pub fn set_resource_deadlines(
+    ctx: Context<SetResourceDeadlines>,
+    opens_at: Option<i64>,
+    due_at: Option<i64>,
+    closes_at: Option<i64>,
+    late_penalty: Option<LatePenaltyPolicy>,
+) -> Result<()> {
+    let resource = &mut ctx.accounts.resource;
+    resource.set_deadlines(opens_at, due_at, closes_at, late_penalty)?;
+    resource.updated = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
 pub fn set_resource_nostr_ref(
     ctx: Context<SetResourceNostrRef>,
     nostr_d_tag: String,
@@ -166,8 +367,14 @@ pub fn set_resource_nostr_ref(
 ) -> Result<()> {
     let resource = &mut ctx.accounts.resource;
 
+    let authority = ctx.accounts.authority.key();
     require!(
-        ctx.accounts.authority.key() == resource.owner,
+        authority == resource.owner
+            || ctx.accounts.provider.has_capability(
+                &authority,
+                Some(&ctx.accounts.course),
+                StaffCapability::ManageCourse
+            ),
         ResourceError::UnauthorizedResourceAuthority
     );
 
@@ -191,8 +398,14 @@ pub fn set_resource_walrus_ref(
 ) -> Result<()> {
     let resource = &mut ctx.accounts.resource;
 
+    let authority = ctx.accounts.authority.key();
     require!(
-        ctx.accounts.authority.key() == resource.owner,
+        authority == resource.owner
+            || ctx.accounts.provider.has_capability(
+                &authority,
+                Some(&ctx.accounts.course),
+                StaffCapability::ManageCourse
+            ),
         ResourceError::UnauthorizedResourceAuthority
     );
 
@@ -324,8 +537,8 @@ pub fn set_asset_walrus_ref(ctx: Context<SetAssetWalrusRef>, walrus_blob_id: Str
     Ok(())
 }
 
+/// One submission per student and resource; later attempts go through `resubmit_submission`
 #[derive(Accounts)]
-#[instruction(submission_timestamp: i64)]
 pub struct CreateSubmission<'info> {
     #[account(
         init,
@@ -335,7 +548,6 @@ pub struct CreateSubmission<'info> {
             Submission::SEED_PREFIX.as_bytes(),
             resource.key().as_ref(),
             student.key().as_ref(),
-            &submission_timestamp.to_le_bytes(),
         ],
         bump
     )]
@@ -349,6 +561,15 @@ pub struct CreateSubmission<'info> {
         bump
     )]
     pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Enrollment::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            student.key().as_ref(),
+        ],
+        bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
     #[account(mut)]
     pub student: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -362,15 +583,172 @@ pub struct GradeSubmission<'info> {
             Submission::SEED_PREFIX.as_bytes(),
             submission.resource.as_ref(),
             submission.student.as_ref(),
-            &submission.submitted_at.to_le_bytes(),
         ],
         bump
     )]
     pub submission: Account<'info, Submission>,
+    #[account(
+        seeds = [
+            Resource::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            &resource.created.to_le_bytes(),
+        ],
+        bump,
+        constraint = resource.key() == submission.resource
+    )]
+    pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = provider.has_capability(&grader.key(), Some(&course), StaffCapability::Grade) @ ResourceError::UnauthorizedGrader
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub grader: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReturnSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Submission::SEED_PREFIX.as_bytes(),
+            submission.resource.as_ref(),
+            submission.student.as_ref(),
+        ],
+        bump
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        seeds = [
+            Resource::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            &resource.created.to_le_bytes(),
+        ],
+        bump,
+        constraint = resource.key() == submission.resource
+    )]
+    pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = provider.has_capability(&grader.key(), Some(&course), StaffCapability::Grade) @ ResourceError::UnauthorizedGrader
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
     #[account(mut)]
     pub grader: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct AcceptSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Submission::SEED_PREFIX.as_bytes(),
+            submission.resource.as_ref(),
+            submission.student.as_ref(),
+        ],
+        bump
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        seeds = [
+            Resource::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            &resource.created.to_le_bytes(),
+        ],
+        bump,
+        constraint = resource.key() == submission.resource
+    )]
+    pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = provider.has_capability(&grader.key(), Some(&course), StaffCapability::Grade) @ ResourceError::UnauthorizedGrader
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub grader: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResubmitSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Submission::SEED_PREFIX.as_bytes(),
+            submission.resource.as_ref(),
+            submission.student.as_ref(),
+        ],
+        bump,
+        constraint = submission.student == student.key() @ ResourceError::UnauthorizedResourceAuthority
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(constraint = resource.key() == submission.resource)]
+    pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Enrollment::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            student.key().as_ref(),
+        ],
+        bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    pub student: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct SetSubmissionNostrRef<'info> {
     #[account(
@@ -379,7 +757,6 @@ pub struct SetSubmissionNostrRef<'info> {
             Submission::SEED_PREFIX.as_bytes(),
             submission.resource.as_ref(),
             submission.student.as_ref(),
-            &submission.submitted_at.to_le_bytes(),
         ],
         bump
     )]
@@ -396,7 +773,6 @@ pub struct SetSubmissionWalrusRef<'info> {
             Submission::SEED_PREFIX.as_bytes(),
             submission.resource.as_ref(),
             submission.student.as_ref(),
-            &submission.submitted_at.to_le_bytes(),
         ],
         bump
     )]
@@ -407,24 +783,21 @@ pub struct SetSubmissionWalrusRef<'info> {
 
 pub fn create_submission(
     ctx: Context<CreateSubmission>,
-    submission_timestamp: i64,
     assets: Vec<Pubkey>,
     evidence_assets: Vec<Pubkey>,
 ) -> Result<()> {
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
 
-    require!(
-        submission_timestamp >= current_time.saturating_sub(TIMESTAMP_TOLERANCE)
-            && submission_timestamp <= current_time.saturating_add(TIMESTAMP_TOLERANCE),
-        ResourceError::InvalidCreationTimestamp
-    );
-
     require!(assets.len() <= 10, ResourceError::TooManyAssets);
     require!(evidence_assets.len() <= 10, ResourceError::TooManyAssets);
+    let late = ctx
+        .accounts
+        .resource
+        .check_submission_window(current_time)?;
 
     let submission = &mut ctx.accounts.submission;
-    submission.submitted_at = submission_timestamp;
+    submission.submitted_at = current_time;
     submission.updated = current_time;
     submission.resource = ctx.accounts.resource.key();
     submission.student = ctx.accounts.student.key();
@@ -438,6 +811,11 @@ pub fn create_submission(
     submission.nostr_d_tag = None;
     submission.nostr_author_pubkey = [0u8; 32];
     submission.walrus_blob_id = None;
+    submission.attempt = 1;
+    submission.attempt_submitted_at = current_time;
+    submission.attempts = Vec::new();
+    submission.late = late;
+    submission.rubric_scores = Vec::new();
 
     Ok(())
 }
@@ -447,8 +825,45 @@ pub fn grade_submission(
     grade: f64,
     feedback: Option<String>,
 ) -> Result<()> {
+    require!(
+        ctx.accounts.resource.rubric.is_none(),
+        ResourceError::RubricGradingRequired
+    );
+    let grader = ctx.accounts.grader.key();
+    let submission = &mut ctx.accounts.submission;
+    let late_penalty = ctx
+        .accounts
+        .resource
+        .late_penalty_percent(submission.attempt_submitted_at);
+    submission.grade_submission(grade, feedback, grader, late_penalty)?;
+    Ok(())
+}
+
+/// Send a submitted or graded submission back to the student for another attempt
+pub fn return_submission(ctx: Context<ReturnSubmission>, feedback: String) -> Result<()> {
+    ctx.accounts.submission.return_for_revision(feedback)
+}
+
+/// Accept a graded submission; accepted module submissions count towards the student's
+/// completed course workload once folded in with `update_course_progress`.
+pub fn accept_submission(ctx: Context<AcceptSubmission>) -> Result<()> {
+    ctx.accounts.submission.accept_submission()
+}
+
+/// Student submits a new attempt for a returned submission.
+/// The previous attempt and its grade move to the submission's attempt history.
+pub fn resubmit_submission(
+    ctx: Context<ResubmitSubmission>,
+    assets: Vec<Pubkey>,
+    evidence_assets: Vec<Pubkey>,
+) -> Result<()> {
+    let resource = &ctx.accounts.resource;
+    let now = Clock::get()?.unix_timestamp;
+    let late = resource.check_submission_window(now)?;
+
     let submission = &mut ctx.accounts.submission;
-    submission.grade_submission(grade, feedback, ctx.accounts.grader.key())?;
+    submission.resubmit(assets, evidence_assets, resource.max_attempts, now)?;
+    submission.late = late;
     Ok(())
 }
 
@@ -510,11 +925,21 @@ pub struct CloseResource<'info> {
         constraint = resource.owner == authority.key() @ ResourceError::UnauthorizedResourceAuthority
     )]
     pub resource: Account<'info, Resource>,
+    /// Mutable so a module resource is removed from the course together with the resource
+    #[account(mut, constraint = course.key() == resource.course)]
+    pub course: Account<'info, Course>,
     #[account(mut)]
     pub authority: Signer<'info>,
 }
 
-pub fn close_resource(_ctx: Context<CloseResource>) -> Result<()> {
+/// Close a resource. A course module is removed from the course as well, which is only
+/// possible while the course is a draft.
+pub fn close_resource(ctx: Context<CloseResource>) -> Result<()> {
+    let resource = ctx.accounts.resource.key();
+    let course = &mut ctx.accounts.course;
+    if course.modules.iter().any(|m| m.resource == resource) {
+        course.remove_module(resource)?;
+    }
     Ok(())
 }
 
@@ -532,6 +957,12 @@ mod tests {
             status: ResourceStatus::Draft,
             external_id: None,
             workload: None,
+            max_attempts: None,
+            opens_at: None,
+            due_at: None,
+            closes_at: None,
+            late_penalty: None,
+            rubric: None,
             course: Pubkey::new_unique(),
             assets: Vec::new(),
             tags: vec!["hybrid".to_string()],
@@ -573,6 +1004,11 @@ mod tests {
             nostr_d_tag: None,
             nostr_author_pubkey: [0u8; 32],
             walrus_blob_id: None,
+            attempt: 1,
+            attempt_submitted_at: 0,
+            attempts: Vec::new(),
+            late: false,
+            rubric_scores: Vec::new(),
         }
     }
 
@@ -629,9 +1065,111 @@ mod tests {
         let mut submission = dummy_submission();
         let grader = Pubkey::new_unique();
 
-        let _ = submission.grade_submission(88.0, Some("Great work".to_string()), grader);
+        let _ = submission.grade_submission(88.0, Some("Great work".to_string()), grader, 0);
         // Only validate grade/feedback changes, without relying on other implementation details.
         assert_eq!(submission.grade, Some(88.0));
         assert!(submission.feedback.is_some());
     }
+
+    #[test]
+    fn resubmit_archives_attempt_and_enforces_max_attempts() {
+        let mut submission = dummy_submission();
+        let grader = Pubkey::new_unique();
+        submission.status = SubmissionStatus::Returned;
+        submission.grade = Some(40.0);
+        submission.graded_by = Some(grader);
+
+        // Only returned submissions can be resubmitted.
+        let mut submitted = dummy_submission();
+        assert!(submitted.resubmit(vec![], vec![], None, 10).is_err());
+
+        let new_asset = Pubkey::new_unique();
+        assert!(submission
+            .resubmit(vec![new_asset], vec![], Some(2), 10)
+            .is_ok());
+        assert_eq!(submission.attempt, 2);
+        assert_eq!(submission.attempt_submitted_at, 10);
+        assert_eq!(submission.status, SubmissionStatus::Submitted);
+        assert_eq!(submission.assets, vec![new_asset]);
+        assert_eq!(submission.grade, None);
+        assert_eq!(submission.attempts.len(), 1);
+        assert_eq!(submission.attempts[0].attempt, 1);
+        assert_eq!(submission.attempts[0].grade, Some(40.0));
+        assert_eq!(submission.attempts[0].graded_by, Some(grader));
+
+        submission.status = SubmissionStatus::Returned;
+        assert!(submission.resubmit(vec![], vec![], Some(2), 20).is_err());
+        assert!(submission.resubmit(vec![], vec![], None, 20).is_ok());
+        assert_eq!(submission.attempt, 3);
+    }
+
+    #[test]
+    fn resubmit_keeps_bounded_attempt_history() {
+        let mut submission = dummy_submission();
+        for round in 0..(Submission::MAX_ATTEMPT_HISTORY + 2) {
+            submission.status = SubmissionStatus::Returned;
+            submission
+                .resubmit(vec![], vec![], None, round as i64)
+                .unwrap();
+        }
+        assert_eq!(submission.attempts.len(), Submission::MAX_ATTEMPT_HISTORY);
+        assert_eq!(submission.attempts[0].attempt, 3);
+        assert_eq!(
+            submission.attempt as usize,
+            Submission::MAX_ATTEMPT_HISTORY + 3
+        );
+    }
+
+    #[test]
+    fn deadlines_only_on_assignments_and_ordered() {
+        let mut resource = dummy_resource();
+        assert!(resource.set_deadlines(None, Some(100), None, None).is_err());
+
+        resource.kind = ResourceKind::Assignment;
+        assert!(resource
+            .set_deadlines(Some(200), Some(100), None, None)
+            .is_err());
+        assert!(resource
+            .set_deadlines(None, Some(100), Some(50), None)
+            .is_err());
+        let over_full = LatePenaltyPolicy {
+            percent_per_day: 10,
+            max_percent: 150,
+        };
+        assert!(resource
+            .set_deadlines(None, Some(100), None, Some(over_full))
+            .is_err());
+        assert!(resource
+            .set_deadlines(Some(0), Some(100), Some(200), None)
+            .is_ok());
+
+        assert!(resource.check_submission_window(-1).is_err());
+        assert_eq!(resource.check_submission_window(100).ok(), Some(false));
+        assert_eq!(resource.check_submission_window(150).ok(), Some(true));
+        assert!(resource.check_submission_window(201).is_err());
+    }
+
+    #[test]
+    fn late_penalty_grows_per_started_day_up_to_cap() {
+        let mut resource = dummy_resource();
+        resource.kind = ResourceKind::AssignmentSummative;
+        let policy = LatePenaltyPolicy {
+            percent_per_day: 10,
+            max_percent: 25,
+        };
+        let due = 1_000_000;
+        resource
+            .set_deadlines(None, Some(due), None, Some(policy))
+            .unwrap();
+
+        assert_eq!(resource.late_penalty_percent(due), 0);
+        assert_eq!(resource.late_penalty_percent(due + 1), 10);
+        assert_eq!(resource.late_penalty_percent(due + 86_400), 10);
+        assert_eq!(resource.late_penalty_percent(due + 86_401), 20);
+        assert_eq!(resource.late_penalty_percent(due + 10 * 86_400), 25);
+
+        let mut submission = dummy_submission();
+        let _ = submission.grade_submission(80.0, None, Pubkey::new_unique(), 25);
+        assert_eq!(submission.grade, Some(60.0));
+    }
 }
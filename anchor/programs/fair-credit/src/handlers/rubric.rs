@@ -0,0 +1,202 @@
+use crate::state::*;
+use crate::types::{ResourceError, ResourceKind, StaffCapability};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateRubric<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Rubric::INIT_SPACE,
+        seeds = [Rubric::SEED_PREFIX.as_bytes(), resource.key().as_ref()],
+        bump
+    )]
+    pub rubric: Account<'info, Rubric>,
+    #[account(
+        mut,
+        seeds = [
+            Resource::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            &resource.created.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = provider.has_capability(&authority.key(), Some(&course), StaffCapability::ManageCourse) @ ResourceError::UnauthorizedResourceAuthority
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRubric<'info> {
+    #[account(
+        mut,
+        seeds = [Rubric::SEED_PREFIX.as_bytes(), resource.key().as_ref()],
+        bump
+    )]
+    pub rubric: Account<'info, Rubric>,
+    #[account(
+        seeds = [
+            Resource::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            &resource.created.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = provider.has_capability(&authority.key(), Some(&course), StaffCapability::ManageCourse) @ ResourceError::UnauthorizedResourceAuthority
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GradeSubmissionWithRubric<'info> {
+    #[account(
+        mut,
+        seeds = [
+            Submission::SEED_PREFIX.as_bytes(),
+            submission.resource.as_ref(),
+            submission.student.as_ref(),
+        ],
+        bump
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        mut,
+        seeds = [Rubric::SEED_PREFIX.as_bytes(), resource.key().as_ref()],
+        bump
+    )]
+    pub rubric: Account<'info, Rubric>,
+    #[account(
+        seeds = [
+            Resource::SEED_PREFIX.as_bytes(),
+            resource.course.as_ref(),
+            &resource.created.to_le_bytes(),
+        ],
+        bump,
+        constraint = resource.key() == submission.resource
+    )]
+    pub resource: Account<'info, Resource>,
+    #[account(
+        seeds = [
+            Course::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            provider.key().as_ref(),
+            &course.creation_timestamp.to_le_bytes(),
+        ],
+        bump,
+        constraint = course.key() == resource.course
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [
+            Provider::SEED_PREFIX.as_bytes(),
+            hub.key().as_ref(),
+            course.provider.as_ref(),
+        ],
+        bump,
+        constraint = provider.has_capability(&grader.key(), Some(&course), StaffCapability::Grade) @ ResourceError::UnauthorizedGrader
+    )]
+    pub provider: Account<'info, Provider>,
+    #[account(seeds = [Hub::SEED_PREFIX.as_bytes(), hub.hub_id.as_bytes()], bump)]
+    pub hub: Account<'info, Hub>,
+    #[account(mut)]
+    pub grader: Signer<'info>,
+}
+
+/// Define the grading rubric of a summative assignment.
+/// Once a resource has a rubric, its submissions can only be graded through it.
+pub fn create_rubric(ctx: Context<CreateRubric>, criteria: Vec<RubricCriterion>) -> Result<()> {
+    let resource = &mut ctx.accounts.resource;
+    require!(
+        resource.kind == ResourceKind::AssignmentSummative,
+        ResourceError::RubricNotSupported
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let rubric = &mut ctx.accounts.rubric;
+    rubric.resource = resource.key();
+    rubric.set_criteria(criteria)?;
+    rubric.graded_count = 0;
+    rubric.created = now;
+    rubric.updated = now;
+
+    resource.rubric = Some(rubric.key());
+    resource.updated = now;
+    Ok(())
+}
+
+/// Replace the rubric criteria; only allowed until the first submission is graded with it,
+/// so every grade of a resource comes from the same rubric.
+pub fn update_rubric(ctx: Context<UpdateRubric>, criteria: Vec<RubricCriterion>) -> Result<()> {
+    let rubric = &mut ctx.accounts.rubric;
+    require!(!rubric.is_in_use(), ResourceError::RubricInUse);
+    rubric.set_criteria(criteria)?;
+    rubric.updated = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+/// Grade a submission by choosing one score level per rubric criterion.
+/// The grade is derived from the levels (then any late penalty applies) and the
+/// per-criterion breakdown is kept on the submission for audit.
+pub fn grade_submission_with_rubric(
+    ctx: Context<GradeSubmissionWithRubric>,
+    levels: Vec<u8>,
+    feedback: Option<String>,
+) -> Result<()> {
+    let (grade, scores) = ctx.accounts.rubric.score(&levels)?;
+    let grader = ctx.accounts.grader.key();
+    let submission = &mut ctx.accounts.submission;
+    let late_penalty = ctx
+        .accounts
+        .resource
+        .late_penalty_percent(submission.attempt_submitted_at);
+    submission.grade_submission(grade, feedback, grader, late_penalty)?;
+    submission.rubric_scores = scores;
+    ctx.accounts.rubric.record_grade();
+    Ok(())
+}
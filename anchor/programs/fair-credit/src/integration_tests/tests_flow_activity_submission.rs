@@ -89,6 +89,9 @@ fn flow_resource_hybrid_storage() {
         ),
         vec![
             AccountMeta::new(resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
             AccountMeta::new(keys.provider_authority, true),
         ],
     );
@@ -108,6 +111,9 @@ fn flow_resource_hybrid_storage() {
         ),
         vec![
             AccountMeta::new(resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
             AccountMeta::new(keys.provider_authority, true),
         ],
     );
@@ -286,6 +292,7 @@ fn flow_activity_management() {
             AccountMeta::new(keys.student, true),
             AccountMeta::new_readonly(keys.provider_pda, false),
             AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
     );
@@ -329,32 +336,39 @@ fn flow_activity_management() {
         ),
         vec![
             AccountMeta::new(activity_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
             AccountMeta::new(keys.student, true),
         ],
     );
 
-    // add_grade (teacher can be any signer per current constraints)
+    // add_grade (provider wallet or staff whose role allows grading)
     #[derive(AnchorSerialize)]
     struct AddGradeArgs {
         grade_value: f64,
         asset_ids: Vec<String>,
         evidence_asset_ids: Vec<String>,
     }
-    let ix_add_grade = Instruction::new_with_bytes(
-        PROGRAM_ID,
-        &anchor_ix_data(
-            "add_grade",
-            &AddGradeArgs {
-                grade_value: 95.5,
-                asset_ids: vec![],
-                evidence_asset_ids: vec![],
-            },
-        ),
-        vec![
-            AccountMeta::new(activity_pda, false),
-            AccountMeta::new(keys.provider_authority, true),
-        ],
-    );
+    let ix_add_grade_by = |teacher: Pubkey, grade_value: f64| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "add_grade",
+                &AddGradeArgs {
+                    grade_value,
+                    asset_ids: vec![],
+                    evidence_asset_ids: vec![],
+                },
+            ),
+            vec![
+                AccountMeta::new(activity_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.course_pda, false),
+                AccountMeta::new(teacher, true),
+            ],
+        )
+    };
+    let ix_add_grade = ix_add_grade_by(keys.provider_authority, 95.5);
 
     // archive_activity
     let ix_archive_activity = Instruction::new_with_bytes(
@@ -374,6 +388,13 @@ fn flow_activity_management() {
         (&ix_archive_activity, &[Check::success()]),
     ]);
 
+    // Students cannot grade, and grades must stay within 0-100
+    let student_grade_result = ctx.process_instruction(&ix_add_grade_by(keys.student, 100.0));
+    assert!(student_grade_result.program_result.is_err());
+    let out_of_range_result =
+        ctx.process_instruction(&ix_add_grade_by(keys.provider_authority, 120.0));
+    assert!(out_of_range_result.program_result.is_err());
+
     let store_ref = ctx.account_store.borrow();
     let activity_account = store_ref.get(&activity_pda).expect("activity account");
     let mut activity_data: &[u8] = activity_account.data.as_slice();
@@ -382,6 +403,7 @@ fn flow_activity_management() {
     assert_eq!(activity_state.kind, ActivityKind::AttendMeeting);
     assert_eq!(activity_state.status, ActivityStatus::Archived);
     assert_eq!(activity_state.grade, Some(95.5));
+    assert_eq!(activity_state.graded_by, Some(keys.provider_authority));
 }
 
 #[test]
@@ -441,21 +463,14 @@ fn flow_submission_management() {
     );
 
     // Submission PDA
-    let submitted_at = now + 2;
     let (submission_pda, _sbump) = Pubkey::find_program_address(
-        &[
-            b"submission",
-            resource_pda.as_ref(),
-            keys.student.as_ref(),
-            &submitted_at.to_le_bytes(),
-        ],
+        &[b"submission", resource_pda.as_ref(), keys.student.as_ref()],
         &PROGRAM_ID,
     );
     precreate_pda(&ctx, submission_pda);
 
     #[derive(AnchorSerialize)]
     struct CreateSubmissionArgs {
-        submission_timestamp: i64,
         assets: Vec<Pubkey>,
         evidence_assets: Vec<Pubkey>,
     }
@@ -464,7 +479,6 @@ fn flow_submission_management() {
         &anchor_ix_data(
             "create_submission",
             &CreateSubmissionArgs {
-                submission_timestamp: submitted_at,
                 assets: vec![],
                 evidence_assets: vec![],
             },
@@ -472,6 +486,7 @@ fn flow_submission_management() {
         vec![
             AccountMeta::new(submission_pda, false),
             AccountMeta::new_readonly(resource_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
             AccountMeta::new(keys.student, true),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
@@ -536,6 +551,10 @@ fn flow_submission_management() {
         ),
         vec![
             AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
             AccountMeta::new(keys.mentor, true),
         ],
     );
@@ -548,6 +567,12 @@ fn flow_submission_management() {
         (&ix_grade_submission, &[Check::success()]),
     ]);
 
+    // Only the provider wallet or its endorsers may grade; the student cannot grade their own work
+    let mut ix_self_grade = ix_grade_submission.clone();
+    ix_self_grade.accounts[5] = AccountMeta::new(keys.student, true);
+    let self_grade_result = ctx.process_instruction(&ix_self_grade);
+    assert!(self_grade_result.program_result.is_err());
+
     let store_ref = ctx.account_store.borrow();
 
     let resource_account = store_ref.get(&resource_pda).expect("resource account");
@@ -562,6 +587,7 @@ fn flow_submission_management() {
         Submission::try_deserialize(&mut submission_data).expect("submission deserialize");
     assert_eq!(submission_state.status, SubmissionStatus::Graded);
     assert_eq!(submission_state.grade, Some(88.0));
+    assert_eq!(submission_state.graded_by, Some(keys.mentor));
     assert_eq!(
         submission_state.nostr_d_tag.as_deref(),
         Some("my-submission-v1")
@@ -574,21 +600,12 @@ fn flow_submission_management() {
 
 #[test]
 fn flow_credential_lifecycle() {
-    use crate::state::{Course, Credential};
-    use crate::types::{ActivityKind, CourseStatus, CredentialStatus};
+    use crate::state::{Course, Credential, CredentialVerificationStats};
+    use crate::types::{ActivityKind, CourseStatus, CredentialStatus, VerificationFrequency};
 
     let now: i64 = 1_700_000_000;
     let (ctx, keys) = setup_hub_provider_course(now, now);
-
-    let ix_add_accepted_course = Instruction::new_with_bytes(
-        PROGRAM_ID,
-        &anchor_ix_data_no_args("add_accepted_course"),
-        vec![
-            AccountMeta::new(keys.hub_pda, false),
-            AccountMeta::new(keys.hub_authority, true),
-            AccountMeta::new(keys.course_pda, false),
-        ],
-    );
+    let progress_pda = complete_course_modules(&ctx, &keys);
 
     let activity_created = now + 1;
     let (activity_pda, _activity_bump) = Pubkey::find_program_address(
@@ -631,6 +648,7 @@ fn flow_credential_lifecycle() {
             AccountMeta::new(keys.student, true),
             AccountMeta::new_readonly(keys.provider_pda, false),
             AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
     );
@@ -645,14 +663,23 @@ fn flow_credential_lifecycle() {
     );
     precreate_pda(&ctx, credential_pda);
 
+    let (verification_stats_pda, _stats_bump) = Pubkey::find_program_address(
+        &[b"verification-stats", credential_pda.as_ref()],
+        &PROGRAM_ID,
+    );
+    precreate_pda(&ctx, verification_stats_pda);
+
     let ix_create_credential = Instruction::new_with_bytes(
         PROGRAM_ID,
         &anchor_ix_data_no_args("create_credential"),
         vec![
             AccountMeta::new(credential_pda, false),
+            AccountMeta::new(verification_stats_pda, false),
             AccountMeta::new_readonly(keys.course_pda, false),
             AccountMeta::new_readonly(keys.provider_pda, false),
             AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new_readonly(progress_pda, false),
             AccountMeta::new(keys.student, true),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
@@ -682,6 +709,9 @@ fn flow_credential_lifecycle() {
         ),
         vec![
             AccountMeta::new(credential_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
             AccountMeta::new(keys.mentor, true),
         ],
     );
@@ -692,19 +722,70 @@ fn flow_credential_lifecycle() {
         vec![
             AccountMeta::new(credential_pda, false),
             AccountMeta::new(keys.course_pda, false),
-            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new(keys.provider_pda, false),
             AccountMeta::new_readonly(keys.hub_pda, false),
             AccountMeta::new(keys.provider_authority, true),
         ],
     );
 
+    #[derive(AnchorSerialize)]
+    struct VerifyCredentialArgs {
+        anonymous: bool,
+    }
+    let verifier = Pubkey::new_unique();
+    ctx.account_store.borrow_mut().insert(
+        verifier,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+    let ix_verify_named = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "verify_credential",
+            &VerifyCredentialArgs { anonymous: false },
+        ),
+        vec![
+            AccountMeta::new(credential_pda, false),
+            AccountMeta::new(verification_stats_pda, false),
+            AccountMeta::new_readonly(verifier, true),
+        ],
+    );
+    let ix_verify_anonymous = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "verify_credential",
+            &VerifyCredentialArgs { anonymous: true },
+        ),
+        vec![
+            AccountMeta::new(credential_pda, false),
+            AccountMeta::new(verification_stats_pda, false),
+            AccountMeta::new_readonly(verifier, true),
+        ],
+    );
+
     ctx.process_and_validate_instruction_chain(&[
-        (&ix_add_accepted_course, &[Check::success()]),
         (&ix_create_activity, &[Check::success()]),
         (&ix_create_credential, &[Check::success()]),
         (&ix_link_activity, &[Check::success()]),
-        (&ix_endorse_credential, &[Check::success()]),
+    ]);
+
+    // The student cannot endorse their own credential from any wallet outside the allowlist.
+    let self_endorse_result = ctx.process_instruction(&ix_endorse_credential_as(
+        &keys,
+        credential_pda,
+        keys.student,
+    ));
+    assert!(self_endorse_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(&ix_endorse_credential, &[Check::success()])]);
+
+    // Credentials cannot be verified before the provider approves them.
+    let early_verify_result = ctx.process_instruction(&ix_verify_named);
+    assert!(early_verify_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
         (&ix_approve_credential, &[Check::success()]),
+        (&ix_verify_named, &[Check::success()]),
+        (&ix_verify_anonymous, &[Check::success()]),
     ]);
 
     let store_ref = ctx.account_store.borrow();
@@ -724,211 +805,3230 @@ fn flow_credential_lifecycle() {
     assert_eq!(credential_state.student_wallet, keys.student);
     assert_eq!(credential_state.mentor_wallet, keys.mentor);
     assert!(credential_state.metadata.activities.contains(&activity_pda));
+    assert_eq!(credential_state.verification_count, 2);
+
+    let stats_account = store_ref
+        .get(&verification_stats_pda)
+        .expect("verification stats account");
+    let mut stats_data: &[u8] = stats_account.data.as_slice();
+    let stats_state = CredentialVerificationStats::try_deserialize(&mut stats_data)
+        .expect("verification stats deserialize");
+    assert_eq!(stats_state.credential, credential_pda);
+    assert_eq!(stats_state.stats.total_verifications, 2);
+    assert_eq!(stats_state.stats.anonymous_verifications, 1);
+    assert_eq!(stats_state.frequency, VerificationFrequency::Low);
+    assert_eq!(stats_state.recent_verifications.len(), 2);
+    assert_eq!(stats_state.recent_verifications[0].verifier, Some(verifier));
+    assert_eq!(stats_state.recent_verifications[1].verifier, None);
 }
 
-#[derive(Clone, Copy)]
-struct SetupKeys {
-    hub_authority: Pubkey,
-    provider_authority: Pubkey,
-    provider_pda: Pubkey,
-    hub_pda: Pubkey,
-    course_pda: Pubkey,
-    student: Pubkey,
-    mentor: Pubkey,
-}
-
-fn setup_hub_provider_course(
-    now: i64,
-    course_creation_timestamp: i64,
-) -> (
-    mollusk_svm::MolluskContext<HashMap<Pubkey, Account>>,
-    SetupKeys,
-) {
-    use crate::state::{Course, Hub, Provider};
+#[test]
+fn flow_credential_revocation() {
+    use crate::state::{Course, Credential, Provider, RevocationRegistry};
+    use crate::types::{CredentialStatus, RevocationReason, TrustLevel};
 
-    let hub_authority = Pubkey::new_unique();
-    let provider_authority = Pubkey::new_unique();
-    let student = Pubkey::new_unique();
-    let mentor = Pubkey::new_unique();
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+    let credential_pda = issue_credential(&ctx, &keys);
 
-    let (hub_pda, _hub_bump) = Pubkey::find_program_address(&[b"hub"], &PROGRAM_ID);
-    let (provider_pda, _provider_bump) = Pubkey::find_program_address(
-        &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
-        &PROGRAM_ID,
-    );
-    let (course_pda, _course_bump) = Pubkey::find_program_address(
-        &[
-            b"course",
-            hub_pda.as_ref(),
-            provider_pda.as_ref(),
-            &course_creation_timestamp.to_le_bytes(),
-        ],
+    let (registry_pda, _registry_bump) = Pubkey::find_program_address(
+        &[b"revocation-registry", keys.course_pda.as_ref()],
         &PROGRAM_ID,
     );
+    precreate_pda(&ctx, registry_pda);
 
-    let mollusk = mollusk_with_program(now);
-
-    let mut store: HashMap<Pubkey, Account> = HashMap::new();
-    // Signers
-    store.insert(
-        hub_authority,
-        Account::new(10_000_000_000, 0, &system_program::id()),
-    );
-    store.insert(
-        provider_authority,
-        Account::new(10_000_000_000, 0, &system_program::id()),
-    );
-    store.insert(
-        student,
-        Account::new(10_000_000_000, 0, &system_program::id()),
-    );
-    store.insert(
-        mentor,
-        Account::new(10_000_000_000, 0, &system_program::id()),
-    );
-
-    // Uninitialized PDAs
-    store.insert(hub_pda, Account::new(0, 0, &system_program::id()));
-    store.insert(provider_pda, Account::new(0, 0, &system_program::id()));
-    store.insert(course_pda, Account::new(0, 0, &system_program::id()));
-
-    let ctx = mollusk.with_context(store);
-
-    // initialize_hub
-    let ix_initialize_hub = Instruction::new_with_bytes(
+    let ix_create_registry = Instruction::new_with_bytes(
         PROGRAM_ID,
-        &anchor_ix_data_no_args("initialize_hub"),
+        &anchor_ix_data_no_args("create_revocation_registry"),
         vec![
-            AccountMeta::new(hub_pda, false),
-            AccountMeta::new(hub_authority, true),
+            AccountMeta::new(registry_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            // The hub authority may create the registry as well as the provider
+            AccountMeta::new(keys.hub_authority, true),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
     );
 
-    // initialize_provider
     #[derive(AnchorSerialize)]
-    struct InitializeProviderArgs {
-        name: String,
-        description: String,
-        website: String,
-        email: String,
-        provider_type: String,
+    struct RevokeCredentialArgs {
+        reason: RevocationReason,
     }
-    let ix_initialize_provider = Instruction::new_with_bytes(
+    let ix_revoke = |authority: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "revoke_credential",
+                &RevokeCredentialArgs {
+                    reason: RevocationReason::AcademicMisconduct,
+                },
+            ),
+            vec![
+                AccountMeta::new(credential_pda, false),
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new(registry_pda, false),
+                AccountMeta::new(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(authority, true),
+                // Credential was never minted: NFT accounts are omitted
+                AccountMeta::new_readonly(PROGRAM_ID, false),
+                AccountMeta::new_readonly(PROGRAM_ID, false),
+                AccountMeta::new_readonly(PROGRAM_ID, false),
+            ],
+        )
+    };
+
+    ctx.process_and_validate_instruction_chain(&[(&ix_create_registry, &[Check::success()])]);
+
+    // The student cannot revoke their own credential.
+    let student_revoke_result = ctx.process_instruction(&ix_revoke(keys.student));
+    assert!(student_revoke_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_revoke(keys.provider_authority),
+        &[Check::success()],
+    )]);
+
+    // Revoking twice is rejected, even by the hub authority.
+    let second_revoke_result = ctx.process_instruction(&ix_revoke(keys.hub_authority));
+    assert!(second_revoke_result.program_result.is_err());
+
+    // Anyone can refresh the provider's trust level from its record.
+    let ix_refresh_trust_level = Instruction::new_with_bytes(
         PROGRAM_ID,
-        &anchor_ix_data(
-            "initialize_provider",
-            &InitializeProviderArgs {
-                name: "Test Provider".to_string(),
-                description: "desc".to_string(),
-                website: "https://example.com".to_string(),
-                email: "test@example.com".to_string(),
-                provider_type: "education".to_string(),
-            },
-        ),
+        &anchor_ix_data_no_args("refresh_provider_trust_level"),
         vec![
-            AccountMeta::new(provider_pda, false),
-            AccountMeta::new_readonly(hub_pda, false),
-            AccountMeta::new(provider_authority, true),
-            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
         ],
     );
+    ctx.process_and_validate_instruction_chain(&[(&ix_refresh_trust_level, &[Check::success()])]);
 
-    // add_accepted_provider
-    let ix_add_accepted_provider = Instruction::new_with_bytes(
-        PROGRAM_ID,
-        &anchor_ix_data_no_args("add_accepted_provider"),
-        vec![
-            AccountMeta::new(hub_pda, false),
-            AccountMeta::new(hub_authority, true),
-            AccountMeta::new_readonly(provider_pda, false),
-            AccountMeta::new_readonly(provider_authority, false),
-        ],
+    let store_ref = ctx.account_store.borrow();
+
+    let provider_account = store_ref.get(&keys.provider_pda).expect("provider account");
+    let mut provider_data: &[u8] = provider_account.data.as_slice();
+    let provider_state =
+        Provider::try_deserialize(&mut provider_data).expect("provider deserialize");
+    assert_eq!(provider_state.approved_credentials_count, 1);
+    assert_eq!(provider_state.revoked_credentials_count, 1);
+    assert_eq!(provider_state.trust_level, TrustLevel::New);
+
+    let credential_account = store_ref.get(&credential_pda).expect("credential account");
+    let mut credential_data: &[u8] = credential_account.data.as_slice();
+    let credential_state =
+        Credential::try_deserialize(&mut credential_data).expect("credential deserialize");
+    assert!(credential_state.status == CredentialStatus::Revoked);
+
+    let course_account = store_ref.get(&keys.course_pda).expect("course account");
+    let mut course_data: &[u8] = course_account.data.as_slice();
+    let course_state = Course::try_deserialize(&mut course_data).expect("course deserialize");
+    assert!(!course_state.approved_credentials.contains(&credential_pda));
+
+    let registry_account = store_ref.get(&registry_pda).expect("registry account");
+    let mut registry_data: &[u8] = registry_account.data.as_slice();
+    let registry_state =
+        RevocationRegistry::try_deserialize(&mut registry_data).expect("registry deserialize");
+    assert!(registry_state.is_revoked(&credential_pda));
+    assert_eq!(
+        registry_state.entries[0].reason,
+        RevocationReason::AcademicMisconduct
+    );
+    assert_eq!(
+        registry_state.entries[0].revoked_by,
+        keys.provider_authority
     );
+}
+
+#[test]
+fn flow_credential_expiry_and_renewal() {
+    use crate::state::{Course, Credential};
+    use crate::types::CredentialStatus;
+
+    let now: i64 = 1_700_000_000;
+    let validity_period: i64 = 365 * 24 * 60 * 60;
+    let (mut ctx, keys) = setup_hub_provider_course(now, now);
 
-    // create_course
     #[derive(AnchorSerialize)]
-    struct CreateCourseArgs {
-        creation_timestamp: i64,
-        name: String,
-        description: String,
-        workload_required: u32,
-        degree_id: Option<String>,
-        nostr_d_tag: Option<String>,
-        nostr_author_pubkey: Option<[u8; 32]>,
+    struct SetCourseValidityPeriodArgs {
+        validity_period: Option<i64>,
     }
-    let ix_create_course = Instruction::new_with_bytes(
+    let ix_set_validity = |validity_period: Option<i64>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "set_course_validity_period",
+                &SetCourseValidityPeriodArgs { validity_period },
+            ),
+            vec![
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.provider_authority, true),
+            ],
+        )
+    };
+
+    let invalid_period_result = ctx.process_instruction(&ix_set_validity(Some(0)));
+    assert!(invalid_period_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_set_validity(Some(validity_period)),
+        &[Check::success()],
+    )]);
+
+    let credential_pda = issue_credential(&ctx, &keys);
+
+    let read_credential = |ctx: &mollusk_svm::MolluskContext<HashMap<Pubkey, Account>>| {
+        let store_ref = ctx.account_store.borrow();
+        let credential_account = store_ref.get(&credential_pda).expect("credential account");
+        let mut credential_data: &[u8] = credential_account.data.as_slice();
+        Credential::try_deserialize(&mut credential_data).expect("credential deserialize")
+    };
+
+    let issued = read_credential(&ctx);
+    assert!(issued.status == CredentialStatus::Verified);
+    assert_eq!(issued.valid_until, Some(now + validity_period));
+    assert!(!issued.is_expired(now + validity_period));
+    assert!(issued.is_expired(now + validity_period + 1));
+
+    let ix_renew_credential = Instruction::new_with_bytes(
         PROGRAM_ID,
-        &anchor_ix_data(
-            "create_course",
-            &CreateCourseArgs {
-                creation_timestamp: course_creation_timestamp,
-                name: "Course 101".to_string(),
-                description: "Intro".to_string(),
-                workload_required: 10,
-                degree_id: None,
-                nostr_d_tag: None,
-                nostr_author_pubkey: None,
-            },
-        ),
+        &anchor_ix_data_no_args("renew_credential"),
         vec![
-            AccountMeta::new(course_pda, false),
-            AccountMeta::new(provider_pda, false),
-            AccountMeta::new_readonly(hub_pda, false),
-            AccountMeta::new(provider_authority, true),
-            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(credential_pda, false),
+            AccountMeta::new(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.student, true),
         ],
     );
 
+    // A credential far from expiry cannot be pulled back into review.
+    let early_renew_result = ctx.process_instruction(&ix_renew_credential);
+    assert!(early_renew_result.program_result.is_err());
+
+    let renewal_opens = now + validity_period - Credential::RENEWAL_WINDOW;
+    ctx.mollusk.sysvars.clock.unix_timestamp = renewal_opens;
+    ctx.process_and_validate_instruction_chain(&[(&ix_renew_credential, &[Check::success()])]);
+
+    let pending = read_credential(&ctx);
+    assert!(pending.status == CredentialStatus::Pending);
+    assert_eq!(pending.mentor_wallet, Pubkey::default());
+    {
+        let store_ref = ctx.account_store.borrow();
+        let course_account = store_ref.get(&keys.course_pda).expect("course account");
+        let mut course_data: &[u8] = course_account.data.as_slice();
+        let course_state = Course::try_deserialize(&mut course_data).expect("course deserialize");
+        assert!(!course_state.approved_credentials.contains(&credential_pda));
+    }
+
+    // A pending renewal cannot be renewed again before it is re-approved.
+    let double_renew_result = ctx.process_instruction(&ix_renew_credential);
+    assert!(double_renew_result.program_result.is_err());
+
     ctx.process_and_validate_instruction_chain(&[
         (
-            &ix_initialize_hub,
-            &[
-                Check::success(),
-                Check::account(&hub_pda)
-                    .owner(&PROGRAM_ID)
-                    .space(8 + Hub::INIT_SPACE)
-                    .rent_exempt()
-                    .build(),
-            ],
+            &ix_endorse_credential(&keys, credential_pda),
+            &[Check::success()],
         ),
         (
-            &ix_initialize_provider,
-            &[
-                Check::success(),
-                Check::account(&provider_pda)
-                    .owner(&PROGRAM_ID)
-                    .space(8 + Provider::INIT_SPACE)
-                    .rent_exempt()
-                    .build(),
+            &ix_approve_credential(&keys, credential_pda),
+            &[Check::success()],
+        ),
+    ]);
+
+    let renewed = read_credential(&ctx);
+    assert!(renewed.status == CredentialStatus::Verified);
+    assert_eq!(renewed.valid_until, Some(renewal_opens + validity_period));
+    assert_eq!(renewed.renewal_count, 1);
+
+    // Re-approval after a renewal does not count as another approved credential.
+    let store_ref = ctx.account_store.borrow();
+    let provider_account = store_ref.get(&keys.provider_pda).expect("provider account");
+    let mut provider_data: &[u8] = provider_account.data.as_slice();
+    let provider_state =
+        crate::state::Provider::try_deserialize(&mut provider_data).expect("provider deserialize");
+    assert_eq!(provider_state.approved_credentials_count, 1);
+}
+
+#[test]
+fn flow_course_endorser_scoping() {
+    use crate::state::{Course, Credential, Provider};
+    use crate::types::{CredentialStatus, RevocationReason};
+
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+
+    let other_endorser = Pubkey::new_unique();
+    ctx.account_store.borrow_mut().insert(
+        other_endorser,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+
+    let ix_add_course_endorser = |endorser: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("add_course_endorser"),
+            vec![
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.provider_authority, true),
+                AccountMeta::new_readonly(endorser, false),
             ],
+        )
+    };
+
+    // Only provider endorsers can be scoped to a course.
+    let unregistered_result = ctx.process_instruction(&ix_add_course_endorser(keys.student));
+    assert!(unregistered_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_add_provider_endorser(&keys, other_endorser),
+            &[Check::success()],
         ),
-        (&ix_add_accepted_provider, &[Check::success()]),
+        (&ix_add_course_endorser(keys.mentor), &[Check::success()]),
+    ]);
+
+    let credential_pda = create_pending_credential(&ctx, &keys);
+
+    // A provider endorser outside the course scope is rejected.
+    let out_of_scope_result = ctx.process_instruction(&ix_endorse_credential_as(
+        &keys,
+        credential_pda,
+        other_endorser,
+    ));
+    assert!(out_of_scope_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_endorse_credential(&keys, credential_pda),
+        &[Check::success()],
+    )]);
+
+    let credential_account = ctx
+        .account_store
+        .borrow()
+        .get(&credential_pda)
+        .expect("credential account")
+        .clone();
+    let mut credential_data: &[u8] = credential_account.data.as_slice();
+    let endorsed_state =
+        Credential::try_deserialize(&mut credential_data).expect("credential deserialize");
+    assert!(endorsed_state.status == CredentialStatus::Endorsed);
+    assert_eq!(endorsed_state.mentor_wallet, keys.mentor);
+
+    // Revoking a credential that was never approved leaves the provider's revoked count alone.
+    #[derive(AnchorSerialize)]
+    struct RevokeCredentialArgs {
+        reason: RevocationReason,
+    }
+    let ix_revoke = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "revoke_credential",
+            &RevokeCredentialArgs {
+                reason: RevocationReason::IssuedInError,
+            },
+        ),
+        vec![
+            AccountMeta::new(credential_pda, false),
+            AccountMeta::new(keys.course_pda, false),
+            // No revocation registry and no NFT accounts
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.provider_authority, true),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    ctx.process_and_validate_instruction_chain(&[(&ix_revoke, &[Check::success()])]);
+
+    let store_ref = ctx.account_store.borrow();
+
+    let course_account = store_ref.get(&keys.course_pda).expect("course account");
+    let mut course_data: &[u8] = course_account.data.as_slice();
+    let course_state = Course::try_deserialize(&mut course_data).expect("course deserialize");
+    assert_eq!(course_state.endorsers, vec![keys.mentor]);
+
+    let provider_account = store_ref.get(&keys.provider_pda).expect("provider account");
+    let mut provider_data: &[u8] = provider_account.data.as_slice();
+    let provider_state =
+        Provider::try_deserialize(&mut provider_data).expect("provider deserialize");
+    assert_eq!(provider_state.revoked_credentials_count, 0);
+
+    let credential_account = store_ref.get(&credential_pda).expect("credential account");
+    let mut credential_data: &[u8] = credential_account.data.as_slice();
+    let credential_state =
+        Credential::try_deserialize(&mut credential_data).expect("credential deserialize");
+    assert!(credential_state.status == CredentialStatus::Revoked);
+}
+
+#[test]
+fn flow_credential_endorsement_quorum() {
+    use crate::state::Credential;
+    use crate::types::CredentialStatus;
+
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+
+    let second_endorser = Pubkey::new_unique();
+    ctx.account_store.borrow_mut().insert(
+        second_endorser,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+
+    #[derive(AnchorSerialize)]
+    struct SetCourseEndorsementThresholdArgs {
+        threshold: u8,
+    }
+    let ix_set_threshold = |threshold: u8| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "set_course_endorsement_threshold",
+                &SetCourseEndorsementThresholdArgs { threshold },
+            ),
+            vec![
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.provider_authority, true),
+            ],
+        )
+    };
+
+    let zero_threshold_result = ctx.process_instruction(&ix_set_threshold(0));
+    assert!(zero_threshold_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_set_threshold(2), &[Check::success()]),
         (
-            &ix_create_course,
+            &ix_add_provider_endorser(&keys, second_endorser),
+            &[Check::success()],
+        ),
+    ]);
+
+    let credential_pda = create_pending_credential(&ctx, &keys);
+
+    let read_credential = || {
+        let store_ref = ctx.account_store.borrow();
+        let credential_account = store_ref.get(&credential_pda).expect("credential account");
+        let mut credential_data: &[u8] = credential_account.data.as_slice();
+        Credential::try_deserialize(&mut credential_data).expect("credential deserialize")
+    };
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_endorse_credential(&keys, credential_pda),
+        &[Check::success()],
+    )]);
+
+    let partially_endorsed = read_credential();
+    assert!(partially_endorsed.status == CredentialStatus::Pending);
+    assert_eq!(partially_endorsed.endorsements.len(), 1);
+
+    // One of two endorsements is not enough for approval, and the same endorser cannot sign twice.
+    let early_approve_result =
+        ctx.process_instruction(&ix_approve_credential(&keys, credential_pda));
+    assert!(early_approve_result.program_result.is_err());
+    let duplicate_result = ctx.process_instruction(&ix_endorse_credential(&keys, credential_pda));
+    assert!(duplicate_result.program_result.is_err());
+
+    // Endorsement messages are capped at 300 characters.
+    #[derive(AnchorSerialize)]
+    struct EndorseCredentialArgs {
+        endorsement_message: String,
+    }
+    let ix_long_endorsement = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "endorse_credential",
+            &EndorseCredentialArgs {
+                endorsement_message: "a".repeat(301),
+            },
+        ),
+        vec![
+            AccountMeta::new(credential_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(second_endorser, true),
+        ],
+    );
+    let long_message_result = ctx.process_instruction(&ix_long_endorsement);
+    assert!(long_message_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_endorse_credential_as(&keys, credential_pda, second_endorser),
+            &[Check::success()],
+        ),
+        (
+            &ix_approve_credential(&keys, credential_pda),
+            &[Check::success()],
+        ),
+    ]);
+
+    let approved = read_credential();
+    assert!(approved.status == CredentialStatus::Verified);
+    assert_eq!(approved.mentor_wallet, keys.mentor);
+    let endorsers: Vec<Pubkey> = approved.endorsements.iter().map(|e| e.endorser).collect();
+    assert_eq!(endorsers, vec![keys.mentor, second_endorser]);
+    assert!(approved
+        .endorsements
+        .iter()
+        .all(|e| e.message == "Completed all requirements" && e.timestamp == now));
+}
+
+#[test]
+fn flow_cross_hub_recognition() {
+    use crate::state::{Credential, HubRecognition, RecognitionScope};
+
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+    let credential_pda = issue_credential(&ctx, &keys);
+    let (verification_stats_pda, _stats_bump) = Pubkey::find_program_address(
+        &[b"verification-stats", credential_pda.as_ref()],
+        &PROGRAM_ID,
+    );
+
+    // Partner hub run by a different authority
+    let partner_authority = Pubkey::new_unique();
+    ctx.account_store.borrow_mut().insert(
+        partner_authority,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+    let (partner_hub_pda, _partner_hub_bump) =
+        Pubkey::find_program_address(&[b"hub", b"partner-hub".as_ref()], &PROGRAM_ID);
+    let (partner_queue_pda, _partner_queue_bump) =
+        Pubkey::find_program_address(&[b"provider-queue", partner_hub_pda.as_ref()], &PROGRAM_ID);
+    precreate_pda(&ctx, partner_hub_pda);
+    precreate_pda(&ctx, partner_queue_pda);
+    precreate_pda(&ctx, course_review_queue_pda(partner_hub_pda));
+
+    #[derive(AnchorSerialize)]
+    struct InitializeHubArgs {
+        hub_id: String,
+    }
+    let ix_initialize_partner_hub = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "initialize_hub",
+            &InitializeHubArgs {
+                hub_id: "partner-hub".to_string(),
+            },
+        ),
+        vec![
+            AccountMeta::new(partner_hub_pda, false),
+            AccountMeta::new(partner_queue_pda, false),
+            AccountMeta::new(course_review_queue_pda(partner_hub_pda), false),
+            AccountMeta::new(partner_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_initialize_partner_hub,
+        &[Check::success()],
+    )]);
+
+    let recognition_pda_for = |subject: Pubkey| {
+        Pubkey::find_program_address(
             &[
-                Check::success(),
-                Check::account(&course_pda)
-                    .owner(&PROGRAM_ID)
-                    .space(8 + Course::INIT_SPACE)
-                    .rent_exempt()
-                    .build(),
+                b"hub-recognition",
+                partner_hub_pda.as_ref(),
+                subject.as_ref(),
+            ],
+            &PROGRAM_ID,
+        )
+        .0
+    };
+    let course_recognition_pda = recognition_pda_for(keys.course_pda);
+    let provider_recognition_pda = recognition_pda_for(keys.provider_pda);
+    precreate_pda(&ctx, course_recognition_pda);
+    precreate_pda(&ctx, provider_recognition_pda);
+
+    #[derive(AnchorSerialize)]
+    struct RecognizeArgs {
+        workload_factor_bps: Option<u16>,
+    }
+    let ix_recognize_course = |hub: Pubkey, authority: Pubkey, factor: Option<u16>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "recognize_course",
+                &RecognizeArgs {
+                    workload_factor_bps: factor,
+                },
+            ),
+            vec![
+                AccountMeta::new(
+                    Pubkey::find_program_address(
+                        &[b"hub-recognition", hub.as_ref(), keys.course_pda.as_ref()],
+                        &PROGRAM_ID,
+                    )
+                    .0,
+                    false,
+                ),
+                AccountMeta::new_readonly(hub, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.course_pda, false),
+                AccountMeta::new(authority, true),
+                AccountMeta::new_readonly(system_program::id(), false),
             ],
+        )
+    };
+    let ix_recognize_provider = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "recognize_provider",
+            &RecognizeArgs {
+                workload_factor_bps: None,
+            },
         ),
-    ]);
+        vec![
+            AccountMeta::new(provider_recognition_pda, false),
+            AccountMeta::new_readonly(partner_hub_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new(partner_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let ix_revoke_recognition = |recognition: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("revoke_recognition"),
+            vec![
+                AccountMeta::new(recognition, false),
+                AccountMeta::new_readonly(partner_hub_pda, false),
+                AccountMeta::new(partner_authority, true),
+            ],
+        )
+    };
 
-    (
-        ctx,
-        SetupKeys {
-            hub_authority,
-            provider_authority,
-            provider_pda,
-            hub_pda,
-            course_pda,
-            student,
-            mentor,
-        },
+    #[derive(AnchorSerialize)]
+    struct VerifyArgs {
+        anonymous: bool,
+    }
+    let ix_verify_recognized = |recognition: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "verify_recognized_credential",
+                &VerifyArgs { anonymous: false },
+            ),
+            vec![
+                AccountMeta::new(credential_pda, false),
+                AccountMeta::new(verification_stats_pda, false),
+                AccountMeta::new_readonly(keys.course_pda, false),
+                AccountMeta::new_readonly(partner_hub_pda, false),
+                AccountMeta::new_readonly(recognition, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.student, true),
+            ],
+        )
+    };
+
+    // Nothing is recognised yet.
+    let unrecognized_result =
+        ctx.process_instruction(&ix_verify_recognized(course_recognition_pda));
+    assert!(unrecognized_result.program_result.is_err());
+
+    // Only the partner hub authority can recognise, with a sane factor, for another hub's course.
+    let wrong_authority_result = ctx.process_instruction(&ix_recognize_course(
+        partner_hub_pda,
+        keys.hub_authority,
+        None,
+    ));
+    assert!(wrong_authority_result.program_result.is_err());
+    let zero_factor_result = ctx.process_instruction(&ix_recognize_course(
+        partner_hub_pda,
+        partner_authority,
+        Some(0),
+    ));
+    assert!(zero_factor_result.program_result.is_err());
+    let same_hub_recognition_pda = Pubkey::find_program_address(
+        &[
+            b"hub-recognition",
+            keys.hub_pda.as_ref(),
+            keys.course_pda.as_ref(),
+        ],
+        &PROGRAM_ID,
     )
+    .0;
+    precreate_pda(&ctx, same_hub_recognition_pda);
+    let same_hub_result =
+        ctx.process_instruction(&ix_recognize_course(keys.hub_pda, keys.hub_authority, None));
+    assert!(same_hub_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_recognize_course(partner_hub_pda, partner_authority, Some(15_000)),
+            &[Check::success()],
+        ),
+        (
+            &ix_verify_recognized(course_recognition_pda),
+            &[Check::success()],
+        ),
+    ]);
+
+    let read_recognition = |pubkey: Pubkey| {
+        let store_ref = ctx.account_store.borrow();
+        let account = store_ref.get(&pubkey).expect("recognition account");
+        let mut data: &[u8] = account.data.as_slice();
+        HubRecognition::try_deserialize(&mut data).expect("recognition deserialize")
+    };
+    let course_recognition = read_recognition(course_recognition_pda);
+    assert_eq!(course_recognition.hub, partner_hub_pda);
+    assert_eq!(course_recognition.source_hub, keys.hub_pda);
+    assert!(course_recognition.scope == RecognitionScope::Course);
+    assert_eq!(course_recognition.subject, keys.course_pda);
+    assert_eq!(course_recognition.recognized_by, partner_authority);
+    // Course 101 requires 10 units of workload; 1.5x in the partner hub.
+    assert_eq!(course_recognition.converted_workload(10), 15);
+
+    // Revoking the recognition stops verification through it.
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_revoke_recognition(course_recognition_pda),
+        &[Check::success()],
+    )]);
+    let revoked_result = ctx.process_instruction(&ix_verify_recognized(course_recognition_pda));
+    assert!(revoked_result.program_result.is_err());
+
+    // Provider-wide recognition covers the same credential.
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_recognize_provider, &[Check::success()]),
+        (
+            &ix_verify_recognized(provider_recognition_pda),
+            &[Check::success()],
+        ),
+    ]);
+    let provider_recognition = read_recognition(provider_recognition_pda);
+    assert!(provider_recognition.scope == RecognitionScope::Provider);
+    assert_eq!(provider_recognition.converted_workload(10), 10);
+
+    // A recognition stops covering a provider suspended in the source hub.
+    #[derive(AnchorSerialize)]
+    struct SuspendProviderArgs {
+        note: Option<String>,
+    }
+    let ix_suspend_provider = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data("suspend_provider", &SuspendProviderArgs { note: None }),
+        vec![
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.hub_authority, true),
+            AccountMeta::new(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.provider_authority, false),
+        ],
+    );
+    ctx.process_and_validate_instruction_chain(&[(&ix_suspend_provider, &[Check::success()])]);
+    let suspended_result = ctx.process_instruction(&ix_verify_recognized(provider_recognition_pda));
+    assert!(suspended_result.program_result.is_err());
+
+    let store_ref = ctx.account_store.borrow();
+    let credential_account = store_ref.get(&credential_pda).expect("credential account");
+    let mut credential_data: &[u8] = credential_account.data.as_slice();
+    let credential =
+        Credential::try_deserialize(&mut credential_data).expect("credential deserialize");
+    assert_eq!(credential.verification_count, 2);
+}
+
+#[test]
+fn flow_course_progress_and_minimum_grade() {
+    use crate::state::CourseProgress;
+
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+
+    // The setup course has a single module worth 100% backed by this resource
+    let module_resource_pda = keys.module_resource_pda;
+    let (submission_pda, _submission_bump) = Pubkey::find_program_address(
+        &[
+            b"submission",
+            module_resource_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    let (progress_pda, _progress_bump) = Pubkey::find_program_address(
+        &[
+            b"course-progress",
+            keys.course_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    let (credential_pda, _credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            keys.course_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    let (verification_stats_pda, _stats_bump) = Pubkey::find_program_address(
+        &[b"verification-stats", credential_pda.as_ref()],
+        &PROGRAM_ID,
+    );
+    precreate_pda(&ctx, submission_pda);
+    precreate_pda(&ctx, progress_pda);
+    precreate_pda(&ctx, credential_pda);
+    precreate_pda(&ctx, verification_stats_pda);
+
+    #[derive(AnchorSerialize)]
+    struct CreateSubmissionArgs {
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+    }
+    let ix_create_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_submission",
+            &CreateSubmissionArgs {
+                assets: vec![],
+                evidence_assets: vec![],
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(module_resource_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    #[derive(AnchorSerialize)]
+    struct GradeSubmissionArgs {
+        grade: f64,
+        feedback: Option<String>,
+    }
+    let ix_grade_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "grade_submission",
+            &GradeSubmissionArgs {
+                grade: 80.0,
+                feedback: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(module_resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.mentor, true),
+        ],
+    );
+
+    let ix_accept_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("accept_submission"),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(module_resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.mentor, true),
+        ],
+    );
+
+    let ix_initialize_progress = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("initialize_course_progress"),
+        vec![
+            AccountMeta::new(progress_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let ix_update_progress = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("update_course_progress"),
+        vec![
+            AccountMeta::new(progress_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(submission_pda, false),
+        ],
+    );
+
+    #[derive(AnchorSerialize)]
+    struct SetCourseMinGradeArgs {
+        min_grade: Option<u8>,
+    }
+    let ix_set_min_grade = |min_grade: Option<u8>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data("set_course_min_grade", &SetCourseMinGradeArgs { min_grade }),
+            vec![
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.provider_authority, true),
+            ],
+        )
+    };
+
+    let ix_create_credential = |course_progress: Option<Pubkey>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("create_credential"),
+            vec![
+                AccountMeta::new(credential_pda, false),
+                AccountMeta::new(verification_stats_pda, false),
+                AccountMeta::new_readonly(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.enrollment_pda, false),
+                AccountMeta::new_readonly(course_progress.unwrap_or(PROGRAM_ID), false),
+                AccountMeta::new(keys.student, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        )
+    };
+
+    // Ungraded submissions do not count towards progress
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_create_submission, &[Check::success()]),
+        (&ix_initialize_progress, &[Check::success()]),
+    ]);
+    let ungraded_result = ctx.process_instruction(&ix_update_progress);
+    assert!(ungraded_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_grade_submission, &[Check::success()]),
+        (&ix_update_progress, &[Check::success()]),
+    ]);
+
+    let read_progress = || {
+        let store_ref = ctx.account_store.borrow();
+        let account = store_ref.get(&progress_pda).expect("progress account");
+        let mut data: &[u8] = account.data.as_slice();
+        CourseProgress::try_deserialize(&mut data).expect("progress deserialize")
+    };
+    let progress = read_progress();
+    assert_eq!(progress.module_grades.len(), 1);
+    assert_eq!(progress.weighted_grade, 80.0);
+    assert_eq!(progress.graded_weight, 100);
+    assert_eq!(progress.completed_workload, 0);
+
+    // A graded but not yet accepted module does not count towards the required workload
+    let incomplete_result = ctx.process_instruction(&ix_create_credential(Some(progress_pda)));
+    assert!(incomplete_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_accept_submission, &[Check::success()]),
+        (&ix_update_progress, &[Check::success()]),
+    ]);
+    assert_eq!(read_progress().completed_workload, 10);
+
+    let out_of_range_result = ctx.process_instruction(&ix_set_min_grade(Some(101)));
+    assert!(out_of_range_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_set_min_grade(Some(90)),
+        &[Check::success()],
+    )]);
+    let missing_progress_result = ctx.process_instruction(&ix_create_credential(None));
+    assert!(missing_progress_result.program_result.is_err());
+    let below_minimum_result = ctx.process_instruction(&ix_create_credential(Some(progress_pda)));
+    assert!(below_minimum_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_set_min_grade(Some(75)), &[Check::success()]),
+        (
+            &ix_create_credential(Some(progress_pda)),
+            &[Check::success()],
+        ),
+    ]);
+}
+
+#[test]
+fn flow_course_enrollment() {
+    use crate::state::Course;
+    use crate::types::{ActivityKind, EnrollmentMode};
+
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+
+    let second_student = Pubkey::new_unique();
+    let outsider = Pubkey::new_unique();
+    for wallet in [second_student, outsider] {
+        ctx.account_store.borrow_mut().insert(
+            wallet,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    let enrollment_pda_for = |student: Pubkey| {
+        Pubkey::find_program_address(
+            &[b"enrollment", keys.course_pda.as_ref(), student.as_ref()],
+            &PROGRAM_ID,
+        )
+        .0
+    };
+    let second_enrollment_pda = enrollment_pda_for(second_student);
+    precreate_pda(&ctx, second_enrollment_pda);
+    precreate_pda(&ctx, enrollment_pda_for(outsider));
+
+    #[derive(AnchorSerialize)]
+    struct SetCourseEnrollmentConfigArgs {
+        mode: EnrollmentMode,
+        capacity: Option<u32>,
+        opens_at: Option<i64>,
+        closes_at: Option<i64>,
+    }
+    let ix_set_enrollment_config = |mode: EnrollmentMode,
+                                    capacity: Option<u32>,
+                                    opens_at: Option<i64>,
+                                    closes_at: Option<i64>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "set_course_enrollment_config",
+                &SetCourseEnrollmentConfigArgs {
+                    mode,
+                    capacity,
+                    opens_at,
+                    closes_at,
+                },
+            ),
+            vec![
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.provider_authority, true),
+            ],
+        )
+    };
+    let ix_enroll = |student: Pubkey, authority: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("enroll_student"),
+            vec![
+                AccountMeta::new(enrollment_pda_for(student), false),
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new_readonly(student, student == authority),
+                AccountMeta::new(authority, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        )
+    };
+    let ix_withdraw = |student: Pubkey, authority: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("withdraw_enrollment"),
+            vec![
+                AccountMeta::new(enrollment_pda_for(student), false),
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new(authority, true),
+            ],
+        )
+    };
+    let read_course = || {
+        let store_ref = ctx.account_store.borrow();
+        let course_account = store_ref.get(&keys.course_pda).expect("course account");
+        let mut course_data: &[u8] = course_account.data.as_slice();
+        Course::try_deserialize(&mut course_data).expect("course deserialize")
+    };
+    assert_eq!(read_course().enrolled_count, 1);
+
+    // Capacity is enforced and cannot drop below current enrollments.
+    let zero_capacity_result = ctx.process_instruction(&ix_set_enrollment_config(
+        EnrollmentMode::SelfEnroll,
+        Some(0),
+        None,
+        None,
+    ));
+    assert!(zero_capacity_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_set_enrollment_config(EnrollmentMode::SelfEnroll, Some(1), None, None),
+        &[Check::success()],
+    )]);
+    let full_result = ctx.process_instruction(&ix_enroll(second_student, second_student));
+    assert!(full_result.program_result.is_err());
+
+    // Enrollment only succeeds inside the window.
+    let inverted_window_result = ctx.process_instruction(&ix_set_enrollment_config(
+        EnrollmentMode::SelfEnroll,
+        None,
+        Some(now + 200),
+        Some(now + 100),
+    ));
+    assert!(inverted_window_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_set_enrollment_config(EnrollmentMode::SelfEnroll, None, Some(now + 100), None),
+        &[Check::success()],
+    )]);
+    let not_open_result = ctx.process_instruction(&ix_enroll(second_student, second_student));
+    assert!(not_open_result.program_result.is_err());
+
+    // With provider enrollment, students cannot enroll themselves.
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_set_enrollment_config(EnrollmentMode::ProviderEnroll, None, None, None),
+        &[Check::success()],
+    )]);
+    let self_enroll_result = ctx.process_instruction(&ix_enroll(second_student, second_student));
+    assert!(self_enroll_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_enroll(second_student, keys.provider_authority),
+        &[Check::success()],
+    )]);
+    assert_eq!(read_course().enrolled_count, 2);
+
+    // Course-linked activities and submissions need an enrollment.
+    let activity_created = now;
+    let (outsider_activity_pda, _activity_bump) = Pubkey::find_program_address(
+        &[
+            b"activity",
+            keys.provider_pda.as_ref(),
+            outsider.as_ref(),
+            &activity_created.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+    precreate_pda(&ctx, outsider_activity_pda);
+    #[derive(AnchorSerialize)]
+    struct CreateActivityArgs {
+        creation_timestamp: i64,
+        kind: ActivityKind,
+        data: String,
+        degree_id: Option<String>,
+        course: Option<Pubkey>,
+        resource_id: Option<String>,
+        resource_kind: Option<crate::types::ResourceKind>,
+    }
+    let ix_outsider_activity = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_activity",
+            &CreateActivityArgs {
+                creation_timestamp: activity_created,
+                kind: ActivityKind::AttendMeeting,
+                data: "Not enrolled".to_string(),
+                degree_id: None,
+                course: Some(keys.course_pda),
+                resource_id: None,
+                resource_kind: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(outsider_activity_pda, false),
+            AccountMeta::new(outsider, true),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let outsider_activity_result = ctx.process_instruction(&ix_outsider_activity);
+    assert!(outsider_activity_result.program_result.is_err());
+
+    let (submission_pda, _submission_bump) = Pubkey::find_program_address(
+        &[
+            b"submission",
+            keys.module_resource_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    precreate_pda(&ctx, submission_pda);
+    #[derive(AnchorSerialize)]
+    struct CreateSubmissionArgs {
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+    }
+    let ix_create_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_submission",
+            &CreateSubmissionArgs {
+                assets: vec![],
+                evidence_assets: vec![],
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    // Only the student or the provider can withdraw; withdrawing closes the enrollment.
+    let outsider_withdraw_result = ctx.process_instruction(&ix_withdraw(keys.student, outsider));
+    assert!(outsider_withdraw_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_withdraw(keys.student, keys.student),
+        &[Check::success()],
+    )]);
+    assert_eq!(read_course().enrolled_count, 1);
+    let withdrawn_submission_result = ctx.process_instruction(&ix_create_submission);
+    assert!(withdrawn_submission_result.program_result.is_err());
+}
+
+#[test]
+fn flow_course_prerequisites() {
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+    let foundation_pda = keys.course_pda;
+    let advanced_keys = create_accepted_course(&ctx, &keys, now + 1);
+    let advanced_pda = advanced_keys.course_pda;
+    precreate_pda(&ctx, advanced_keys.enrollment_pda);
+
+    let (foundation_credential_pda, _foundation_credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            foundation_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    let (advanced_credential_pda, _advanced_credential_bump) = Pubkey::find_program_address(
+        &[b"credential", advanced_pda.as_ref(), keys.student.as_ref()],
+        &PROGRAM_ID,
+    );
+    let (advanced_stats_pda, _advanced_stats_bump) = Pubkey::find_program_address(
+        &[b"verification-stats", advanced_credential_pda.as_ref()],
+        &PROGRAM_ID,
+    );
+    precreate_pda(&ctx, advanced_credential_pda);
+    precreate_pda(&ctx, advanced_stats_pda);
+
+    let ix_add_prerequisite = |prerequisite: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("add_course_prerequisite"),
+            vec![
+                AccountMeta::new(advanced_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.provider_authority, true),
+                AccountMeta::new_readonly(prerequisite, false),
+            ],
+        )
+    };
+    let with_prerequisite_credential = |mut ix: Instruction| {
+        ix.accounts
+            .push(AccountMeta::new_readonly(foundation_credential_pda, false));
+        ix
+    };
+    let ix_enroll = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("enroll_student"),
+        vec![
+            AccountMeta::new(advanced_keys.enrollment_pda, false),
+            AccountMeta::new(advanced_pda, false),
+            AccountMeta::new_readonly(keys.student, true),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    let self_prerequisite_result = ctx.process_instruction(&ix_add_prerequisite(advanced_pda));
+    assert!(self_prerequisite_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_add_prerequisite(foundation_pda),
+        &[Check::success()],
+    )]);
+    let duplicate_result = ctx.process_instruction(&ix_add_prerequisite(foundation_pda));
+    assert!(duplicate_result.program_result.is_err());
+
+    // Enrolling needs the foundation credential, and it must be Verified or Minted.
+    let missing_credential_result = ctx.process_instruction(&ix_enroll);
+    assert!(missing_credential_result.program_result.is_err());
+    let foundation_credential = create_pending_credential(&ctx, &keys);
+    assert_eq!(foundation_credential, foundation_credential_pda);
+    let pending_credential_result =
+        ctx.process_instruction(&with_prerequisite_credential(ix_enroll.clone()));
+    assert!(pending_credential_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_endorse_credential(&keys, foundation_credential_pda),
+            &[Check::success()],
+        ),
+        (
+            &ix_approve_credential(&keys, foundation_credential_pda),
+            &[Check::success()],
+        ),
+        (
+            &with_prerequisite_credential(ix_enroll.clone()),
+            &[Check::success()],
+        ),
+    ]);
+
+    // Credential creation checks the prerequisites again.
+    let advanced_progress_pda = complete_course_modules(&ctx, &advanced_keys);
+    let ix_create_advanced_credential = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("create_credential"),
+        vec![
+            AccountMeta::new(advanced_credential_pda, false),
+            AccountMeta::new(advanced_stats_pda, false),
+            AccountMeta::new_readonly(advanced_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(advanced_keys.enrollment_pda, false),
+            AccountMeta::new_readonly(advanced_progress_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let no_prerequisite_result = ctx.process_instruction(&ix_create_advanced_credential);
+    assert!(no_prerequisite_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &with_prerequisite_credential(ix_create_advanced_credential),
+        &[Check::success()],
+    )]);
+}
+
+#[test]
+fn flow_degree_credential() {
+    use crate::state::DegreeCredential;
+
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+    let advanced_keys = create_accepted_course(&ctx, &keys, now + 1);
+    precreate_pda(&ctx, advanced_keys.enrollment_pda);
+
+    let degree_id = "cs-bsc";
+    let (degree_pda, _degree_bump) = Pubkey::find_program_address(
+        &[b"degree", keys.provider_pda.as_ref(), degree_id.as_bytes()],
+        &PROGRAM_ID,
+    );
+    let (degree_credential_pda, _degree_credential_bump) = Pubkey::find_program_address(
+        &[
+            b"degree-credential",
+            degree_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    precreate_pda(&ctx, degree_pda);
+    precreate_pda(&ctx, degree_credential_pda);
+
+    #[derive(AnchorSerialize)]
+    struct CreateDegreeArgs {
+        degree_id: String,
+        name: String,
+        description: String,
+        workload_required: u32,
+    }
+    let ix_create_degree = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_degree",
+            &CreateDegreeArgs {
+                degree_id: degree_id.to_string(),
+                name: "BSc Computer Science".to_string(),
+                description: "Foundation plus one advanced elective".to_string(),
+                workload_required: 20,
+            },
+        ),
+        vec![
+            AccountMeta::new(degree_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.provider_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let degree_accounts = || {
+        vec![
+            AccountMeta::new(degree_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.provider_authority, true),
+        ]
+    };
+    let ix_add_required = {
+        let mut accounts = degree_accounts();
+        accounts.push(AccountMeta::new_readonly(keys.course_pda, false));
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("add_degree_required_course"),
+            accounts,
+        )
+    };
+    #[derive(AnchorSerialize)]
+    struct AddDegreeElectivePoolArgs {
+        name: String,
+        min_courses: u8,
+    }
+    let ix_add_pool = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "add_degree_elective_pool",
+            &AddDegreeElectivePoolArgs {
+                name: "Advanced".to_string(),
+                min_courses: 1,
+            },
+        ),
+        degree_accounts(),
+    );
+    #[derive(AnchorSerialize)]
+    struct AddDegreeElectiveCourseArgs {
+        pool_index: u8,
+    }
+    let ix_add_elective = |course: Pubkey| {
+        let mut accounts = degree_accounts();
+        accounts.push(AccountMeta::new_readonly(course, false));
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "add_degree_elective_course",
+                &AddDegreeElectiveCourseArgs { pool_index: 0 },
+            ),
+            accounts,
+        )
+    };
+
+    let ix_publish = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("publish_degree"),
+        degree_accounts(),
+    );
+
+    ctx.process_and_validate_instruction_chain(&[(&ix_create_degree, &[Check::success()])]);
+    // An empty degree cannot be published.
+    let empty_publish_result = ctx.process_instruction(&ix_publish);
+    assert!(empty_publish_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_add_required, &[Check::success()]),
+        (&ix_add_pool, &[Check::success()]),
+    ]);
+    // The elective pool holds fewer courses than its minimum.
+    let small_pool_publish_result = ctx.process_instruction(&ix_publish);
+    assert!(small_pool_publish_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_add_elective(advanced_keys.course_pda),
+        &[Check::success()],
+    )]);
+    // A course can only appear once in the degree structure.
+    let duplicate_result = ctx.process_instruction(&ix_add_elective(keys.course_pda));
+    assert!(duplicate_result.program_result.is_err());
+
+    let foundation_credential_pda = issue_credential(&ctx, &keys);
+    let ix_claim = |pairs: &[(Pubkey, Pubkey)]| {
+        let mut accounts = vec![
+            AccountMeta::new(degree_credential_pda, false),
+            AccountMeta::new_readonly(degree_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+        for (credential, course) in pairs {
+            accounts.push(AccountMeta::new_readonly(*credential, false));
+            accounts.push(AccountMeta::new_readonly(*course, false));
+        }
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("claim_degree_credential"),
+            accounts,
+        )
+    };
+
+    // The elective pool and the total workload are not covered by the foundation course alone.
+    let foundation_only_result =
+        ctx.process_instruction(&ix_claim(&[(foundation_credential_pda, keys.course_pda)]));
+    assert!(foundation_only_result.program_result.is_err());
+
+    let ix_enroll_advanced = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("enroll_student"),
+        vec![
+            AccountMeta::new(advanced_keys.enrollment_pda, false),
+            AccountMeta::new(advanced_keys.course_pda, false),
+            AccountMeta::new_readonly(keys.student, true),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    ctx.process_and_validate_instruction_chain(&[(&ix_enroll_advanced, &[Check::success()])]);
+    let advanced_credential_pda = issue_credential(&ctx, &advanced_keys);
+
+    // Claims are only accepted once the degree is published.
+    let unpublished_claim_result = ctx.process_instruction(&ix_claim(&[
+        (foundation_credential_pda, keys.course_pda),
+        (advanced_credential_pda, advanced_keys.course_pda),
+    ]));
+    assert!(unpublished_claim_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(&ix_publish, &[Check::success()])]);
+    // The structure is frozen once published.
+    let frozen_result = ctx.process_instruction(&ix_add_required);
+    assert!(frozen_result.program_result.is_err());
+
+    // Credentials must be paired with their own course.
+    let mismatched_result = ctx.process_instruction(&ix_claim(&[
+        (foundation_credential_pda, advanced_keys.course_pda),
+        (advanced_credential_pda, keys.course_pda),
+    ]));
+    assert!(mismatched_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_claim(&[
+            (foundation_credential_pda, keys.course_pda),
+            (advanced_credential_pda, advanced_keys.course_pda),
+        ]),
+        &[Check::success()],
+    )]);
+
+    let read_degree_credential = || {
+        let store_ref = ctx.account_store.borrow();
+        let degree_credential_account = store_ref
+            .get(&degree_credential_pda)
+            .expect("degree credential account");
+        let mut degree_credential_data: &[u8] = degree_credential_account.data.as_slice();
+        DegreeCredential::try_deserialize(&mut degree_credential_data)
+            .expect("degree credential deserialize")
+    };
+    let degree_credential = read_degree_credential();
+    assert_eq!(degree_credential.degree, degree_pda);
+    assert_eq!(degree_credential.total_workload, 20);
+    assert_eq!(
+        degree_credential.course_credentials,
+        vec![foundation_credential_pda, advanced_credential_pda]
+    );
+    assert_eq!(degree_credential.revoked_at, None);
+
+    // Anyone may invalidate the degree credential, but only on a revoked course credential.
+    let ix_invalidate = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("invalidate_degree_credential"),
+        vec![
+            AccountMeta::new(degree_credential_pda, false),
+            AccountMeta::new_readonly(foundation_credential_pda, false),
+            AccountMeta::new_readonly(keys.mentor, true),
+        ],
+    );
+    let valid_course_credential_result = ctx.process_instruction(&ix_invalidate);
+    assert!(valid_course_credential_result.program_result.is_err());
+
+    let ix_revoke_degree_credential = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("revoke_degree_credential"),
+        vec![
+            AccountMeta::new(degree_credential_pda, false),
+            AccountMeta::new_readonly(degree_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.provider_authority, true),
+        ],
+    );
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_revoke_degree_credential,
+        &[Check::success()],
+    )]);
+    assert!(read_degree_credential().revoked_at.is_some());
+
+    let second_revoke_result = ctx.process_instruction(&ix_revoke_degree_credential);
+    assert!(second_revoke_result.program_result.is_err());
+}
+
+#[test]
+fn flow_staff_roles() {
+    use crate::state::{Activity, Course};
+    use crate::types::{ActivityKind, StaffRole};
+
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+
+    let assistant = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    for wallet in [assistant, admin] {
+        ctx.account_store.borrow_mut().insert(
+            wallet,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+
+    let activity_created = now;
+    let (activity_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"activity",
+            keys.provider_pda.as_ref(),
+            keys.student.as_ref(),
+            &activity_created.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+    precreate_pda(&ctx, activity_pda);
+
+    #[derive(AnchorSerialize)]
+    struct CreateActivityArgs {
+        creation_timestamp: i64,
+        kind: ActivityKind,
+        data: String,
+        degree_id: Option<String>,
+        course: Option<Pubkey>,
+        resource_id: Option<String>,
+        resource_kind: Option<crate::types::ResourceKind>,
+    }
+    let ix_create_activity = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_activity",
+            &CreateActivityArgs {
+                creation_timestamp: activity_created,
+                kind: ActivityKind::SubmitAssignment,
+                data: "Essay draft".to_string(),
+                degree_id: None,
+                course: Some(keys.course_pda),
+                resource_id: None,
+                resource_kind: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(activity_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    #[derive(AnchorSerialize)]
+    struct AddFeedbackArgs {
+        content: String,
+        asset_ids: Vec<String>,
+        evidence_asset_ids: Vec<String>,
+    }
+    let ix_add_feedback = |author: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "add_feedback",
+                &AddFeedbackArgs {
+                    content: "Tighten the introduction".to_string(),
+                    asset_ids: vec![],
+                    evidence_asset_ids: vec![],
+                },
+            ),
+            vec![
+                AccountMeta::new(activity_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.course_pda, false),
+                AccountMeta::new(author, true),
+            ],
+        )
+    };
+    #[derive(AnchorSerialize)]
+    struct AddGradeArgs {
+        grade_value: f64,
+        asset_ids: Vec<String>,
+        evidence_asset_ids: Vec<String>,
+    }
+    let ix_add_grade = |teacher: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "add_grade",
+                &AddGradeArgs {
+                    grade_value: 80.0,
+                    asset_ids: vec![],
+                    evidence_asset_ids: vec![],
+                },
+            ),
+            vec![
+                AccountMeta::new(activity_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.course_pda, false),
+                AccountMeta::new(teacher, true),
+            ],
+        )
+    };
+    #[derive(AnchorSerialize)]
+    struct SetCourseStaffRoleArgs {
+        role: Option<StaffRole>,
+    }
+    let ix_set_course_staff_role = |staff: Pubkey, role: Option<StaffRole>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data("set_course_staff_role", &SetCourseStaffRoleArgs { role }),
+            vec![
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.provider_authority, true),
+                AccountMeta::new_readonly(staff, false),
+            ],
+        )
+    };
+    #[derive(AnchorSerialize)]
+    struct SetCourseValidityPeriodArgs {
+        validity_period: Option<i64>,
+    }
+    let ix_set_validity_by = |authority: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "set_course_validity_period",
+                &SetCourseValidityPeriodArgs {
+                    validity_period: Some(86_400),
+                },
+            ),
+            vec![
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(authority, true),
+            ],
+        )
+    };
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_set_provider_staff_role(&keys, assistant, StaffRole::TeachingAssistant),
+            &[Check::success()],
+        ),
+        (
+            &ix_set_provider_staff_role(&keys, admin, StaffRole::Admin),
+            &[Check::success()],
+        ),
+        (&ix_create_activity, &[Check::success()]),
+        (&ix_add_feedback(assistant), &[Check::success()]),
+    ]);
+
+    // Teaching assistants leave feedback but cannot grade; admins cannot grade either.
+    let assistant_grade_result = ctx.process_instruction(&ix_add_grade(assistant));
+    assert!(assistant_grade_result.program_result.is_err());
+    let admin_grade_result = ctx.process_instruction(&ix_add_grade(admin));
+    assert!(admin_grade_result.program_result.is_err());
+
+    // Admins manage course settings; other staff cannot, and only the provider assigns roles.
+    let assistant_manage_result = ctx.process_instruction(&ix_set_validity_by(assistant));
+    assert!(assistant_manage_result.program_result.is_err());
+    let admin_assign_result = ctx.process_instruction(&Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "set_course_staff_role",
+            &SetCourseStaffRoleArgs {
+                role: Some(StaffRole::Instructor),
+            },
+        ),
+        vec![
+            AccountMeta::new(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new_readonly(assistant, false),
+        ],
+    ));
+    assert!(admin_assign_result.program_result.is_err());
+
+    // A course override promotes the assistant to instructor on this course only.
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_set_validity_by(admin), &[Check::success()]),
+        (
+            &ix_set_course_staff_role(assistant, Some(StaffRole::Instructor)),
+            &[Check::success()],
+        ),
+        (&ix_add_grade(assistant), &[Check::success()]),
+        (
+            &ix_set_course_staff_role(assistant, None),
+            &[Check::success()],
+        ),
+    ]);
+
+    // An override of None removes the assistant's access to the course.
+    let revoked_feedback_result = ctx.process_instruction(&ix_add_feedback(assistant));
+    assert!(revoked_feedback_result.program_result.is_err());
+
+    // Approving credentials is a course-management action: admins may, other staff may not.
+    let credential_pda = create_pending_credential(&ctx, &keys);
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_endorse_credential(&keys, credential_pda),
+        &[Check::success()],
+    )]);
+    let assistant_approve_result =
+        ctx.process_instruction(&ix_approve_credential_as(&keys, credential_pda, assistant));
+    assert!(assistant_approve_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_approve_credential_as(&keys, credential_pda, admin),
+        &[Check::success()],
+    )]);
+
+    let store_ref = ctx.account_store.borrow();
+    let activity_account = store_ref.get(&activity_pda).expect("activity account");
+    let mut activity_data: &[u8] = activity_account.data.as_slice();
+    let activity_state =
+        Activity::try_deserialize(&mut activity_data).expect("activity deserialize");
+    assert_eq!(activity_state.grade, Some(80.0));
+    assert_eq!(activity_state.graded_by, Some(assistant));
+
+    let course_account = store_ref.get(&keys.course_pda).expect("course account");
+    let mut course_data: &[u8] = course_account.data.as_slice();
+    let course_state = Course::try_deserialize(&mut course_data).expect("course deserialize");
+    assert_eq!(course_state.validity_period, Some(86_400));
+    assert_eq!(course_state.staff_overrides.len(), 1);
+    assert_eq!(course_state.staff_overrides[0].role, None);
+    assert_eq!(course_state.approved_credentials, vec![credential_pda]);
+}
+
+#[test]
+fn flow_submission_revision() {
+    use crate::state::Submission;
+    use crate::types::SubmissionStatus;
+
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+
+    let submitted_at = now;
+    let (submission_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"submission",
+            keys.module_resource_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    precreate_pda(&ctx, submission_pda);
+
+    #[derive(AnchorSerialize)]
+    struct SetResourceMaxAttemptsArgs {
+        max_attempts: Option<u8>,
+    }
+    let ix_set_max_attempts = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "set_resource_max_attempts",
+            &SetResourceMaxAttemptsArgs {
+                max_attempts: Some(2),
+            },
+        ),
+        vec![
+            AccountMeta::new(keys.module_resource_pda, false),
+            AccountMeta::new(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.provider_authority, true),
+        ],
+    );
+    #[derive(AnchorSerialize)]
+    struct CreateSubmissionArgs {
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+    }
+    let ix_create_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_submission",
+            &CreateSubmissionArgs {
+                assets: vec![],
+                evidence_assets: vec![],
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let grader_accounts = || {
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.mentor, true),
+        ]
+    };
+    #[derive(AnchorSerialize)]
+    struct GradeSubmissionArgs {
+        grade: f64,
+        feedback: Option<String>,
+    }
+    let ix_grade = |grade: f64| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "grade_submission",
+                &GradeSubmissionArgs {
+                    grade,
+                    feedback: None,
+                },
+            ),
+            grader_accounts(),
+        )
+    };
+    #[derive(AnchorSerialize)]
+    struct ReturnSubmissionArgs {
+        feedback: String,
+    }
+    let ix_return = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "return_submission",
+            &ReturnSubmissionArgs {
+                feedback: "Cite your sources".to_string(),
+            },
+        ),
+        grader_accounts(),
+    );
+    let ix_accept = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("accept_submission"),
+        grader_accounts(),
+    );
+    #[derive(AnchorSerialize)]
+    struct ResubmitSubmissionArgs {
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+    }
+    let revised_asset = Pubkey::new_unique();
+    let ix_resubmit = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "resubmit_submission",
+            &ResubmitSubmissionArgs {
+                assets: vec![revised_asset],
+                evidence_assets: vec![],
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new_readonly(keys.student, true),
+        ],
+    );
+
+    // A submission can only be resubmitted after it was returned.
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_set_max_attempts, &[Check::success()]),
+        (&ix_create_submission, &[Check::success()]),
+    ]);
+    let early_resubmit_result = ctx.process_instruction(&ix_resubmit);
+    assert!(early_resubmit_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_grade(40.0), &[Check::success()]),
+        (&ix_return, &[Check::success()]),
+    ]);
+    // Returned submissions cannot be accepted or graded until the student resubmits.
+    let accept_returned_result = ctx.process_instruction(&ix_accept);
+    assert!(accept_returned_result.program_result.is_err());
+    let grade_returned_result = ctx.process_instruction(&ix_grade(90.0));
+    assert!(grade_returned_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_resubmit, &[Check::success()]),
+        (&ix_grade(85.0), &[Check::success()]),
+        (&ix_return, &[Check::success()]),
+    ]);
+    // The resource allows two attempts in total.
+    let third_attempt_result = ctx.process_instruction(&ix_resubmit);
+    assert!(third_attempt_result.program_result.is_err());
+    // Attempts are counted per student: a fresh submission cannot restart the count.
+    let new_submission_result = ctx.process_instruction(&ix_create_submission);
+    assert!(new_submission_result.program_result.is_err());
+
+    let store_ref = ctx.account_store.borrow();
+    let submission_account = store_ref.get(&submission_pda).expect("submission account");
+    let mut submission_data: &[u8] = submission_account.data.as_slice();
+    let submission_state =
+        Submission::try_deserialize(&mut submission_data).expect("submission deserialize");
+    assert_eq!(submission_state.status, SubmissionStatus::Returned);
+    assert_eq!(submission_state.attempt, 2);
+    assert_eq!(submission_state.assets, vec![revised_asset]);
+    assert_eq!(submission_state.grade, Some(85.0));
+    assert_eq!(submission_state.attempts.len(), 1);
+    assert_eq!(submission_state.attempts[0].attempt, 1);
+    assert_eq!(submission_state.attempts[0].submitted_at, submitted_at);
+    assert_eq!(submission_state.attempts[0].grade, Some(40.0));
+}
+
+#[test]
+fn flow_assignment_deadlines() {
+    use crate::state::Submission;
+    use crate::types::LatePenaltyPolicy;
+
+    const DAY: i64 = 86_400;
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+
+    #[derive(AnchorSerialize)]
+    struct SetResourceDeadlinesArgs {
+        opens_at: Option<i64>,
+        due_at: Option<i64>,
+        closes_at: Option<i64>,
+        late_penalty: Option<LatePenaltyPolicy>,
+    }
+    let ix_set_deadlines = |args: SetResourceDeadlinesArgs| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data("set_resource_deadlines", &args),
+            vec![
+                AccountMeta::new(keys.module_resource_pda, false),
+                AccountMeta::new(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new(keys.provider_authority, true),
+            ],
+        )
+    };
+    let (submission_pda, _submission_bump) = Pubkey::find_program_address(
+        &[
+            b"submission",
+            keys.module_resource_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    precreate_pda(&ctx, submission_pda);
+    #[derive(AnchorSerialize)]
+    struct CreateSubmissionArgs {
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+    }
+    let ix_create_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_submission",
+            &CreateSubmissionArgs {
+                assets: vec![],
+                evidence_assets: vec![],
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let grader_accounts = || {
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.mentor, true),
+        ]
+    };
+    #[derive(AnchorSerialize)]
+    struct GradeSubmissionArgs {
+        grade: f64,
+        feedback: Option<String>,
+    }
+    let ix_grade_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "grade_submission",
+            &GradeSubmissionArgs {
+                grade: 80.0,
+                feedback: None,
+            },
+        ),
+        grader_accounts(),
+    );
+    #[derive(AnchorSerialize)]
+    struct ReturnSubmissionArgs {
+        feedback: String,
+    }
+    let ix_return = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "return_submission",
+            &ReturnSubmissionArgs {
+                feedback: "Please revise".to_string(),
+            },
+        ),
+        grader_accounts(),
+    );
+    #[derive(AnchorSerialize)]
+    struct ResubmitSubmissionArgs {
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+    }
+    let ix_resubmit = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "resubmit_submission",
+            &ResubmitSubmissionArgs {
+                assets: vec![],
+                evidence_assets: vec![],
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new_readonly(keys.student, true),
+        ],
+    );
+
+    // Not open yet: submissions are rejected.
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_set_deadlines(SetResourceDeadlinesArgs {
+            opens_at: Some(now + DAY),
+            due_at: None,
+            closes_at: None,
+            late_penalty: None,
+        }),
+        &[Check::success()],
+    )]);
+    let not_open_result = ctx.process_instruction(&ix_create_submission);
+    assert!(not_open_result.program_result.is_err());
+
+    // Two days and one second past the due date: three started days at 10% each.
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_set_deadlines(SetResourceDeadlinesArgs {
+                opens_at: Some(now - 10 * DAY),
+                due_at: Some(now - 2 * DAY - 1),
+                closes_at: Some(now + DAY),
+                late_penalty: Some(LatePenaltyPolicy {
+                    percent_per_day: 10,
+                    max_percent: 50,
+                }),
+            }),
+            &[Check::success()],
+        ),
+        (&ix_create_submission, &[Check::success()]),
+        (&ix_grade_submission, &[Check::success()]),
+    ]);
+
+    let read_submission = || {
+        let store_ref = ctx.account_store.borrow();
+        let submission_account = store_ref.get(&submission_pda).expect("submission account");
+        let mut submission_data: &[u8] = submission_account.data.as_slice();
+        Submission::try_deserialize(&mut submission_data).expect("submission deserialize")
+    };
+    let submission_state = read_submission();
+    assert!(submission_state.late);
+    assert_eq!(submission_state.grade, Some(56.0));
+
+    // After the hard close no new work is accepted, not even a requested revision.
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_set_deadlines(SetResourceDeadlinesArgs {
+                opens_at: None,
+                due_at: Some(now - 2 * DAY),
+                closes_at: Some(now - DAY),
+                late_penalty: None,
+            }),
+            &[Check::success()],
+        ),
+        (&ix_return, &[Check::success()]),
+    ]);
+    let closed_result = ctx.process_instruction(&ix_resubmit);
+    assert!(closed_result.program_result.is_err());
+}
+
+#[test]
+fn flow_rubric_grading() {
+    use crate::state::{Resource, RubricCriterion, RubricLevel, Submission};
+    use crate::types::ResourceKind;
+
+    let now: i64 = 1_700_000_000;
+    let (ctx, keys) = setup_hub_provider_course(now, now);
+
+    let resource_created = now;
+    let (resource_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"resource",
+            keys.course_pda.as_ref(),
+            &resource_created.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+    let (rubric_pda, _bump) =
+        Pubkey::find_program_address(&[b"rubric", resource_pda.as_ref()], &PROGRAM_ID);
+    let (submission_pda, _bump) = Pubkey::find_program_address(
+        &[b"submission", resource_pda.as_ref(), keys.student.as_ref()],
+        &PROGRAM_ID,
+    );
+    for pda in [resource_pda, rubric_pda, submission_pda] {
+        precreate_pda(&ctx, pda);
+    }
+
+    #[derive(AnchorSerialize)]
+    struct AddResourceArgs {
+        creation_timestamp: i64,
+        kind: ResourceKind,
+        name: String,
+        external_id: Option<String>,
+        workload: Option<u32>,
+        tags: Vec<String>,
+        nostr_d_tag: Option<String>,
+        nostr_author_pubkey: Option<[u8; 32]>,
+    }
+    let ix_add_resource = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "add_resource",
+            &AddResourceArgs {
+                creation_timestamp: resource_created,
+                kind: ResourceKind::AssignmentSummative,
+                name: "Final Essay".to_string(),
+                external_id: None,
+                workload: Some(20),
+                tags: vec![],
+                nostr_d_tag: None,
+                nostr_author_pubkey: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.provider_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    let criterion = |name: &str, weight: u8| RubricCriterion {
+        name: name.to_string(),
+        weight,
+        levels: vec![
+            RubricLevel {
+                name: "Missing".to_string(),
+                points: 0,
+            },
+            RubricLevel {
+                name: "Developing".to_string(),
+                points: 2,
+            },
+            RubricLevel {
+                name: "Proficient".to_string(),
+                points: 4,
+            },
+        ],
+    };
+    #[derive(AnchorSerialize)]
+    struct RubricArgs {
+        criteria: Vec<RubricCriterion>,
+    }
+    let ix_create_rubric = |criteria: Vec<RubricCriterion>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data("create_rubric", &RubricArgs { criteria }),
+            vec![
+                AccountMeta::new(rubric_pda, false),
+                AccountMeta::new(resource_pda, false),
+                AccountMeta::new_readonly(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new(keys.provider_authority, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        )
+    };
+    let ix_update_rubric = |criteria: Vec<RubricCriterion>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data("update_rubric", &RubricArgs { criteria }),
+            vec![
+                AccountMeta::new(rubric_pda, false),
+                AccountMeta::new_readonly(resource_pda, false),
+                AccountMeta::new_readonly(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new_readonly(keys.provider_authority, true),
+            ],
+        )
+    };
+
+    #[derive(AnchorSerialize)]
+    struct CreateSubmissionArgs {
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+    }
+    let ix_create_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_submission",
+            &CreateSubmissionArgs {
+                assets: vec![],
+                evidence_assets: vec![],
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(resource_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    #[derive(AnchorSerialize)]
+    struct GradeSubmissionArgs {
+        grade: f64,
+        feedback: Option<String>,
+    }
+    let ix_grade_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "grade_submission",
+            &GradeSubmissionArgs {
+                grade: 100.0,
+                feedback: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.mentor, true),
+        ],
+    );
+    #[derive(AnchorSerialize)]
+    struct GradeWithRubricArgs {
+        levels: Vec<u8>,
+        feedback: Option<String>,
+    }
+    let ix_grade_with_rubric = |levels: Vec<u8>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "grade_submission_with_rubric",
+                &GradeWithRubricArgs {
+                    levels,
+                    feedback: Some("See rubric".to_string()),
+                },
+            ),
+            vec![
+                AccountMeta::new(submission_pda, false),
+                AccountMeta::new(rubric_pda, false),
+                AccountMeta::new_readonly(resource_pda, false),
+                AccountMeta::new_readonly(keys.course_pda, false),
+                AccountMeta::new_readonly(keys.provider_pda, false),
+                AccountMeta::new_readonly(keys.hub_pda, false),
+                AccountMeta::new(keys.mentor, true),
+            ],
+        )
+    };
+
+    ctx.process_and_validate_instruction_chain(&[(&ix_add_resource, &[Check::success()])]);
+
+    // Weights must add up to 100.
+    let bad_weights_result = ctx.process_instruction(&ix_create_rubric(vec![
+        criterion("Argument", 50),
+        criterion("Sources", 20),
+    ]));
+    assert!(bad_weights_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_create_rubric(vec![
+                criterion("Argument", 50),
+                criterion("Sources", 40),
+                criterion("Style", 10),
+            ]),
+            &[Check::success()],
+        ),
+        // The rubric can still change while nothing has been graded with it
+        (
+            &ix_update_rubric(vec![
+                criterion("Argument", 50),
+                criterion("Sources", 30),
+                criterion("Style", 20),
+            ]),
+            &[Check::success()],
+        ),
+        (&ix_create_submission, &[Check::success()]),
+    ]);
+
+    // Free-form grades are refused once the resource has a rubric, as are partial scorings.
+    let free_grade_result = ctx.process_instruction(&ix_grade_submission);
+    assert!(free_grade_result.program_result.is_err());
+    let partial_result = ctx.process_instruction(&ix_grade_with_rubric(vec![2, 1]));
+    assert!(partial_result.program_result.is_err());
+    let bad_level_result = ctx.process_instruction(&ix_grade_with_rubric(vec![2, 1, 3]));
+    assert!(bad_level_result.program_result.is_err());
+
+    // 50 * 4/4 + 30 * 2/4 + 20 * 0/4 = 65
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_grade_with_rubric(vec![2, 1, 0]),
+        &[Check::success()],
+    )]);
+
+    // Once a submission is graded with it, the rubric is frozen.
+    let locked_update_result = ctx.process_instruction(&ix_update_rubric(vec![
+        criterion("Argument", 40),
+        criterion("Sources", 40),
+        criterion("Style", 20),
+    ]));
+    assert!(locked_update_result.program_result.is_err());
+
+    let store_ref = ctx.account_store.borrow();
+    let resource_account = store_ref.get(&resource_pda).expect("resource account");
+    let mut resource_data: &[u8] = resource_account.data.as_slice();
+    let resource_state =
+        Resource::try_deserialize(&mut resource_data).expect("resource deserialize");
+    assert_eq!(resource_state.rubric, Some(rubric_pda));
+
+    let submission_account = store_ref.get(&submission_pda).expect("submission account");
+    let mut submission_data: &[u8] = submission_account.data.as_slice();
+    let submission_state =
+        Submission::try_deserialize(&mut submission_data).expect("submission deserialize");
+    assert_eq!(submission_state.grade, Some(65.0));
+    assert_eq!(submission_state.graded_by, Some(keys.mentor));
+    assert_eq!(submission_state.rubric_scores.len(), 3);
+    assert_eq!(submission_state.rubric_scores[0].points, 4);
+    assert_eq!(submission_state.rubric_scores[1].level, 1);
+    assert_eq!(submission_state.rubric_scores[1].weight, 30);
+    assert_eq!(submission_state.rubric_scores[2].max_points, 4);
+}
+
+#[derive(Clone, Copy)]
+struct SetupKeys {
+    hub_authority: Pubkey,
+    provider_authority: Pubkey,
+    provider_pda: Pubkey,
+    hub_pda: Pubkey,
+    course_pda: Pubkey,
+    /// Resource behind the setup course's single 100% module
+    module_resource_pda: Pubkey,
+    /// Student's enrollment in the setup course
+    enrollment_pda: Pubkey,
+    student: Pubkey,
+    mentor: Pubkey,
+}
+
+fn setup_hub_provider_course(
+    now: i64,
+    course_creation_timestamp: i64,
+) -> (
+    mollusk_svm::MolluskContext<HashMap<Pubkey, Account>>,
+    SetupKeys,
+) {
+    use crate::state::{Course, Hub, Provider};
+
+    let hub_authority = Pubkey::new_unique();
+    let provider_authority = Pubkey::new_unique();
+    let student = Pubkey::new_unique();
+    let mentor = Pubkey::new_unique();
+
+    let (hub_pda, _hub_bump) =
+        Pubkey::find_program_address(&[b"hub", b"test-hub".as_ref()], &PROGRAM_ID);
+    let (provider_pda, _provider_bump) = Pubkey::find_program_address(
+        &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
+        &PROGRAM_ID,
+    );
+    let (course_pda, _course_bump) = Pubkey::find_program_address(
+        &[
+            b"course",
+            hub_pda.as_ref(),
+            provider_pda.as_ref(),
+            &course_creation_timestamp.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+
+    let mollusk = mollusk_with_program(now);
+
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    // Signers
+    store.insert(
+        hub_authority,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+    store.insert(
+        provider_authority,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+    store.insert(
+        student,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+    store.insert(
+        mentor,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+
+    // Uninitialized PDAs
+    store.insert(hub_pda, Account::new(0, 0, &system_program::id()));
+    let (provider_queue_pda, _provider_queue_bump) =
+        Pubkey::find_program_address(&[b"provider-queue", hub_pda.as_ref()], &PROGRAM_ID);
+    store.insert(
+        provider_queue_pda,
+        Account::new(0, 0, &system_program::id()),
+    );
+    store.insert(
+        course_review_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
+    store.insert(provider_pda, Account::new(0, 0, &system_program::id()));
+    store.insert(course_pda, Account::new(0, 0, &system_program::id()));
+    let (enrollment_pda, _enrollment_bump) = Pubkey::find_program_address(
+        &[b"enrollment", course_pda.as_ref(), student.as_ref()],
+        &PROGRAM_ID,
+    );
+    store.insert(enrollment_pda, Account::new(0, 0, &system_program::id()));
+    // Created one second before `now` so tests can add their own resources at `now`
+    let module_resource_created = now - 1;
+    let (module_resource_pda, _module_resource_bump) = Pubkey::find_program_address(
+        &[
+            b"resource",
+            course_pda.as_ref(),
+            &module_resource_created.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+    store.insert(
+        module_resource_pda,
+        Account::new(0, 0, &system_program::id()),
+    );
+
+    let ctx = mollusk.with_context(store);
+
+    // initialize_hub
+    #[derive(AnchorSerialize)]
+    struct InitializeHubArgs {
+        hub_id: String,
+    }
+    let ix_initialize_hub = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "initialize_hub",
+            &InitializeHubArgs {
+                hub_id: "test-hub".to_string(),
+            },
+        ),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(provider_queue_pda, false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
+            AccountMeta::new(hub_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    // initialize_provider
+    #[derive(AnchorSerialize)]
+    struct InitializeProviderArgs {
+        name: String,
+        description: String,
+        website: String,
+        email: String,
+        provider_type: String,
+    }
+    let ix_initialize_provider = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "initialize_provider",
+            &InitializeProviderArgs {
+                name: "Test Provider".to_string(),
+                description: "desc".to_string(),
+                website: "https://example.com".to_string(),
+                email: "test@example.com".to_string(),
+                provider_type: "education".to_string(),
+            },
+        ),
+        vec![
+            AccountMeta::new(provider_pda, false),
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(provider_queue_pda, false),
+            AccountMeta::new(provider_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    // add_accepted_provider
+    let ix_add_accepted_provider = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("add_accepted_provider"),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(hub_authority, true),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(provider_authority, false),
+            AccountMeta::new(provider_queue_pda, false),
+        ],
+    );
+
+    // create_course
+    #[derive(AnchorSerialize)]
+    struct CreateCourseArgs {
+        creation_timestamp: i64,
+        name: String,
+        description: String,
+        workload_required: u32,
+        degree_id: Option<String>,
+        nostr_d_tag: Option<String>,
+        nostr_author_pubkey: Option<[u8; 32]>,
+    }
+    let ix_create_course = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_course",
+            &CreateCourseArgs {
+                creation_timestamp: course_creation_timestamp,
+                name: "Course 101".to_string(),
+                description: "Intro".to_string(),
+                workload_required: 10,
+                degree_id: None,
+                nostr_d_tag: None,
+                nostr_author_pubkey: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(provider_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    // add_resource + add_course_module + finalize_modules (one module carrying 100% of the weight)
+    #[derive(AnchorSerialize)]
+    struct AddResourceArgs {
+        creation_timestamp: i64,
+        kind: crate::types::ResourceKind,
+        name: String,
+        external_id: Option<String>,
+        workload: Option<u32>,
+        tags: Vec<String>,
+        nostr_d_tag: Option<String>,
+        nostr_author_pubkey: Option<[u8; 32]>,
+    }
+    let ix_add_module_resource = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "add_resource",
+            &AddResourceArgs {
+                creation_timestamp: module_resource_created,
+                kind: crate::types::ResourceKind::Assignment,
+                name: "Course Module".to_string(),
+                external_id: None,
+                workload: Some(10),
+                tags: vec![],
+                nostr_d_tag: None,
+                nostr_author_pubkey: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(module_resource_pda, false),
+            AccountMeta::new_readonly(course_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(provider_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    #[derive(AnchorSerialize)]
+    struct AddCourseModuleArgs {
+        percentage: u8,
+    }
+    let ix_add_course_module = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "add_course_module",
+            &AddCourseModuleArgs { percentage: 100 },
+        ),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(module_resource_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(provider_authority, true),
+        ],
+    );
+    let ix_finalize_modules = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("finalize_modules"),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new_readonly(provider_authority, true),
+        ],
+    );
+
+    // update_course_status (submit the course for hub review)
+    #[derive(AnchorSerialize)]
+    struct UpdateCourseStatusArgs {
+        status: crate::types::CourseStatus,
+    }
+    let ix_submit_course_for_review = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "update_course_status",
+            &UpdateCourseStatusArgs {
+                status: crate::types::CourseStatus::InReview,
+            },
+        ),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
+            AccountMeta::new(provider_authority, true),
+        ],
+    );
+
+    // add_accepted_course + enroll_student (the student enrolls themselves)
+    let ix_add_accepted_course = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("add_accepted_course"),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(hub_authority, true),
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
+        ],
+    );
+    let ix_enroll_student = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("enroll_student"),
+        vec![
+            AccountMeta::new(enrollment_pda, false),
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(student, false),
+            AccountMeta::new(student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    // add_provider_endorser (mentor endorses credentials for this provider)
+    let ix_add_provider_endorser = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("add_provider_endorser"),
+        vec![
+            AccountMeta::new(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(provider_authority, true),
+            AccountMeta::new_readonly(mentor, false),
+        ],
+    );
+
+    // set_provider_staff_role (mentor also grades as a provider-wide instructor)
+    let ix_set_mentor_instructor = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "set_provider_staff_role",
+            &SetStaffRoleArgs {
+                role: crate::types::StaffRole::Instructor,
+            },
+        ),
+        vec![
+            AccountMeta::new(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new_readonly(provider_authority, true),
+            AccountMeta::new_readonly(mentor, false),
+        ],
+    );
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub,
+            &[
+                Check::success(),
+                Check::account(&hub_pda)
+                    .owner(&PROGRAM_ID)
+                    .space(8 + Hub::INIT_SPACE)
+                    .rent_exempt()
+                    .build(),
+            ],
+        ),
+        (
+            &ix_initialize_provider,
+            &[
+                Check::success(),
+                Check::account(&provider_pda)
+                    .owner(&PROGRAM_ID)
+                    .space(8 + Provider::INIT_SPACE)
+                    .rent_exempt()
+                    .build(),
+            ],
+        ),
+        (&ix_add_accepted_provider, &[Check::success()]),
+        (&ix_add_provider_endorser, &[Check::success()]),
+        (&ix_set_mentor_instructor, &[Check::success()]),
+        (
+            &ix_create_course,
+            &[
+                Check::success(),
+                Check::account(&course_pda)
+                    .owner(&PROGRAM_ID)
+                    .space(8 + Course::INIT_SPACE)
+                    .rent_exempt()
+                    .build(),
+            ],
+        ),
+        (&ix_add_module_resource, &[Check::success()]),
+        (&ix_add_course_module, &[Check::success()]),
+        (&ix_finalize_modules, &[Check::success()]),
+        (&ix_submit_course_for_review, &[Check::success()]),
+        (&ix_add_accepted_course, &[Check::success()]),
+        (&ix_enroll_student, &[Check::success()]),
+    ]);
+
+    (
+        ctx,
+        SetupKeys {
+            hub_authority,
+            provider_authority,
+            provider_pda,
+            hub_pda,
+            course_pda,
+            module_resource_pda,
+            enrollment_pda,
+            student,
+            mentor,
+        },
+    )
+}
+
+/// Create a second course under the setup provider, take it through review to Accepted and
+/// return setup keys pointing at it (enrollment PDA derived but not created).
+fn create_accepted_course(
+    ctx: &mollusk_svm::MolluskContext<HashMap<Pubkey, Account>>,
+    keys: &SetupKeys,
+    created: i64,
+) -> SetupKeys {
+    let (course_pda, _course_bump) = Pubkey::find_program_address(
+        &[
+            b"course",
+            keys.hub_pda.as_ref(),
+            keys.provider_pda.as_ref(),
+            &created.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+    let (module_resource_pda, _module_resource_bump) = Pubkey::find_program_address(
+        &[b"resource", course_pda.as_ref(), &created.to_le_bytes()],
+        &PROGRAM_ID,
+    );
+    let (enrollment_pda, _enrollment_bump) = Pubkey::find_program_address(
+        &[b"enrollment", course_pda.as_ref(), keys.student.as_ref()],
+        &PROGRAM_ID,
+    );
+    precreate_pda(ctx, course_pda);
+    precreate_pda(ctx, module_resource_pda);
+
+    #[derive(AnchorSerialize)]
+    struct CreateCourseArgs {
+        creation_timestamp: i64,
+        name: String,
+        description: String,
+        workload_required: u32,
+        degree_id: Option<String>,
+        nostr_d_tag: Option<String>,
+        nostr_author_pubkey: Option<[u8; 32]>,
+    }
+    let ix_create_course = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_course",
+            &CreateCourseArgs {
+                creation_timestamp: created,
+                name: "Course 201".to_string(),
+                description: "Advanced".to_string(),
+                workload_required: 10,
+                degree_id: None,
+                nostr_d_tag: None,
+                nostr_author_pubkey: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.provider_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    #[derive(AnchorSerialize)]
+    struct AddResourceArgs {
+        creation_timestamp: i64,
+        kind: crate::types::ResourceKind,
+        name: String,
+        external_id: Option<String>,
+        workload: Option<u32>,
+        tags: Vec<String>,
+        nostr_d_tag: Option<String>,
+        nostr_author_pubkey: Option<[u8; 32]>,
+    }
+    let ix_add_module_resource = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "add_resource",
+            &AddResourceArgs {
+                creation_timestamp: created,
+                kind: crate::types::ResourceKind::Assignment,
+                name: "Course Module".to_string(),
+                external_id: None,
+                workload: Some(10),
+                tags: vec![],
+                nostr_d_tag: None,
+                nostr_author_pubkey: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(module_resource_pda, false),
+            AccountMeta::new_readonly(course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.provider_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    #[derive(AnchorSerialize)]
+    struct AddCourseModuleArgs {
+        percentage: u8,
+    }
+    let ix_add_course_module = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "add_course_module",
+            &AddCourseModuleArgs { percentage: 100 },
+        ),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(module_resource_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.provider_authority, true),
+        ],
+    );
+    let ix_finalize_modules = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("finalize_modules"),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.provider_authority, true),
+        ],
+    );
+    #[derive(AnchorSerialize)]
+    struct UpdateCourseStatusArgs {
+        status: crate::types::CourseStatus,
+    }
+    let ix_submit_course_for_review = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "update_course_status",
+            &UpdateCourseStatusArgs {
+                status: crate::types::CourseStatus::InReview,
+            },
+        ),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(course_review_queue_pda(keys.hub_pda), false),
+            AccountMeta::new(keys.provider_authority, true),
+        ],
+    );
+    let ix_add_accepted_course = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("add_accepted_course"),
+        vec![
+            AccountMeta::new(keys.hub_pda, false),
+            AccountMeta::new(keys.hub_authority, true),
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new(course_review_queue_pda(keys.hub_pda), false),
+        ],
+    );
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_create_course, &[Check::success()]),
+        (&ix_add_module_resource, &[Check::success()]),
+        (&ix_add_course_module, &[Check::success()]),
+        (&ix_finalize_modules, &[Check::success()]),
+        (&ix_submit_course_for_review, &[Check::success()]),
+        (&ix_add_accepted_course, &[Check::success()]),
+    ]);
+
+    SetupKeys {
+        course_pda,
+        module_resource_pda,
+        enrollment_pda,
+        ..*keys
+    }
+}
+
+/// Submit, grade and accept the setup course's module, then fold it into the student's
+/// course progress so the course's required workload is completed.
+fn complete_course_modules(
+    ctx: &mollusk_svm::MolluskContext<HashMap<Pubkey, Account>>,
+    keys: &SetupKeys,
+) -> Pubkey {
+    let (submission_pda, _submission_bump) = Pubkey::find_program_address(
+        &[
+            b"submission",
+            keys.module_resource_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    let (progress_pda, _progress_bump) = Pubkey::find_program_address(
+        &[
+            b"course-progress",
+            keys.course_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    precreate_pda(ctx, submission_pda);
+    precreate_pda(ctx, progress_pda);
+
+    #[derive(AnchorSerialize)]
+    struct CreateSubmissionArgs {
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+    }
+    let ix_create_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "create_submission",
+            &CreateSubmissionArgs {
+                assets: vec![],
+                evidence_assets: vec![],
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    #[derive(AnchorSerialize)]
+    struct GradeSubmissionArgs {
+        grade: f64,
+        feedback: Option<String>,
+    }
+    let ix_grade_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "grade_submission",
+            &GradeSubmissionArgs {
+                grade: 90.0,
+                feedback: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.mentor, true),
+        ],
+    );
+    let ix_accept_submission = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("accept_submission"),
+        vec![
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(keys.module_resource_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.mentor, true),
+        ],
+    );
+    let ix_initialize_progress = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("initialize_course_progress"),
+        vec![
+            AccountMeta::new(progress_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let ix_update_progress = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("update_course_progress"),
+        vec![
+            AccountMeta::new(progress_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(submission_pda, false),
+        ],
+    );
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_create_submission, &[Check::success()]),
+        (&ix_grade_submission, &[Check::success()]),
+        (&ix_accept_submission, &[Check::success()]),
+        (&ix_initialize_progress, &[Check::success()]),
+        (&ix_update_progress, &[Check::success()]),
+    ]);
+
+    progress_pda
+}
+
+/// Complete the setup course's module and create a pending credential for the student.
+fn create_pending_credential(
+    ctx: &mollusk_svm::MolluskContext<HashMap<Pubkey, Account>>,
+    keys: &SetupKeys,
+) -> Pubkey {
+    let progress_pda = complete_course_modules(ctx, keys);
+
+    let (credential_pda, _credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            keys.course_pda.as_ref(),
+            keys.student.as_ref(),
+        ],
+        &PROGRAM_ID,
+    );
+    let (verification_stats_pda, _stats_bump) = Pubkey::find_program_address(
+        &[b"verification-stats", credential_pda.as_ref()],
+        &PROGRAM_ID,
+    );
+    precreate_pda(ctx, credential_pda);
+    precreate_pda(ctx, verification_stats_pda);
+
+    let ix_create_credential = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("create_credential"),
+        vec![
+            AccountMeta::new(credential_pda, false),
+            AccountMeta::new(verification_stats_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.enrollment_pda, false),
+            AccountMeta::new_readonly(progress_pda, false),
+            AccountMeta::new(keys.student, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    ctx.process_and_validate_instruction_chain(&[(&ix_create_credential, &[Check::success()])]);
+
+    credential_pda
+}
+
+fn issue_credential(
+    ctx: &mollusk_svm::MolluskContext<HashMap<Pubkey, Account>>,
+    keys: &SetupKeys,
+) -> Pubkey {
+    let credential_pda = create_pending_credential(ctx, keys);
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_endorse_credential(keys, credential_pda),
+            &[Check::success()],
+        ),
+        (
+            &ix_approve_credential(keys, credential_pda),
+            &[Check::success()],
+        ),
+    ]);
+
+    credential_pda
+}
+
+#[derive(AnchorSerialize)]
+struct SetStaffRoleArgs {
+    role: crate::types::StaffRole,
+}
+
+fn ix_set_provider_staff_role(
+    keys: &SetupKeys,
+    staff: Pubkey,
+    role: crate::types::StaffRole,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data("set_provider_staff_role", &SetStaffRoleArgs { role }),
+        vec![
+            AccountMeta::new(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new_readonly(keys.provider_authority, true),
+            AccountMeta::new_readonly(staff, false),
+        ],
+    )
+}
+
+fn ix_add_provider_endorser(keys: &SetupKeys, endorser: Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("add_provider_endorser"),
+        vec![
+            AccountMeta::new(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(keys.provider_authority, true),
+            AccountMeta::new_readonly(endorser, false),
+        ],
+    )
+}
+
+fn ix_endorse_credential(keys: &SetupKeys, credential_pda: Pubkey) -> Instruction {
+    ix_endorse_credential_as(keys, credential_pda, keys.mentor)
+}
+
+fn ix_endorse_credential_as(
+    keys: &SetupKeys,
+    credential_pda: Pubkey,
+    endorser: Pubkey,
+) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct EndorseCredentialArgs {
+        endorsement_message: String,
+    }
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "endorse_credential",
+            &EndorseCredentialArgs {
+                endorsement_message: "Completed all requirements".to_string(),
+            },
+        ),
+        vec![
+            AccountMeta::new(credential_pda, false),
+            AccountMeta::new_readonly(keys.course_pda, false),
+            AccountMeta::new_readonly(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(endorser, true),
+        ],
+    )
+}
+
+fn ix_approve_credential(keys: &SetupKeys, credential_pda: Pubkey) -> Instruction {
+    ix_approve_credential_as(keys, credential_pda, keys.provider_authority)
+}
+
+fn ix_approve_credential_as(
+    keys: &SetupKeys,
+    credential_pda: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("approve_credential"),
+        vec![
+            AccountMeta::new(credential_pda, false),
+            AccountMeta::new(keys.course_pda, false),
+            AccountMeta::new(keys.provider_pda, false),
+            AccountMeta::new_readonly(keys.hub_pda, false),
+            AccountMeta::new(authority, true),
+        ],
+    )
+}
+
+fn course_review_queue_pda(hub_pda: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"course-review-queue", hub_pda.as_ref()], &PROGRAM_ID).0
 }
 
 fn precreate_pda(ctx: &mollusk_svm::MolluskContext<HashMap<Pubkey, Account>>, pubkey: Pubkey) {
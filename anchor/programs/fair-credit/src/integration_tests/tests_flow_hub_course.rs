@@ -13,6 +13,8 @@ use {
 
 use crate::ID as PROGRAM_ID;
 
+const HUB_ID: &str = "test-hub";
+
 #[test]
 fn flow_hub_provider_course() {
     use crate::state::{Course, Hub, Provider};
@@ -23,7 +25,7 @@ fn flow_hub_provider_course() {
     let hub_authority = Pubkey::new_unique();
     let provider_authority = Pubkey::new_unique();
 
-    let (hub_pda, _hub_bump) = Pubkey::find_program_address(&[b"hub"], &PROGRAM_ID);
+    let hub_pda = hub_pda_for(HUB_ID);
     let (provider_pda, _provider_bump) = Pubkey::find_program_address(
         &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
         &PROGRAM_ID,
@@ -52,6 +54,14 @@ fn flow_hub_provider_course() {
         Account::new(10_000_000_000, 0, &system_program::id()),
     );
     store.insert(hub_pda, Account::new(0, 0, &system_program::id()));
+    store.insert(
+        provider_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
+    store.insert(
+        course_review_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
     store.insert(provider_pda, Account::new(0, 0, &system_program::id()));
     store.insert(course_pda, Account::new(0, 0, &system_program::id()));
 
@@ -107,6 +117,21 @@ fn flow_hub_provider_course() {
                     .build(),
             ],
         ),
+    ]);
+
+    finalize_course_modules(
+        &ctx,
+        hub_pda,
+        provider_pda,
+        provider_authority,
+        course_pda,
+        now,
+    );
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_submit_course_for_review(hub_pda, provider_pda, provider_authority, course_pda),
+            &[Check::success()],
+        ),
         (&ix_add_accepted_course, &[Check::success()]),
     ]);
 
@@ -138,7 +163,7 @@ fn flow_hub_admin_and_provider_reinitialization_guards() {
     let hub_authority = Pubkey::new_unique();
     let provider_authority = Pubkey::new_unique();
 
-    let (hub_pda, _hub_bump) = Pubkey::find_program_address(&[b"hub"], &PROGRAM_ID);
+    let hub_pda = hub_pda_for(HUB_ID);
     let (provider_pda, _provider_bump) = Pubkey::find_program_address(
         &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
         &PROGRAM_ID,
@@ -155,6 +180,14 @@ fn flow_hub_admin_and_provider_reinitialization_guards() {
         Account::new(10_000_000_000, 0, &system_program::id()),
     );
     store.insert(hub_pda, Account::new(0, 0, &system_program::id()));
+    store.insert(
+        provider_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
+    store.insert(
+        course_review_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
     store.insert(provider_pda, Account::new(0, 0, &system_program::id()));
 
     let ctx = mollusk.with_context(store);
@@ -199,7 +232,7 @@ fn flow_course_sharding_and_nonaccepted_provider_rejection() {
     let accepted_provider_authority = Pubkey::new_unique();
     let pending_provider_authority = Pubkey::new_unique();
 
-    let (hub_pda, _hub_bump) = Pubkey::find_program_address(&[b"hub"], &PROGRAM_ID);
+    let hub_pda = hub_pda_for(HUB_ID);
     let (accepted_provider_pda, _accepted_provider_bump) = Pubkey::find_program_address(
         &[
             b"provider",
@@ -264,6 +297,14 @@ fn flow_course_sharding_and_nonaccepted_provider_rejection() {
         Account::new(10_000_000_000, 0, &system_program::id()),
     );
     store.insert(hub_pda, Account::new(0, 0, &system_program::id()));
+    store.insert(
+        provider_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
+    store.insert(
+        course_review_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
     store.insert(
         accepted_provider_pda,
         Account::new(0, 0, &system_program::id()),
@@ -329,6 +370,43 @@ fn flow_course_sharding_and_nonaccepted_provider_rejection() {
         (&ix_create_pending_course, &[Check::success()]),
     ]);
 
+    finalize_course_modules(
+        &ctx,
+        hub_pda,
+        accepted_provider_pda,
+        accepted_provider_authority,
+        accepted_course_pda,
+        now,
+    );
+    finalize_course_modules(
+        &ctx,
+        hub_pda,
+        pending_provider_pda,
+        pending_provider_authority,
+        pending_course_pda,
+        now,
+    );
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_submit_course_for_review(
+                hub_pda,
+                accepted_provider_pda,
+                accepted_provider_authority,
+                accepted_course_pda,
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_submit_course_for_review(
+                hub_pda,
+                pending_provider_pda,
+                pending_provider_authority,
+                pending_course_pda,
+            ),
+            &[Check::success()],
+        ),
+    ]);
+
     let ix_add_pending_course = ix_add_accepted_course(hub_pda, hub_authority, pending_course_pda);
     let pending_result = ctx.process_instruction(&ix_add_pending_course);
     assert!(pending_result.program_result.is_err());
@@ -339,7 +417,7 @@ fn flow_course_sharding_and_nonaccepted_provider_rejection() {
         ix_remove_accepted_course(hub_pda, hub_authority, accepted_course_pda);
     let ix_create_course_list =
         ix_create_course_list(hub_pda, course_list_pda, hub_authority, course_list_index);
-    let ix_add_course_to_list = ix_add_course_to_list(
+    let ix_list_accepted_course = ix_add_course_to_list(
         hub_pda,
         hub_authority,
         course_list_pda,
@@ -357,12 +435,23 @@ fn flow_course_sharding_and_nonaccepted_provider_rejection() {
 
     ctx.process_and_validate_instruction_chain(&[
         (&ix_add_accepted_course, &[Check::success()]),
-        (&ix_remove_accepted_course, &[Check::success()]),
         (&ix_create_course_list, &[Check::success()]),
-        (&ix_add_course_to_list, &[Check::success()]),
+        (&ix_list_accepted_course, &[Check::success()]),
         (&ix_remove_course_from_list, &[Check::success()]),
+        (&ix_remove_accepted_course, &[Check::success()]),
     ]);
 
+    // Only accepted courses can be listed.
+    let ix_list_pending_course = ix_add_course_to_list(
+        hub_pda,
+        hub_authority,
+        course_list_pda,
+        pending_course_pda,
+        course_list_index,
+    );
+    let list_pending_result = ctx.process_instruction(&ix_list_pending_course);
+    assert!(list_pending_result.program_result.is_err());
+
     let store_ref = ctx.account_store.borrow();
 
     let hub_account = store_ref.get(&hub_pda).expect("hub account");
@@ -393,122 +482,2162 @@ fn flow_course_sharding_and_nonaccepted_provider_rejection() {
     assert!(course_list.courses.is_empty());
 }
 
-fn ix_initialize_hub(hub_pda: Pubkey, hub_authority: Pubkey) -> Instruction {
-    Instruction::new_with_bytes(
-        PROGRAM_ID,
-        &anchor_ix_data_no_args("initialize_hub"),
-        vec![
-            AccountMeta::new(hub_pda, false),
-            AccountMeta::new(hub_authority, true),
-            AccountMeta::new_readonly(system_program::id(), false),
+#[test]
+fn flow_provider_suspension_and_reputation() {
+    use crate::state::Provider;
+
+    let now: i64 = 1_700_000_000;
+    let hub_authority = Pubkey::new_unique();
+    let provider_authority = Pubkey::new_unique();
+
+    let hub_pda = hub_pda_for(HUB_ID);
+    let (provider_pda, _provider_bump) = Pubkey::find_program_address(
+        &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
+        &PROGRAM_ID,
+    );
+    let course_creation_timestamp = now;
+    let (course_pda, _course_bump) = Pubkey::find_program_address(
+        &[
+            b"course",
+            hub_pda.as_ref(),
+            provider_pda.as_ref(),
+            &course_creation_timestamp.to_le_bytes(),
         ],
-    )
-}
+        &PROGRAM_ID,
+    );
 
-fn ix_initialize_provider(
-    hub_pda: Pubkey,
-    provider_pda: Pubkey,
-    provider_authority: Pubkey,
-    name: &str,
-) -> Instruction {
-    #[derive(AnchorSerialize)]
-    struct InitializeProviderArgs {
-        name: String,
-        description: String,
-        website: String,
-        email: String,
-        provider_type: String,
-    }
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    store.insert(
+        hub_authority,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+    store.insert(
+        provider_authority,
+        Account::new(10_000_000_000, 0, &system_program::id()),
+    );
+    store.insert(hub_pda, Account::new(0, 0, &system_program::id()));
+    store.insert(
+        provider_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
+    store.insert(
+        course_review_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
+    store.insert(provider_pda, Account::new(0, 0, &system_program::id()));
+    store.insert(course_pda, Account::new(0, 0, &system_program::id()));
 
-    Instruction::new_with_bytes(
-        PROGRAM_ID,
-        &anchor_ix_data(
-            "initialize_provider",
-            &InitializeProviderArgs {
-                name: name.to_string(),
-                description: "desc".to_string(),
-                website: "https://example.com".to_string(),
-                email: "test@example.com".to_string(),
-                provider_type: "education".to_string(),
-            },
+    let ctx = mollusk.with_context(store);
+
+    let ix_create_course = ix_create_course(
+        hub_pda,
+        provider_pda,
+        provider_authority,
+        course_pda,
+        course_creation_timestamp,
+        "Course 101",
+    );
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(hub_pda, provider_pda, provider_authority, "Provider A"),
+            &[Check::success()],
+        ),
+        (
+            &ix_add_accepted_provider(hub_pda, hub_authority, provider_pda, provider_authority),
+            &[Check::success()],
         ),
+    ]);
+
+    let out_of_range_result = ctx.process_instruction(&ix_update_provider_reputation(
+        hub_pda,
+        hub_authority,
+        provider_pda,
+        provider_authority,
+        101,
+    ));
+    assert!(out_of_range_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_update_provider_reputation(
+                hub_pda,
+                hub_authority,
+                provider_pda,
+                provider_authority,
+                85,
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_suspend_provider(hub_pda, hub_authority, provider_pda, provider_authority),
+            &[Check::success()],
+        ),
+    ]);
+
+    let read_provider = || {
+        let store_ref = ctx.account_store.borrow();
+        let provider_account = store_ref.get(&provider_pda).expect("provider account");
+        let mut provider_data: &[u8] = provider_account.data.as_slice();
+        Provider::try_deserialize(&mut provider_data).expect("provider deserialize")
+    };
+
+    let suspended = read_provider();
+    assert!(suspended.suspended);
+    assert_eq!(suspended.suspended_at, Some(now));
+    assert_eq!(suspended.suspension_note.as_deref(), Some("Under review"));
+    assert_eq!(suspended.suspension_count, 1);
+    assert_eq!(suspended.reputation_score, 85);
+
+    // Suspended providers cannot create courses, and cannot be suspended twice.
+    let suspended_course_result = ctx.process_instruction(&ix_create_course);
+    assert!(suspended_course_result.program_result.is_err());
+    let double_suspend_result = ctx.process_instruction(&ix_suspend_provider(
+        hub_pda,
+        hub_authority,
+        provider_pda,
+        provider_authority,
+    ));
+    assert!(double_suspend_result.program_result.is_err());
+
+    // Nor can they close the account to re-register with a clean slate.
+    let ix_close_provider = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("close_provider"),
         vec![
             AccountMeta::new(provider_pda, false),
             AccountMeta::new_readonly(hub_pda, false),
             AccountMeta::new(provider_authority, true),
-            AccountMeta::new_readonly(system_program::id(), false),
         ],
-    )
-}
+    );
+    let suspended_close_result = ctx.process_instruction(&ix_close_provider);
+    assert!(suspended_close_result.program_result.is_err());
 
-fn ix_add_accepted_provider(
-    hub_pda: Pubkey,
-    hub_authority: Pubkey,
-    provider_pda: Pubkey,
-    provider_authority: Pubkey,
-) -> Instruction {
-    Instruction::new_with_bytes(
-        PROGRAM_ID,
-        &anchor_ix_data_no_args("add_accepted_provider"),
-        vec![
-            AccountMeta::new(hub_pda, false),
-            AccountMeta::new(hub_authority, true),
-            AccountMeta::new_readonly(provider_pda, false),
-            AccountMeta::new_readonly(provider_authority, false),
-        ],
-    )
-}
+    let ix_unsuspend_provider =
+        ix_unsuspend_provider(hub_pda, hub_authority, provider_pda, provider_authority);
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_unsuspend_provider, &[Check::success()]),
+        (&ix_create_course, &[Check::success()]),
+    ]);
 
-fn ix_remove_accepted_provider(
-    hub_pda: Pubkey,
-    hub_authority: Pubkey,
-    provider_authority: Pubkey,
-) -> Instruction {
-    Instruction::new_with_bytes(
-        PROGRAM_ID,
-        &anchor_ix_data_no_args("remove_accepted_provider"),
-        vec![
-            AccountMeta::new(hub_pda, false),
-            AccountMeta::new(hub_authority, true),
-            AccountMeta::new_readonly(provider_authority, false),
-        ],
-    )
+    let double_unsuspend_result = ctx.process_instruction(&ix_unsuspend_provider);
+    assert!(double_unsuspend_result.program_result.is_err());
+
+    let restored = read_provider();
+    assert!(!restored.suspended);
+    assert_eq!(restored.suspended_at, None);
+    assert_eq!(restored.suspension_note, None);
+    assert_eq!(restored.suspension_count, 1);
 }
 
-fn ix_update_hub_config(
-    hub_pda: Pubkey,
-    hub_authority: Pubkey,
-    require_provider_approval: bool,
-    min_reputation_score: u64,
-) -> Instruction {
-    #[derive(AnchorSerialize)]
-    struct HubConfigArgs {
-        require_provider_approval: bool,
-        min_reputation_score: u64,
-    }
+#[test]
+fn flow_provider_auto_acceptance_and_queue() {
+    use crate::state::{Hub, PendingProviderQueue};
 
-    #[derive(AnchorSerialize)]
-    struct UpdateHubConfigArgs {
-        config: HubConfigArgs,
+    let now: i64 = 1_700_000_000;
+    let hub_authority = Pubkey::new_unique();
+    let open_provider_authority = Pubkey::new_unique();
+    let reviewed_provider_authority = Pubkey::new_unique();
+    let rejected_provider_authority = Pubkey::new_unique();
+
+    let hub_pda = hub_pda_for(HUB_ID);
+    let provider_pda_for = |wallet: &Pubkey| {
+        Pubkey::find_program_address(
+            &[b"provider", hub_pda.as_ref(), wallet.as_ref()],
+            &PROGRAM_ID,
+        )
+        .0
+    };
+    let open_provider_pda = provider_pda_for(&open_provider_authority);
+    let reviewed_provider_pda = provider_pda_for(&reviewed_provider_authority);
+    let rejected_provider_pda = provider_pda_for(&rejected_provider_authority);
+
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    for signer in [
+        hub_authority,
+        open_provider_authority,
+        reviewed_provider_authority,
+        rejected_provider_authority,
+    ] {
+        store.insert(
+            signer,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    for pda in [
+        hub_pda,
+        provider_queue_pda(hub_pda),
+        course_review_queue_pda(hub_pda),
+        open_provider_pda,
+        reviewed_provider_pda,
+        rejected_provider_pda,
+    ] {
+        store.insert(pda, Account::new(0, 0, &system_program::id()));
     }
 
-    Instruction::new_with_bytes(
-        PROGRAM_ID,
-        &anchor_ix_data(
-            "update_hub_config",
-            &UpdateHubConfigArgs {
-                config: HubConfigArgs {
-                    require_provider_approval,
-                    min_reputation_score,
-                },
-            },
+    let ctx = mollusk.with_context(store);
+
+    // With approval switched off, registration alone joins the hub.
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
         ),
-        vec![
-            AccountMeta::new(hub_pda, false),
-            AccountMeta::new(hub_authority, true),
-        ],
-    )
-}
+        (
+            &ix_update_hub_config(hub_pda, hub_authority, false, 70),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(hub_pda, open_provider_pda, open_provider_authority, "Open"),
+            &[Check::success()],
+        ),
+        (
+            &ix_update_hub_config(hub_pda, hub_authority, true, 70),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(
+                hub_pda,
+                reviewed_provider_pda,
+                reviewed_provider_authority,
+                "Reviewed",
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(
+                hub_pda,
+                rejected_provider_pda,
+                rejected_provider_authority,
+                "Rejected",
+            ),
+            &[Check::success()],
+        ),
+    ]);
+
+    let read_state = || {
+        let store_ref = ctx.account_store.borrow();
+        let hub_account = store_ref.get(&hub_pda).expect("hub account");
+        let mut hub_data: &[u8] = hub_account.data.as_slice();
+        let hub_state = Hub::try_deserialize(&mut hub_data).expect("hub deserialize");
+        let queue_account = store_ref
+            .get(&provider_queue_pda(hub_pda))
+            .expect("provider queue account");
+        let mut queue_data: &[u8] = queue_account.data.as_slice();
+        let queue_state =
+            PendingProviderQueue::try_deserialize(&mut queue_data).expect("queue deserialize");
+        (hub_state, queue_state)
+    };
+
+    let (hub_state, queue_state) = read_state();
+    assert_eq!(hub_state.accepted_providers, vec![open_provider_authority]);
+    assert!(queue_state.contains(&reviewed_provider_authority));
+    assert!(queue_state.contains(&rejected_provider_authority));
+
+    // A new provider has no reputation yet, so the re-check fails until the hub raises it.
+    let ix_accept_reviewed =
+        ix_accept_pending_provider(hub_pda, reviewed_provider_pda, reviewed_provider_authority);
+    let low_reputation_result = ctx.process_instruction(&ix_accept_reviewed);
+    assert!(low_reputation_result.program_result.is_err());
+
+    let ix_reject = ix_reject_pending_provider(hub_pda, hub_authority, rejected_provider_authority);
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_update_provider_reputation(
+                hub_pda,
+                hub_authority,
+                reviewed_provider_pda,
+                reviewed_provider_authority,
+                75,
+            ),
+            &[Check::success()],
+        ),
+        (&ix_accept_reviewed, &[Check::success()]),
+        (&ix_reject, &[Check::success()]),
+    ]);
+
+    let double_reject_result = ctx.process_instruction(&ix_reject);
+    assert!(double_reject_result.program_result.is_err());
+
+    let (hub_state, queue_state) = read_state();
+    assert_eq!(
+        hub_state.accepted_providers,
+        vec![open_provider_authority, reviewed_provider_authority]
+    );
+    assert!(queue_state.providers.is_empty());
+}
+
+#[test]
+fn flow_hub_queue_pruning_and_close() {
+    use crate::state::PendingProviderQueue;
+
+    let now: i64 = 1_700_000_000;
+    let hub_authority = Pubkey::new_unique();
+    let spam_wallets = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let hub_pda = hub_pda_for(HUB_ID);
+    let spam_provider_pdas = spam_wallets.map(|wallet| {
+        Pubkey::find_program_address(
+            &[b"provider", hub_pda.as_ref(), wallet.as_ref()],
+            &PROGRAM_ID,
+        )
+        .0
+    });
+
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    for signer in [hub_authority, spam_wallets[0], spam_wallets[1]] {
+        store.insert(
+            signer,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    for pda in [
+        hub_pda,
+        provider_queue_pda(hub_pda),
+        course_review_queue_pda(hub_pda),
+        spam_provider_pdas[0],
+        spam_provider_pdas[1],
+    ] {
+        store.insert(pda, Account::new(0, 0, &system_program::id()));
+    }
+
+    let ctx = mollusk.with_context(store);
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_update_hub_config(hub_pda, hub_authority, true, 70),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(hub_pda, spam_provider_pdas[0], spam_wallets[0], "Spam 1"),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(hub_pda, spam_provider_pdas[1], spam_wallets[1], "Spam 2"),
+            &[Check::success()],
+        ),
+    ]);
+
+    #[derive(AnchorSerialize)]
+    struct PruneProviderQueueArgs {
+        wallets: Vec<Pubkey>,
+    }
+    let ix_prune = |signer: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "prune_provider_queue",
+                &PruneProviderQueueArgs {
+                    wallets: vec![spam_wallets[0], spam_wallets[1], Pubkey::new_unique()],
+                },
+            ),
+            vec![
+                AccountMeta::new_readonly(hub_pda, false),
+                AccountMeta::new_readonly(signer, true),
+                AccountMeta::new(provider_queue_pda(hub_pda), false),
+            ],
+        )
+    };
+    let stranger_result = ctx.process_instruction(&ix_prune(spam_wallets[0]));
+    assert!(stranger_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(&ix_prune(hub_authority), &[Check::success()])]);
+
+    {
+        let store_ref = ctx.account_store.borrow();
+        let queue_account = store_ref
+            .get(&provider_queue_pda(hub_pda))
+            .expect("provider queue account");
+        let mut queue_data: &[u8] = queue_account.data.as_slice();
+        let queue_state =
+            PendingProviderQueue::try_deserialize(&mut queue_data).expect("queue deserialize");
+        assert!(queue_state.providers.is_empty());
+    }
+
+    // Closing the hub also closes both queues, so the same hub id can be initialized again.
+    let ix_close_hub = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("close_hub"),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(provider_queue_pda(hub_pda), false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
+            AccountMeta::new(hub_authority, true),
+        ],
+    );
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_close_hub, &[Check::success()]),
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
+        ),
+    ]);
+}
+
+#[test]
+fn flow_hub_authority_two_step_transfer() {
+    use crate::state::Hub;
+
+    let now: i64 = 1_700_000_000;
+    let hub_authority = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let stranger = Pubkey::new_unique();
+
+    let hub_pda = hub_pda_for(HUB_ID);
+
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    for signer in [hub_authority, new_authority, stranger] {
+        store.insert(
+            signer,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    store.insert(hub_pda, Account::new(0, 0, &system_program::id()));
+    store.insert(
+        provider_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
+    store.insert(
+        course_review_queue_pda(hub_pda),
+        Account::new(0, 0, &system_program::id()),
+    );
+
+    let ctx = mollusk.with_context(store);
+
+    let ix_transfer = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("transfer_hub_authority"),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new_readonly(hub_authority, true),
+            AccountMeta::new_readonly(new_authority, false),
+        ],
+    );
+    let ix_accept = |signer: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("accept_hub_authority"),
+            vec![
+                AccountMeta::new(hub_pda, false),
+                AccountMeta::new_readonly(signer, true),
+            ],
+        )
+    };
+    let ix_cancel = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("cancel_hub_authority_transfer"),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new_readonly(hub_authority, true),
+        ],
+    );
+
+    let read_hub = || {
+        let store_ref = ctx.account_store.borrow();
+        let hub_account = store_ref.get(&hub_pda).expect("hub account");
+        let mut hub_data: &[u8] = hub_account.data.as_slice();
+        Hub::try_deserialize(&mut hub_data).expect("hub deserialize")
+    };
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
+        ),
+        (&ix_transfer, &[Check::success()]),
+    ]);
+
+    // Proposing does not hand over control yet.
+    let proposed = read_hub();
+    assert_eq!(proposed.authority, hub_authority);
+    assert_eq!(proposed.pending_authority, Some(new_authority));
+
+    let stranger_accept_result = ctx.process_instruction(&ix_accept(stranger));
+    assert!(stranger_accept_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(&ix_cancel, &[Check::success()])]);
+    assert_eq!(read_hub().pending_authority, None);
+
+    let cancelled_accept_result = ctx.process_instruction(&ix_accept(new_authority));
+    assert!(cancelled_accept_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_transfer, &[Check::success()]),
+        (&ix_accept(new_authority), &[Check::success()]),
+    ]);
+
+    let transferred = read_hub();
+    assert_eq!(transferred.authority, new_authority);
+    assert_eq!(transferred.pending_authority, None);
+
+    let old_authority_result =
+        ctx.process_instruction(&ix_update_hub_config(hub_pda, hub_authority, false, 50));
+    assert!(old_authority_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_update_hub_config(hub_pda, new_authority, false, 50),
+        &[Check::success()],
+    )]);
+}
+
+#[test]
+fn flow_hub_governance_proposals() {
+    use crate::state::{CourseList, Hub, HubAction, HubGovernance, HubProposal, ProposalStatus};
+
+    let now: i64 = 1_700_000_000;
+    let hub_authority = Pubkey::new_unique();
+    let provider_authority = Pubkey::new_unique();
+    let members = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let outsider = Pubkey::new_unique();
+    let operator = Pubkey::new_unique();
+
+    let hub_pda = hub_pda_for(HUB_ID);
+    let (provider_pda, _provider_bump) = Pubkey::find_program_address(
+        &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
+        &PROGRAM_ID,
+    );
+    let (governance_pda, _governance_bump) =
+        Pubkey::find_program_address(&[b"hub-governance", hub_pda.as_ref()], &PROGRAM_ID);
+    let proposal_pda = |index: u64| {
+        Pubkey::find_program_address(
+            &[
+                b"hub-proposal",
+                governance_pda.as_ref(),
+                &index.to_le_bytes(),
+            ],
+            &PROGRAM_ID,
+        )
+        .0
+    };
+    let (course_pda, _course_bump) = Pubkey::find_program_address(
+        &[
+            b"course",
+            hub_pda.as_ref(),
+            provider_pda.as_ref(),
+            &now.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+    let course_list_index: u16 = 3;
+    let (course_list_pda, _course_list_bump) = Pubkey::find_program_address(
+        &[
+            b"course-list",
+            hub_pda.as_ref(),
+            &course_list_index.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    for signer in [hub_authority, provider_authority, outsider]
+        .iter()
+        .chain(members.iter())
+    {
+        store.insert(
+            *signer,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    for pda in [
+        hub_pda,
+        provider_queue_pda(hub_pda),
+        course_review_queue_pda(hub_pda),
+        provider_pda,
+        governance_pda,
+        proposal_pda(0),
+        proposal_pda(1),
+        proposal_pda(2),
+        proposal_pda(3),
+        proposal_pda(4),
+        proposal_pda(5),
+        proposal_pda(6),
+        course_pda,
+        course_list_pda,
+    ] {
+        store.insert(pda, Account::new(0, 0, &system_program::id()));
+    }
+
+    let ctx = mollusk.with_context(store);
+
+    #[derive(AnchorSerialize)]
+    struct InitializeHubGovernanceArgs {
+        members: Vec<Pubkey>,
+        threshold: u8,
+    }
+    let ix_initialize_governance = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "initialize_hub_governance",
+            &InitializeHubGovernanceArgs {
+                members: members.to_vec(),
+                threshold: 2,
+            },
+        ),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(governance_pda, false),
+            AccountMeta::new(hub_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    #[derive(AnchorSerialize)]
+    struct CreateHubProposalArgs {
+        action: HubAction,
+    }
+    let ix_create_proposal = |index: u64, proposer: Pubkey, action: HubAction| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data("create_hub_proposal", &CreateHubProposalArgs { action }),
+            vec![
+                AccountMeta::new(proposal_pda(index), false),
+                AccountMeta::new(governance_pda, false),
+                AccountMeta::new_readonly(hub_pda, false),
+                AccountMeta::new(proposer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        )
+    };
+    let ix_approve = |index: u64, member: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("approve_hub_proposal"),
+            vec![
+                AccountMeta::new(proposal_pda(index), false),
+                AccountMeta::new_readonly(governance_pda, false),
+                AccountMeta::new_readonly(member, true),
+            ],
+        )
+    };
+    // Optional accounts a proposal action does not need are passed as the program id.
+    let ix_execute = |index: u64,
+                      provider: Option<Pubkey>,
+                      course: Option<Pubkey>,
+                      course_list: Option<Pubkey>| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("execute_hub_proposal"),
+            vec![
+                AccountMeta::new(proposal_pda(index), false),
+                AccountMeta::new(governance_pda, false),
+                AccountMeta::new(hub_pda, false),
+                AccountMeta::new(provider_queue_pda(hub_pda), false),
+                AccountMeta::new(provider.unwrap_or(PROGRAM_ID), false),
+                AccountMeta::new(course.unwrap_or(PROGRAM_ID), false),
+                AccountMeta::new(course_review_queue_pda(hub_pda), false),
+                AccountMeta::new(course_list.unwrap_or(PROGRAM_ID), false),
+                AccountMeta::new_readonly(outsider, true),
+            ],
+        )
+    };
+    let ix_execute_add_provider = ix_execute(0, Some(provider_pda), None, None);
+
+    #[derive(AnchorSerialize)]
+    struct ExecuteCourseListProposalArgs {
+        course_list_index: u16,
+    }
+    let ix_execute_course_list = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "execute_course_list_proposal",
+            &ExecuteCourseListProposalArgs { course_list_index },
+        ),
+        vec![
+            AccountMeta::new(proposal_pda(1), false),
+            AccountMeta::new(governance_pda, false),
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(course_list_pda, false),
+            AccountMeta::new(members[2], true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(hub_pda, provider_pda, provider_authority, "Provider A"),
+            &[Check::success()],
+        ),
+        (&ix_initialize_governance, &[Check::success()]),
+    ]);
+
+    // The former single authority can no longer act on its own.
+    let direct_result = ctx.process_instruction(&ix_add_accepted_provider(
+        hub_pda,
+        hub_authority,
+        provider_pda,
+        provider_authority,
+    ));
+    assert!(direct_result.program_result.is_err());
+
+    let outsider_proposal_result = ctx.process_instruction(&ix_create_proposal(
+        0,
+        outsider,
+        HubAction::AddProvider {
+            provider_wallet: provider_authority,
+        },
+    ));
+    assert!(outsider_proposal_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_create_proposal(
+            0,
+            members[0],
+            HubAction::AddProvider {
+                provider_wallet: provider_authority,
+            },
+        ),
+        &[Check::success()],
+    )]);
+
+    // One approval out of a threshold of two is not enough.
+    let early_execute_result = ctx.process_instruction(&ix_execute_add_provider);
+    assert!(early_execute_result.program_result.is_err());
+    let outsider_vote_result = ctx.process_instruction(&ix_approve(0, outsider));
+    assert!(outsider_vote_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_approve(0, members[1]), &[Check::success()]),
+        (&ix_execute_add_provider, &[Check::success()]),
+        (
+            &ix_create_proposal(
+                1,
+                members[1],
+                HubAction::CreateCourseList { course_list_index },
+            ),
+            &[Check::success()],
+        ),
+        (&ix_approve(1, members[2]), &[Check::success()]),
+        (&ix_execute_course_list, &[Check::success()]),
+    ]);
+
+    let replay_result = ctx.process_instruction(&ix_execute_add_provider);
+    assert!(replay_result.program_result.is_err());
+
+    // Courses, provider standing and course lists stay manageable under governance.
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_create_course(
+            hub_pda,
+            provider_pda,
+            provider_authority,
+            course_pda,
+            now,
+            "Course 101",
+        ),
+        &[Check::success()],
+    )]);
+    finalize_course_modules(
+        &ctx,
+        hub_pda,
+        provider_pda,
+        provider_authority,
+        course_pda,
+        now,
+    );
+    let ix_list_course = ix_create_proposal(
+        2,
+        members[0],
+        HubAction::AddCourseToList {
+            course_list_index,
+            course: course_pda,
+        },
+    );
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_list_course, &[Check::success()]),
+        (&ix_approve(2, members[1]), &[Check::success()]),
+    ]);
+    // A course still in Draft cannot be listed.
+    let list_draft_result = ctx.process_instruction(&ix_execute(
+        2,
+        None,
+        Some(course_pda),
+        Some(course_list_pda),
+    ));
+    assert!(list_draft_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_submit_course_for_review(hub_pda, provider_pda, provider_authority, course_pda),
+            &[Check::success()],
+        ),
+        (
+            &ix_create_proposal(3, members[2], HubAction::AddCourse { course: course_pda }),
+            &[Check::success()],
+        ),
+        (&ix_approve(3, members[0]), &[Check::success()]),
+        (
+            &ix_execute(3, None, Some(course_pda), None),
+            &[Check::success()],
+        ),
+        (
+            &ix_execute(2, None, Some(course_pda), Some(course_list_pda)),
+            &[Check::success()],
+        ),
+        (
+            &ix_create_proposal(
+                4,
+                members[0],
+                HubAction::SuspendProvider {
+                    provider_wallet: provider_authority,
+                    note: Some("Audit".to_string()),
+                },
+            ),
+            &[Check::success()],
+        ),
+        (&ix_approve(4, members[2]), &[Check::success()]),
+        (
+            &ix_execute(4, Some(provider_pda), None, None),
+            &[Check::success()],
+        ),
+    ]);
+
+    // The hub can leave governance: a proposal nominates a wallet, which then accepts.
+    let ix_accept_authority = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("accept_hub_authority"),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new_readonly(hub_authority, true),
+        ],
+    );
+    ctx.process_and_validate_instruction_chain(&[
+        // Hub-signed operations without a proposal action go through the operator.
+        (
+            &ix_create_proposal(
+                5,
+                members[2],
+                HubAction::SetOperator {
+                    operator: Some(operator),
+                },
+            ),
+            &[Check::success()],
+        ),
+        (&ix_approve(5, members[1]), &[Check::success()]),
+        (&ix_execute(5, None, None, None), &[Check::success()]),
+        (
+            &ix_create_proposal(
+                6,
+                members[1],
+                HubAction::TransferAuthority {
+                    new_authority: hub_authority,
+                },
+            ),
+            &[Check::success()],
+        ),
+        (&ix_approve(6, members[2]), &[Check::success()]),
+        (&ix_execute(6, None, None, None), &[Check::success()]),
+        (&ix_accept_authority, &[Check::success()]),
+        (
+            &ix_unsuspend_provider(hub_pda, hub_authority, provider_pda, provider_authority),
+            &[Check::success()],
+        ),
+    ]);
+
+    let store_ref = ctx.account_store.borrow();
+
+    let hub_account = store_ref.get(&hub_pda).expect("hub account");
+    let mut hub_data: &[u8] = hub_account.data.as_slice();
+    let hub_state = Hub::try_deserialize(&mut hub_data).expect("hub deserialize");
+    assert_eq!(hub_state.authority, hub_authority);
+    assert_eq!(hub_state.pending_authority, None);
+    assert_eq!(hub_state.operator, Some(operator));
+    assert!(hub_state.can_operate(&operator));
+    assert!(hub_state.accepted_providers.contains(&provider_authority));
+    assert!(hub_state.accepted_courses.contains(&course_list_pda));
+
+    let governance_account = store_ref.get(&governance_pda).expect("governance account");
+    let mut governance_data: &[u8] = governance_account.data.as_slice();
+    let governance_state =
+        HubGovernance::try_deserialize(&mut governance_data).expect("governance deserialize");
+    assert_eq!(governance_state.proposal_count, 7);
+
+    let proposal_account = store_ref.get(&proposal_pda(0)).expect("proposal account");
+    let mut proposal_data: &[u8] = proposal_account.data.as_slice();
+    let proposal_state =
+        HubProposal::try_deserialize(&mut proposal_data).expect("proposal deserialize");
+    assert_eq!(proposal_state.status, ProposalStatus::Executed);
+    assert_eq!(proposal_state.approvals, vec![members[0], members[1]]);
+
+    let course_list_account = store_ref
+        .get(&course_list_pda)
+        .expect("course list account");
+    let mut course_list_data: &[u8] = course_list_account.data.as_slice();
+    let course_list_state =
+        CourseList::try_deserialize(&mut course_list_data).expect("course list deserialize");
+    assert_eq!(course_list_state.index, course_list_index);
+    assert_eq!(course_list_state.courses, vec![course_pda]);
+
+    let provider_account = store_ref.get(&provider_pda).expect("provider account");
+    let mut provider_data: &[u8] = provider_account.data.as_slice();
+    let provider_state =
+        crate::state::Provider::try_deserialize(&mut provider_data).expect("provider deserialize");
+    assert!(!provider_state.suspended);
+    assert_eq!(provider_state.suspension_count, 1);
+}
+
+#[test]
+fn flow_multiple_hubs_side_by_side() {
+    use crate::state::{Hub, Provider};
+
+    let now: i64 = 1_700_000_000;
+    let country_authority = Pubkey::new_unique();
+    let consortium_authority = Pubkey::new_unique();
+    let provider_authority = Pubkey::new_unique();
+
+    let country_hub = hub_pda_for("country");
+    let consortium_hub = hub_pda_for("consortium");
+    let empty_id_hub = hub_pda_for("");
+    let provider_pda_in = |hub_pda: &Pubkey| {
+        Pubkey::find_program_address(
+            &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
+            &PROGRAM_ID,
+        )
+        .0
+    };
+    let country_provider = provider_pda_in(&country_hub);
+    let consortium_provider = provider_pda_in(&consortium_hub);
+    let (country_course, _country_course_bump) = Pubkey::find_program_address(
+        &[
+            b"course",
+            country_hub.as_ref(),
+            country_provider.as_ref(),
+            &now.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    for signer in [country_authority, consortium_authority, provider_authority] {
+        store.insert(
+            signer,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    for pda in [
+        country_hub,
+        consortium_hub,
+        empty_id_hub,
+        provider_queue_pda(country_hub),
+        provider_queue_pda(consortium_hub),
+        provider_queue_pda(empty_id_hub),
+        course_review_queue_pda(country_hub),
+        course_review_queue_pda(consortium_hub),
+        course_review_queue_pda(empty_id_hub),
+        country_provider,
+        consortium_provider,
+        country_course,
+    ] {
+        store.insert(pda, Account::new(0, 0, &system_program::id()));
+    }
+
+    let ctx = mollusk.with_context(store);
+
+    let empty_id_result = ctx.process_instruction(&ix_initialize_named_hub(
+        "",
+        empty_id_hub,
+        country_authority,
+    ));
+    assert!(empty_id_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_named_hub("country", country_hub, country_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_named_hub("consortium", consortium_hub, consortium_authority),
+            &[Check::success()],
+        ),
+        // The same institution registers independently in both hubs.
+        (
+            &ix_initialize_provider(country_hub, country_provider, provider_authority, "Uni"),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(
+                consortium_hub,
+                consortium_provider,
+                provider_authority,
+                "Uni",
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_add_accepted_provider(
+                country_hub,
+                country_authority,
+                country_provider,
+                provider_authority,
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_add_accepted_provider(
+                consortium_hub,
+                consortium_authority,
+                consortium_provider,
+                provider_authority,
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_create_course(
+                country_hub,
+                country_provider,
+                provider_authority,
+                country_course,
+                now,
+                "Course 101",
+            ),
+            &[Check::success()],
+        ),
+    ]);
+
+    finalize_course_modules(
+        &ctx,
+        country_hub,
+        country_provider,
+        provider_authority,
+        country_course,
+        now,
+    );
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_submit_course_for_review(
+            country_hub,
+            country_provider,
+            provider_authority,
+            country_course,
+        ),
+        &[Check::success()],
+    )]);
+
+    // One hub's authority cannot act on another hub, and courses stay in the hub they were created under.
+    let foreign_authority_result = ctx.process_instruction(&ix_add_accepted_course(
+        country_hub,
+        consortium_authority,
+        country_course,
+    ));
+    assert!(foreign_authority_result.program_result.is_err());
+    let foreign_course_result = ctx.process_instruction(&ix_add_accepted_course(
+        consortium_hub,
+        consortium_authority,
+        country_course,
+    ));
+    assert!(foreign_course_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_add_accepted_course(country_hub, country_authority, country_course),
+        &[Check::success()],
+    )]);
+
+    let store_ref = ctx.account_store.borrow();
+    let read_hub = |hub_pda: &Pubkey| {
+        let hub_account = store_ref.get(hub_pda).expect("hub account");
+        let mut hub_data: &[u8] = hub_account.data.as_slice();
+        Hub::try_deserialize(&mut hub_data).expect("hub deserialize")
+    };
+
+    let country_state = read_hub(&country_hub);
+    assert_eq!(country_state.hub_id, "country");
+    assert_eq!(country_state.authority, country_authority);
+    assert_eq!(country_state.accepted_courses, vec![country_course]);
+
+    let consortium_state = read_hub(&consortium_hub);
+    assert_eq!(consortium_state.hub_id, "consortium");
+    assert_eq!(consortium_state.authority, consortium_authority);
+    assert!(consortium_state.accepted_courses.is_empty());
+
+    let provider_account = store_ref
+        .get(&consortium_provider)
+        .expect("provider account");
+    let mut provider_data: &[u8] = provider_account.data.as_slice();
+    let provider_state =
+        Provider::try_deserialize(&mut provider_data).expect("provider deserialize");
+    assert_eq!(provider_state.wallet, provider_authority);
+}
+
+#[test]
+fn flow_course_status_state_machine() {
+    use crate::state::{Course, Hub};
+    use crate::types::CourseStatus;
+
+    let now: i64 = 1_700_000_000;
+
+    let hub_authority = Pubkey::new_unique();
+    let provider_authority = Pubkey::new_unique();
+    let hub_pda = hub_pda_for(HUB_ID);
+    let (provider_pda, _provider_bump) = Pubkey::find_program_address(
+        &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
+        &PROGRAM_ID,
+    );
+    let (course_pda, _course_bump) = Pubkey::find_program_address(
+        &[
+            b"course",
+            hub_pda.as_ref(),
+            provider_pda.as_ref(),
+            &now.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    for signer in [hub_authority, provider_authority] {
+        store.insert(
+            signer,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    for pda in [
+        hub_pda,
+        provider_queue_pda(hub_pda),
+        course_review_queue_pda(hub_pda),
+        provider_pda,
+        course_pda,
+    ] {
+        store.insert(pda, Account::new(0, 0, &system_program::id()));
+    }
+    let ctx = mollusk.with_context(store);
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(hub_pda, provider_pda, provider_authority, "Provider A"),
+            &[Check::success()],
+        ),
+        (
+            &ix_add_accepted_provider(hub_pda, hub_authority, provider_pda, provider_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_create_course(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                course_pda,
+                now,
+                "Course 101",
+            ),
+            &[Check::success()],
+        ),
+    ]);
+
+    let read_course = || {
+        let store_ref = ctx.account_store.borrow();
+        let course_account = store_ref.get(&course_pda).expect("course account");
+        let mut course_data: &[u8] = course_account.data.as_slice();
+        Course::try_deserialize(&mut course_data).expect("course deserialize")
+    };
+    let set_status = |status: CourseStatus| {
+        ix_update_course_status(
+            hub_pda,
+            provider_pda,
+            provider_authority,
+            course_pda,
+            status,
+        )
+    };
+
+    // A provider cannot accept its own course, and the hub cannot accept a draft.
+    let self_accept_result = ctx.process_instruction(&set_status(CourseStatus::Accepted));
+    assert!(self_accept_result.program_result.is_err());
+    let draft_accept_result =
+        ctx.process_instruction(&ix_add_accepted_course(hub_pda, hub_authority, course_pda));
+    assert!(draft_accept_result.program_result.is_err());
+    assert_eq!(read_course().status, CourseStatus::Draft);
+
+    finalize_course_modules(
+        &ctx,
+        hub_pda,
+        provider_pda,
+        provider_authority,
+        course_pda,
+        now,
+    );
+    ctx.process_and_validate_instruction_chain(&[(
+        &set_status(CourseStatus::InReview),
+        &[Check::success()],
+    )]);
+    assert_eq!(read_course().status, CourseStatus::InReview);
+
+    // Only the hub moves a course out of review.
+    let in_review_accept_result = ctx.process_instruction(&set_status(CourseStatus::Accepted));
+    assert!(in_review_accept_result.program_result.is_err());
+    let in_review_archive_result = ctx.process_instruction(&set_status(CourseStatus::Archived));
+    assert!(in_review_archive_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_add_accepted_course(hub_pda, hub_authority, course_pda),
+            &[Check::success()],
+        ),
+        (&set_status(CourseStatus::Archived), &[Check::success()]),
+    ]);
+    assert_eq!(read_course().status, CourseStatus::Archived);
+
+    // Archived is terminal; the hub can still drop its reference.
+    let unarchive_result = ctx.process_instruction(&set_status(CourseStatus::Draft));
+    assert!(unarchive_result.program_result.is_err());
+    let reaccept_result =
+        ctx.process_instruction(&ix_add_accepted_course(hub_pda, hub_authority, course_pda));
+    assert!(reaccept_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_remove_accepted_course(hub_pda, hub_authority, course_pda),
+        &[Check::success()],
+    )]);
+    assert_eq!(read_course().status, CourseStatus::Archived);
+
+    let store_ref = ctx.account_store.borrow();
+    let hub_account = store_ref.get(&hub_pda).expect("hub account");
+    let mut hub_data: &[u8] = hub_account.data.as_slice();
+    let hub_state = Hub::try_deserialize(&mut hub_data).expect("hub deserialize");
+    assert!(!hub_state.accepted_courses.contains(&course_pda));
+}
+
+#[test]
+fn flow_course_review_queue_and_rejection() {
+    use crate::state::{Course, CourseReviewQueue};
+    use crate::types::CourseStatus;
+
+    let now: i64 = 1_700_000_000;
+
+    let hub_authority = Pubkey::new_unique();
+    let provider_authority = Pubkey::new_unique();
+    let hub_pda = hub_pda_for(HUB_ID);
+    let (provider_pda, _provider_bump) = Pubkey::find_program_address(
+        &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
+        &PROGRAM_ID,
+    );
+    let course_pda_at = |created: i64| {
+        Pubkey::find_program_address(
+            &[
+                b"course",
+                hub_pda.as_ref(),
+                provider_pda.as_ref(),
+                &created.to_le_bytes(),
+            ],
+            &PROGRAM_ID,
+        )
+        .0
+    };
+    let rejected_course = course_pda_at(now);
+    let accepted_course = course_pda_at(now + 1);
+
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    for signer in [hub_authority, provider_authority] {
+        store.insert(
+            signer,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    for pda in [
+        hub_pda,
+        provider_queue_pda(hub_pda),
+        course_review_queue_pda(hub_pda),
+        provider_pda,
+        rejected_course,
+        accepted_course,
+    ] {
+        store.insert(pda, Account::new(0, 0, &system_program::id()));
+    }
+    let ctx = mollusk.with_context(store);
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(hub_pda, provider_pda, provider_authority, "Provider A"),
+            &[Check::success()],
+        ),
+        (
+            &ix_add_accepted_provider(hub_pda, hub_authority, provider_pda, provider_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_create_course(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                rejected_course,
+                now,
+                "Course 101",
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_create_course(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                accepted_course,
+                now + 1,
+                "Course 102",
+            ),
+            &[Check::success()],
+        ),
+    ]);
+
+    finalize_course_modules(
+        &ctx,
+        hub_pda,
+        provider_pda,
+        provider_authority,
+        rejected_course,
+        now,
+    );
+    finalize_course_modules(
+        &ctx,
+        hub_pda,
+        provider_pda,
+        provider_authority,
+        accepted_course,
+        now,
+    );
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_submit_course_for_review(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                rejected_course,
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_submit_course_for_review(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                accepted_course,
+            ),
+            &[Check::success()],
+        ),
+    ]);
+
+    let read_queue = || {
+        let store_ref = ctx.account_store.borrow();
+        let queue_account = store_ref
+            .get(&course_review_queue_pda(hub_pda))
+            .expect("course review queue");
+        let mut queue_data: &[u8] = queue_account.data.as_slice();
+        let queue = CourseReviewQueue::try_deserialize(&mut queue_data).expect("queue deserialize");
+        queue.courses.iter().map(|c| c.course).collect::<Vec<_>>()
+    };
+    let read_course = |course_pda: Pubkey| {
+        let store_ref = ctx.account_store.borrow();
+        let course_account = store_ref.get(&course_pda).expect("course account");
+        let mut course_data: &[u8] = course_account.data.as_slice();
+        Course::try_deserialize(&mut course_data).expect("course deserialize")
+    };
+    assert_eq!(read_queue(), vec![rejected_course, accepted_course]);
+
+    // Rejection needs the hub authority and a reason.
+    let provider_reject_result = ctx.process_instruction(&ix_reject_course(
+        hub_pda,
+        provider_authority,
+        rejected_course,
+        "Missing syllabus",
+    ));
+    assert!(provider_reject_result.program_result.is_err());
+    let empty_reason_result = ctx.process_instruction(&ix_reject_course(
+        hub_pda,
+        hub_authority,
+        rejected_course,
+        "",
+    ));
+    assert!(empty_reason_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_reject_course(hub_pda, hub_authority, rejected_course, "Missing syllabus"),
+            &[Check::success()],
+        ),
+        (
+            &ix_add_accepted_course(hub_pda, hub_authority, accepted_course),
+            &[Check::success()],
+        ),
+    ]);
+
+    let rejected = read_course(rejected_course);
+    assert_eq!(rejected.status, CourseStatus::Draft);
+    assert_eq!(
+        rejected.rejection_reason.as_deref(),
+        Some("Missing syllabus")
+    );
+    assert_eq!(read_course(accepted_course).status, CourseStatus::Accepted);
+    assert!(read_queue().is_empty());
+
+    // A draft cannot be rejected again; resubmitting and removal both put courses back in the queue.
+    let draft_reject_result = ctx.process_instruction(&ix_reject_course(
+        hub_pda,
+        hub_authority,
+        rejected_course,
+        "Still missing",
+    ));
+    assert!(draft_reject_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_submit_course_for_review(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                rejected_course,
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_remove_accepted_course(hub_pda, hub_authority, accepted_course),
+            &[Check::success()],
+        ),
+    ]);
+
+    assert_eq!(read_queue(), vec![rejected_course, accepted_course]);
+    assert_eq!(read_course(rejected_course).rejection_reason, None);
+    assert_eq!(read_course(accepted_course).status, CourseStatus::InReview);
+
+    // Closing a course in review frees its queue slot; the hub can also drop entries by key.
+    let ix_close_course = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("close_course"),
+        vec![
+            AccountMeta::new(rejected_course, false),
+            AccountMeta::new(provider_pda, false),
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
+            AccountMeta::new(provider_authority, true),
+        ],
+    );
+    ctx.process_and_validate_instruction_chain(&[(&ix_close_course, &[Check::success()])]);
+    assert_eq!(read_queue(), vec![accepted_course]);
+
+    #[derive(AnchorSerialize)]
+    struct PruneCourseReviewQueueArgs {
+        courses: Vec<Pubkey>,
+    }
+    let ix_prune = |signer: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "prune_course_review_queue",
+                &PruneCourseReviewQueueArgs {
+                    courses: vec![accepted_course],
+                },
+            ),
+            vec![
+                AccountMeta::new_readonly(hub_pda, false),
+                AccountMeta::new_readonly(signer, true),
+                AccountMeta::new(course_review_queue_pda(hub_pda), false),
+            ],
+        )
+    };
+    let provider_prune_result = ctx.process_instruction(&ix_prune(provider_authority));
+    assert!(provider_prune_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(&ix_prune(hub_authority), &[Check::success()])]);
+    assert!(read_queue().is_empty());
+}
+
+#[test]
+fn flow_course_module_weights_and_ownership() {
+    use crate::state::Course;
+    use crate::types::CourseStatus;
+
+    let now: i64 = 1_700_000_000;
+
+    let hub_authority = Pubkey::new_unique();
+    let provider_authority = Pubkey::new_unique();
+    let hub_pda = hub_pda_for(HUB_ID);
+    let (provider_pda, _provider_bump) = Pubkey::find_program_address(
+        &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
+        &PROGRAM_ID,
+    );
+    let course_pda_at = |created: i64| {
+        Pubkey::find_program_address(
+            &[
+                b"course",
+                hub_pda.as_ref(),
+                provider_pda.as_ref(),
+                &created.to_le_bytes(),
+            ],
+            &PROGRAM_ID,
+        )
+        .0
+    };
+    let course_pda = course_pda_at(now);
+    let other_course_pda = course_pda_at(now + 1);
+    let lecture_pda = course_resource_pda(course_pda, now);
+    let project_pda = course_resource_pda(course_pda, now + 1);
+    let foreign_resource_pda = course_resource_pda(other_course_pda, now);
+
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    for signer in [hub_authority, provider_authority] {
+        store.insert(
+            signer,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    for pda in [
+        hub_pda,
+        provider_queue_pda(hub_pda),
+        course_review_queue_pda(hub_pda),
+        provider_pda,
+        course_pda,
+        other_course_pda,
+        lecture_pda,
+        project_pda,
+        foreign_resource_pda,
+    ] {
+        store.insert(pda, Account::new(0, 0, &system_program::id()));
+    }
+    let ctx = mollusk.with_context(store);
+
+    let add_resource = |course: Pubkey, resource: Pubkey, created: i64| {
+        ix_add_course_resource(
+            hub_pda,
+            provider_pda,
+            provider_authority,
+            course,
+            resource,
+            created,
+        )
+    };
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(hub_pda, provider_pda, provider_authority, "Provider A"),
+            &[Check::success()],
+        ),
+        (
+            &ix_add_accepted_provider(hub_pda, hub_authority, provider_pda, provider_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_create_course(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                course_pda,
+                now,
+                "Course 101",
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_create_course(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                other_course_pda,
+                now + 1,
+                "Course 102",
+            ),
+            &[Check::success()],
+        ),
+        (
+            &add_resource(course_pda, lecture_pda, now),
+            &[Check::success()],
+        ),
+        (
+            &add_resource(course_pda, project_pda, now + 1),
+            &[Check::success()],
+        ),
+        (
+            &add_resource(other_course_pda, foreign_resource_pda, now),
+            &[Check::success()],
+        ),
+    ]);
+
+    let module = |ix_name: &str, resource: Pubkey, percentage: u8| {
+        ix_course_module(
+            ix_name,
+            hub_pda,
+            provider_pda,
+            provider_authority,
+            course_pda,
+            resource,
+            percentage,
+        )
+    };
+    let finalize = ix_finalize_modules(hub_pda, provider_pda, provider_authority, course_pda);
+    let submit = ix_submit_course_for_review(hub_pda, provider_pda, provider_authority, course_pda);
+    let read_course = || {
+        let store_ref = ctx.account_store.borrow();
+        let course_account = store_ref.get(&course_pda).expect("course account");
+        let mut course_data: &[u8] = course_account.data.as_slice();
+        Course::try_deserialize(&mut course_data).expect("course deserialize")
+    };
+
+    // Modules must point at this course's resources, once each.
+    let foreign_result =
+        ctx.process_instruction(&module("add_course_module", foreign_resource_pda, 50));
+    assert!(foreign_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &module("add_course_module", lecture_pda, 60),
+        &[Check::success()],
+    )]);
+    let duplicate_result = ctx.process_instruction(&module("add_course_module", lecture_pda, 10));
+    assert!(duplicate_result.program_result.is_err());
+
+    // Weights can never exceed 100% in total.
+    let overweight_add_result =
+        ctx.process_instruction(&module("add_course_module", project_pda, 50));
+    assert!(overweight_add_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &module("add_course_module", project_pda, 30),
+        &[Check::success()],
+    )]);
+    let overweight_update_result =
+        ctx.process_instruction(&module("update_course_module", lecture_pda, 80));
+    assert!(overweight_update_result.program_result.is_err());
+
+    // 90% is not enough to finalize, and the course cannot leave Draft before finalizing.
+    let partial_finalize_result = ctx.process_instruction(&finalize);
+    assert!(partial_finalize_result.program_result.is_err());
+    let unfinalized_submit_result = ctx.process_instruction(&submit);
+    assert!(unfinalized_submit_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &module("update_course_module", lecture_pda, 70),
+            &[Check::success()],
+        ),
+        (&finalize, &[Check::success()]),
+    ]);
+    let finalized = read_course();
+    assert!(finalized.modules_finalized);
+    assert_eq!(finalized.module_weight_total(), 100);
+
+    // Any module change clears the finalized flag again.
+    ctx.process_and_validate_instruction_chain(&[(
+        &module("update_course_module", project_pda, 30),
+        &[Check::success()],
+    )]);
+    assert!(!read_course().modules_finalized);
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&finalize, &[Check::success()]),
+        (&submit, &[Check::success()]),
+    ]);
+    assert_eq!(read_course().status, CourseStatus::InReview);
+
+    // Modules are locked once the course is out of Draft.
+    let locked_result = ctx.process_instruction(&module("update_course_module", project_pda, 20));
+    assert!(locked_result.program_result.is_err());
+}
+
+#[test]
+fn flow_course_workload_tracks_modules() {
+    use crate::state::Course;
+
+    let now: i64 = 1_700_000_000;
+
+    let hub_authority = Pubkey::new_unique();
+    let provider_authority = Pubkey::new_unique();
+    let hub_pda = hub_pda_for(HUB_ID);
+    let (provider_pda, _provider_bump) = Pubkey::find_program_address(
+        &[b"provider", hub_pda.as_ref(), provider_authority.as_ref()],
+        &PROGRAM_ID,
+    );
+    let (course_pda, _course_bump) = Pubkey::find_program_address(
+        &[
+            b"course",
+            hub_pda.as_ref(),
+            provider_pda.as_ref(),
+            &now.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+    let lecture_pda = course_resource_pda(course_pda, now);
+    let project_pda = course_resource_pda(course_pda, now + 1);
+
+    let mollusk = mollusk_with_program(now);
+    let mut store: HashMap<Pubkey, Account> = HashMap::new();
+    for signer in [hub_authority, provider_authority] {
+        store.insert(
+            signer,
+            Account::new(10_000_000_000, 0, &system_program::id()),
+        );
+    }
+    for pda in [
+        hub_pda,
+        provider_queue_pda(hub_pda),
+        course_review_queue_pda(hub_pda),
+        provider_pda,
+        course_pda,
+        lecture_pda,
+        project_pda,
+    ] {
+        store.insert(pda, Account::new(0, 0, &system_program::id()));
+    }
+    let ctx = mollusk.with_context(store);
+
+    let add_resource = |resource: Pubkey, created: i64| {
+        ix_add_course_resource(
+            hub_pda,
+            provider_pda,
+            provider_authority,
+            course_pda,
+            resource,
+            created,
+        )
+    };
+    let module = |ix_name: &str, resource: Pubkey, percentage: u8| {
+        ix_course_module(
+            ix_name,
+            hub_pda,
+            provider_pda,
+            provider_authority,
+            course_pda,
+            resource,
+            percentage,
+        )
+    };
+
+    #[derive(AnchorSerialize)]
+    struct UpdateResourceDataArgs {
+        name: Option<String>,
+        workload: Option<u32>,
+        tags: Option<Vec<String>>,
+    }
+    let set_workload = |resource: Pubkey, workload: u32| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data(
+                "update_resource_data",
+                &UpdateResourceDataArgs {
+                    name: None,
+                    workload: Some(workload),
+                    tags: None,
+                },
+            ),
+            vec![
+                AccountMeta::new(resource, false),
+                AccountMeta::new(course_pda, false),
+                AccountMeta::new_readonly(provider_pda, false),
+                AccountMeta::new_readonly(hub_pda, false),
+                AccountMeta::new(provider_authority, true),
+            ],
+        )
+    };
+    #[derive(AnchorSerialize)]
+    struct RemoveCourseModuleArgs {
+        resource: Pubkey,
+    }
+    let ix_remove_lecture = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "remove_course_module",
+            &RemoveCourseModuleArgs {
+                resource: lecture_pda,
+            },
+        ),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(provider_authority, true),
+        ],
+    );
+    let finalize = ix_finalize_modules(hub_pda, provider_pda, provider_authority, course_pda);
+    let read_course = || {
+        let store_ref = ctx.account_store.borrow();
+        let course_account = store_ref.get(&course_pda).expect("course account");
+        let mut course_data: &[u8] = course_account.data.as_slice();
+        Course::try_deserialize(&mut course_data).expect("course deserialize")
+    };
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_initialize_hub(hub_pda, hub_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_initialize_provider(hub_pda, provider_pda, provider_authority, "Provider A"),
+            &[Check::success()],
+        ),
+        (
+            &ix_add_accepted_provider(hub_pda, hub_authority, provider_pda, provider_authority),
+            &[Check::success()],
+        ),
+        (
+            &ix_create_course(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                course_pda,
+                now,
+                "Course 101",
+            ),
+            &[Check::success()],
+        ),
+        (&add_resource(lecture_pda, now), &[Check::success()]),
+        (&add_resource(project_pda, now + 1), &[Check::success()]),
+        (
+            &module("add_course_module", lecture_pda, 50),
+            &[Check::success()],
+        ),
+        (
+            &module("add_course_module", project_pda, 50),
+            &[Check::success()],
+        ),
+    ]);
+    let course = read_course();
+    assert_eq!(course.workload, 20);
+    assert_eq!(course.module_workload_total(), 20);
+
+    // Changing a module resource's workload updates the course right away.
+    ctx.process_and_validate_instruction_chain(&[(
+        &set_workload(project_pda, 5),
+        &[Check::success()],
+    )]);
+    assert_eq!(read_course().workload, 15);
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&ix_remove_lecture, &[Check::success()]),
+        (
+            &module("update_course_module", project_pda, 100),
+            &[Check::success()],
+        ),
+    ]);
+    assert_eq!(read_course().workload, 5);
+
+    // Modules must cover the course's required workload (10) before finalizing.
+    let short_workload_result = ctx.process_instruction(&finalize);
+    assert!(short_workload_result.program_result.is_err());
+
+    ctx.process_and_validate_instruction_chain(&[
+        (&set_workload(project_pda, 25), &[Check::success()]),
+        (&finalize, &[Check::success()]),
+    ]);
+    let finalized = read_course();
+    assert_eq!(finalized.workload, 25);
+    assert!(finalized.modules_finalized);
+
+    // Once the course leaves Draft its modules are locked, so module workloads are too.
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_submit_course_for_review(hub_pda, provider_pda, provider_authority, course_pda),
+        &[Check::success()],
+    )]);
+    let locked_workload_result = ctx.process_instruction(&set_workload(project_pda, 40));
+    assert!(locked_workload_result.program_result.is_err());
+    assert_eq!(read_course().workload, 25);
+
+    // Other edits of a live module resource still go through, as does resending its workload.
+    let ix_rename_project = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "update_resource_data",
+            &UpdateResourceDataArgs {
+                name: Some("Capstone project".to_string()),
+                workload: Some(25),
+                tags: Some(vec!["capstone".to_string()]),
+            },
+        ),
+        vec![
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(provider_authority, true),
+        ],
+    );
+    ctx.process_and_validate_instruction_chain(&[(&ix_rename_project, &[Check::success()])]);
+
+    // A live course's module resource cannot be closed; a resource that is not a module can.
+    let ix_close_resource = |resource: Pubkey| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &anchor_ix_data_no_args("close_resource"),
+            vec![
+                AccountMeta::new(resource, false),
+                AccountMeta::new(course_pda, false),
+                AccountMeta::new(provider_authority, true),
+            ],
+        )
+    };
+    let close_module_result = ctx.process_instruction(&ix_close_resource(project_pda));
+    assert!(close_module_result.program_result.is_err());
+    ctx.process_and_validate_instruction_chain(&[(
+        &ix_close_resource(lecture_pda),
+        &[Check::success()],
+    )]);
+    assert_eq!(read_course().modules.len(), 1);
+}
+
+fn ix_initialize_hub(hub_pda: Pubkey, hub_authority: Pubkey) -> Instruction {
+    ix_initialize_named_hub(HUB_ID, hub_pda, hub_authority)
+}
+
+fn ix_initialize_named_hub(hub_id: &str, hub_pda: Pubkey, hub_authority: Pubkey) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct InitializeHubArgs {
+        hub_id: String,
+    }
+
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "initialize_hub",
+            &InitializeHubArgs {
+                hub_id: hub_id.to_string(),
+            },
+        ),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(provider_queue_pda(hub_pda), false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
+            AccountMeta::new(hub_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+fn ix_initialize_provider(
+    hub_pda: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+    name: &str,
+) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct InitializeProviderArgs {
+        name: String,
+        description: String,
+        website: String,
+        email: String,
+        provider_type: String,
+    }
+
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "initialize_provider",
+            &InitializeProviderArgs {
+                name: name.to_string(),
+                description: "desc".to_string(),
+                website: "https://example.com".to_string(),
+                email: "test@example.com".to_string(),
+                provider_type: "education".to_string(),
+            },
+        ),
+        vec![
+            AccountMeta::new(provider_pda, false),
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(provider_queue_pda(hub_pda), false),
+            AccountMeta::new(provider_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+fn ix_add_accepted_provider(
+    hub_pda: Pubkey,
+    hub_authority: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("add_accepted_provider"),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(hub_authority, true),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(provider_authority, false),
+            AccountMeta::new(provider_queue_pda(hub_pda), false),
+        ],
+    )
+}
+
+fn ix_remove_accepted_provider(
+    hub_pda: Pubkey,
+    hub_authority: Pubkey,
+    provider_authority: Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("remove_accepted_provider"),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(hub_authority, true),
+            AccountMeta::new_readonly(provider_authority, false),
+        ],
+    )
+}
+
+fn ix_suspend_provider(
+    hub_pda: Pubkey,
+    hub_authority: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct SuspendProviderArgs {
+        note: Option<String>,
+    }
+
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "suspend_provider",
+            &SuspendProviderArgs {
+                note: Some("Under review".to_string()),
+            },
+        ),
+        provider_standing_accounts(hub_pda, hub_authority, provider_pda, provider_authority),
+    )
+}
+
+fn ix_unsuspend_provider(
+    hub_pda: Pubkey,
+    hub_authority: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("unsuspend_provider"),
+        provider_standing_accounts(hub_pda, hub_authority, provider_pda, provider_authority),
+    )
+}
+
+fn ix_update_provider_reputation(
+    hub_pda: Pubkey,
+    hub_authority: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+    reputation_score: u64,
+) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct UpdateProviderReputationArgs {
+        reputation_score: u64,
+        note: Option<String>,
+    }
+
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "update_provider_reputation",
+            &UpdateProviderReputationArgs {
+                reputation_score,
+                note: None,
+            },
+        ),
+        provider_standing_accounts(hub_pda, hub_authority, provider_pda, provider_authority),
+    )
+}
+
+fn provider_standing_accounts(
+    hub_pda: Pubkey,
+    hub_authority: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(hub_pda, false),
+        AccountMeta::new_readonly(hub_authority, true),
+        AccountMeta::new(provider_pda, false),
+        AccountMeta::new_readonly(provider_authority, false),
+    ]
+}
+
+fn ix_accept_pending_provider(
+    hub_pda: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("accept_pending_provider"),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(provider_queue_pda(hub_pda), false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(provider_authority, false),
+        ],
+    )
+}
+
+fn ix_reject_pending_provider(
+    hub_pda: Pubkey,
+    hub_authority: Pubkey,
+    provider_authority: Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("reject_pending_provider"),
+        vec![
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new_readonly(hub_authority, true),
+            AccountMeta::new(provider_queue_pda(hub_pda), false),
+            AccountMeta::new_readonly(provider_authority, false),
+        ],
+    )
+}
+
+fn ix_update_hub_config(
+    hub_pda: Pubkey,
+    hub_authority: Pubkey,
+    require_provider_approval: bool,
+    min_reputation_score: u64,
+) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct HubConfigArgs {
+        require_provider_approval: bool,
+        min_reputation_score: u64,
+    }
+
+    #[derive(AnchorSerialize)]
+    struct UpdateHubConfigArgs {
+        config: HubConfigArgs,
+    }
+
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "update_hub_config",
+            &UpdateHubConfigArgs {
+                config: HubConfigArgs {
+                    require_provider_approval,
+                    min_reputation_score,
+                },
+            },
+        ),
+        vec![
+            AccountMeta::new(hub_pda, false),
+            AccountMeta::new(hub_authority, true),
+        ],
+    )
+}
 
 fn ix_create_course(
     hub_pda: Pubkey,
@@ -553,6 +2682,196 @@ fn ix_create_course(
     )
 }
 
+fn ix_update_course_status(
+    hub_pda: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+    course_pda: Pubkey,
+    status: crate::types::CourseStatus,
+) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct UpdateCourseStatusArgs {
+        status: crate::types::CourseStatus,
+    }
+
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data("update_course_status", &UpdateCourseStatusArgs { status }),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
+            AccountMeta::new(provider_authority, true),
+        ],
+    )
+}
+
+fn ix_add_course_resource(
+    hub_pda: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+    course_pda: Pubkey,
+    resource_pda: Pubkey,
+    resource_created: i64,
+) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct AddResourceArgs {
+        creation_timestamp: i64,
+        kind: crate::types::ResourceKind,
+        name: String,
+        external_id: Option<String>,
+        workload: Option<u32>,
+        tags: Vec<String>,
+        nostr_d_tag: Option<String>,
+        nostr_author_pubkey: Option<[u8; 32]>,
+    }
+
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "add_resource",
+            &AddResourceArgs {
+                creation_timestamp: resource_created,
+                kind: crate::types::ResourceKind::Assignment,
+                name: "Module".to_string(),
+                external_id: None,
+                workload: Some(10),
+                tags: vec![],
+                nostr_d_tag: None,
+                nostr_author_pubkey: None,
+            },
+        ),
+        vec![
+            AccountMeta::new(resource_pda, false),
+            AccountMeta::new_readonly(course_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(provider_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+fn ix_course_module(
+    ix_name: &str,
+    hub_pda: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+    course_pda: Pubkey,
+    resource_pda: Pubkey,
+    percentage: u8,
+) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct CourseModuleArgs {
+        percentage: u8,
+    }
+
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(ix_name, &CourseModuleArgs { percentage }),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(resource_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new(provider_authority, true),
+        ],
+    )
+}
+
+fn ix_finalize_modules(
+    hub_pda: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+    course_pda: Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data_no_args("finalize_modules"),
+        vec![
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new_readonly(provider_pda, false),
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new_readonly(provider_authority, true),
+        ],
+    )
+}
+
+fn course_resource_pda(course_pda: Pubkey, resource_created: i64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"resource",
+            course_pda.as_ref(),
+            &resource_created.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+    .0
+}
+
+/// Give a draft course a single full-weight module and finalize it so it can be submitted for review.
+fn finalize_course_modules(
+    ctx: &mollusk_svm::MolluskContext<HashMap<Pubkey, Account>>,
+    hub_pda: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+    course_pda: Pubkey,
+    resource_created: i64,
+) -> Pubkey {
+    let resource_pda = course_resource_pda(course_pda, resource_created);
+    ctx.account_store
+        .borrow_mut()
+        .insert(resource_pda, Account::new(0, 0, &system_program::id()));
+
+    ctx.process_and_validate_instruction_chain(&[
+        (
+            &ix_add_course_resource(
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                course_pda,
+                resource_pda,
+                resource_created,
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_course_module(
+                "add_course_module",
+                hub_pda,
+                provider_pda,
+                provider_authority,
+                course_pda,
+                resource_pda,
+                100,
+            ),
+            &[Check::success()],
+        ),
+        (
+            &ix_finalize_modules(hub_pda, provider_pda, provider_authority, course_pda),
+            &[Check::success()],
+        ),
+    ]);
+
+    resource_pda
+}
+
+fn ix_submit_course_for_review(
+    hub_pda: Pubkey,
+    provider_pda: Pubkey,
+    provider_authority: Pubkey,
+    course_pda: Pubkey,
+) -> Instruction {
+    ix_update_course_status(
+        hub_pda,
+        provider_pda,
+        provider_authority,
+        course_pda,
+        crate::types::CourseStatus::InReview,
+    )
+}
+
 fn ix_add_accepted_course(
     hub_pda: Pubkey,
     hub_authority: Pubkey,
@@ -565,6 +2884,7 @@ fn ix_add_accepted_course(
             AccountMeta::new(hub_pda, false),
             AccountMeta::new(hub_authority, true),
             AccountMeta::new(course_pda, false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
         ],
     )
 }
@@ -581,6 +2901,35 @@ fn ix_remove_accepted_course(
             AccountMeta::new(hub_pda, false),
             AccountMeta::new(hub_authority, true),
             AccountMeta::new(course_pda, false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
+        ],
+    )
+}
+
+fn ix_reject_course(
+    hub_pda: Pubkey,
+    hub_authority: Pubkey,
+    course_pda: Pubkey,
+    reason: &str,
+) -> Instruction {
+    #[derive(AnchorSerialize)]
+    struct RejectCourseArgs {
+        reason: String,
+    }
+
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &anchor_ix_data(
+            "reject_course",
+            &RejectCourseArgs {
+                reason: reason.to_string(),
+            },
+        ),
+        vec![
+            AccountMeta::new_readonly(hub_pda, false),
+            AccountMeta::new_readonly(hub_authority, true),
+            AccountMeta::new(course_pda, false),
+            AccountMeta::new(course_review_queue_pda(hub_pda), false),
         ],
     )
 }
@@ -670,6 +3019,18 @@ fn ix_remove_course_from_list(
     )
 }
 
+fn hub_pda_for(hub_id: &str) -> Pubkey {
+    Pubkey::find_program_address(&[b"hub", hub_id.as_bytes()], &PROGRAM_ID).0
+}
+
+fn provider_queue_pda(hub_pda: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"provider-queue", hub_pda.as_ref()], &PROGRAM_ID).0
+}
+
+fn course_review_queue_pda(hub_pda: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"course-review-queue", hub_pda.as_ref()], &PROGRAM_ID).0
+}
+
 fn mollusk_with_program(now: i64) -> Mollusk {
     let elf = load_fair_credit_elf();
     let mut mollusk = Mollusk::default();
@@ -9,10 +9,16 @@ pub mod types;
 use handlers::activity::*;
 use handlers::course::*;
 use handlers::credential::*;
+use handlers::degree::*;
+use handlers::enrollment::*;
+use handlers::governance::*;
 use handlers::hub::*;
+use handlers::progress::*;
 use handlers::provider::*;
+use handlers::recognition::*;
 use handlers::resource::*;
-use state::HubConfig;
+use handlers::rubric::*;
+use state::{HubAction, HubConfig};
 
 // Must match target/deploy/fair_credit-keypair.json (run: solana address -k anchor/target/deploy/fair_credit-keypair.json)
 declare_id!("95asCfd7nbJN5i6REuiuLHj7Wb6DqqAKrhG1tRJ7Dthx");
@@ -66,12 +72,31 @@ pub mod fair_credit {
         handlers::credential::approve_credential(ctx)
     }
 
+    pub fn renew_credential(ctx: Context<RenewCredential>) -> Result<()> {
+        handlers::credential::renew_credential(ctx)
+    }
+
+    pub fn verify_credential(ctx: Context<VerifyCredential>, anonymous: bool) -> Result<()> {
+        handlers::credential::verify_credential(ctx, anonymous)
+    }
+
+    pub fn create_revocation_registry(ctx: Context<CreateRevocationRegistry>) -> Result<()> {
+        handlers::credential::create_revocation_registry(ctx)
+    }
+
+    pub fn revoke_credential(
+        ctx: Context<RevokeCredential>,
+        reason: types::RevocationReason,
+    ) -> Result<()> {
+        handlers::credential::revoke_credential(ctx, reason)
+    }
+
     pub fn mint_credential_nft(ctx: Context<MintCredentialNft>) -> Result<()> {
         handlers::credential::mint_credential_nft(ctx)
     }
 
-    pub fn initialize_hub(ctx: Context<InitializeHub>) -> Result<()> {
-        handlers::hub::initialize_hub(ctx)
+    pub fn initialize_hub(ctx: Context<InitializeHub>, hub_id: String) -> Result<()> {
+        handlers::hub::initialize_hub(ctx, hub_id)
     }
 
     pub fn close_hub(ctx: Context<CloseHub>) -> Result<()> {
@@ -90,10 +115,90 @@ pub mod fair_credit {
         handlers::hub::remove_accepted_provider(ctx)
     }
 
+    pub fn accept_pending_provider(ctx: Context<AcceptPendingProvider>) -> Result<()> {
+        handlers::hub::accept_pending_provider(ctx)
+    }
+
+    pub fn reject_pending_provider(ctx: Context<RejectPendingProvider>) -> Result<()> {
+        handlers::hub::reject_pending_provider(ctx)
+    }
+
+    pub fn prune_provider_queue(
+        ctx: Context<PruneProviderQueue>,
+        wallets: Vec<Pubkey>,
+    ) -> Result<()> {
+        handlers::hub::prune_provider_queue(ctx, wallets)
+    }
+
+    pub fn prune_course_review_queue(
+        ctx: Context<PruneCourseReviewQueue>,
+        courses: Vec<Pubkey>,
+    ) -> Result<()> {
+        handlers::hub::prune_course_review_queue(ctx, courses)
+    }
+
+    pub fn suspend_provider(
+        ctx: Context<ManageProviderStanding>,
+        note: Option<String>,
+    ) -> Result<()> {
+        handlers::hub::suspend_provider(ctx, note)
+    }
+
+    pub fn unsuspend_provider(ctx: Context<ManageProviderStanding>) -> Result<()> {
+        handlers::hub::unsuspend_provider(ctx)
+    }
+
+    pub fn update_provider_reputation(
+        ctx: Context<ManageProviderStanding>,
+        reputation_score: u64,
+        note: Option<String>,
+    ) -> Result<()> {
+        handlers::hub::update_provider_reputation(ctx, reputation_score, note)
+    }
+
     pub fn transfer_hub_authority(ctx: Context<TransferHubAuthority>) -> Result<()> {
         handlers::hub::transfer_hub_authority(ctx)
     }
 
+    pub fn accept_hub_authority(ctx: Context<AcceptHubAuthority>) -> Result<()> {
+        handlers::hub::accept_hub_authority(ctx)
+    }
+
+    pub fn cancel_hub_authority_transfer(ctx: Context<CancelHubAuthorityTransfer>) -> Result<()> {
+        handlers::hub::cancel_hub_authority_transfer(ctx)
+    }
+
+    pub fn initialize_hub_governance(
+        ctx: Context<InitializeHubGovernance>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        handlers::governance::initialize_hub_governance(ctx, members, threshold)
+    }
+
+    pub fn create_hub_proposal(ctx: Context<CreateHubProposal>, action: HubAction) -> Result<()> {
+        handlers::governance::create_hub_proposal(ctx, action)
+    }
+
+    pub fn approve_hub_proposal(ctx: Context<VoteHubProposal>) -> Result<()> {
+        handlers::governance::approve_hub_proposal(ctx)
+    }
+
+    pub fn cancel_hub_proposal(ctx: Context<VoteHubProposal>) -> Result<()> {
+        handlers::governance::cancel_hub_proposal(ctx)
+    }
+
+    pub fn execute_hub_proposal(ctx: Context<ExecuteHubProposal>) -> Result<()> {
+        handlers::governance::execute_hub_proposal(ctx)
+    }
+
+    pub fn execute_course_list_proposal(
+        ctx: Context<ExecuteCourseListProposal>,
+        course_list_index: u16,
+    ) -> Result<()> {
+        handlers::governance::execute_course_list_proposal(ctx, course_list_index)
+    }
+
     pub fn add_accepted_course(ctx: Context<AddAcceptedCourse>) -> Result<()> {
         handlers::hub::add_accepted_course(ctx)
     }
@@ -102,6 +207,10 @@ pub mod fair_credit {
         handlers::hub::remove_accepted_course(ctx)
     }
 
+    pub fn reject_course(ctx: Context<RejectCourse>, reason: String) -> Result<()> {
+        handlers::hub::reject_course(ctx, reason)
+    }
+
     pub fn create_course_list(
         ctx: Context<CreateCourseList>,
         course_list_index: u16,
@@ -128,6 +237,31 @@ pub mod fair_credit {
         handlers::hub::set_course_list_next(ctx, course_list_index)
     }
 
+    pub fn recognize_course(
+        ctx: Context<RecognizeCourse>,
+        workload_factor_bps: Option<u16>,
+    ) -> Result<()> {
+        handlers::recognition::recognize_course(ctx, workload_factor_bps)
+    }
+
+    pub fn recognize_provider(
+        ctx: Context<RecognizeProvider>,
+        workload_factor_bps: Option<u16>,
+    ) -> Result<()> {
+        handlers::recognition::recognize_provider(ctx, workload_factor_bps)
+    }
+
+    pub fn revoke_recognition(ctx: Context<RevokeRecognition>) -> Result<()> {
+        handlers::recognition::revoke_recognition(ctx)
+    }
+
+    pub fn verify_recognized_credential(
+        ctx: Context<VerifyRecognizedCredential>,
+        anonymous: bool,
+    ) -> Result<()> {
+        handlers::recognition::verify_recognized_credential(ctx, anonymous)
+    }
+
     pub fn add_provider_endorser(ctx: Context<AddProviderEndorser>) -> Result<()> {
         handlers::provider::add_provider_endorser(ctx)
     }
@@ -136,6 +270,21 @@ pub mod fair_credit {
         handlers::provider::remove_provider_endorser(ctx)
     }
 
+    pub fn set_provider_staff_role(
+        ctx: Context<ManageProviderStaff>,
+        role: types::StaffRole,
+    ) -> Result<()> {
+        handlers::provider::set_provider_staff_role(ctx, role)
+    }
+
+    pub fn remove_provider_staff(ctx: Context<ManageProviderStaff>) -> Result<()> {
+        handlers::provider::remove_provider_staff(ctx)
+    }
+
+    pub fn refresh_provider_trust_level(ctx: Context<RefreshProviderTrustLevel>) -> Result<()> {
+        handlers::provider::refresh_provider_trust_level(ctx)
+    }
+
     pub fn close_provider(ctx: Context<CloseProvider>) -> Result<()> {
         handlers::provider::close_provider(ctx)
     }
@@ -162,34 +311,27 @@ pub mod fair_credit {
         )
     }
 
-    pub fn add_course_module(
-        ctx: Context<AddCourseModule>,
-        resource: Pubkey,
-        percentage: u8,
-    ) -> Result<()> {
-        ctx.accounts.course.add_module(resource, percentage)?;
-        Ok(())
+    pub fn add_course_module(ctx: Context<AddCourseModule>, percentage: u8) -> Result<()> {
+        handlers::course::add_course_module(ctx, percentage)
     }
 
-    pub fn update_course_module(
-        ctx: Context<UpdateCourseModule>,
-        resource: Pubkey,
-        percentage: u8,
-    ) -> Result<()> {
-        ctx.accounts.course.update_module(resource, percentage)?;
-        Ok(())
+    pub fn update_course_module(ctx: Context<UpdateCourseModule>, percentage: u8) -> Result<()> {
+        handlers::course::update_course_module(ctx, percentage)
     }
 
     pub fn remove_course_module(ctx: Context<RemoveCourseModule>, resource: Pubkey) -> Result<()> {
         handlers::course::remove_course_module(ctx, resource)
     }
 
+    pub fn finalize_modules(ctx: Context<FinalizeModules>) -> Result<()> {
+        handlers::course::finalize_modules(ctx)
+    }
+
     pub fn update_course_status(
         ctx: Context<UpdateCourseStatus>,
         status: types::CourseStatus,
-        rejection_reason: Option<String>,
     ) -> Result<()> {
-        handlers::course::update_course_status(ctx, status, rejection_reason)
+        handlers::course::update_course_status(ctx, status)
     }
 
     pub fn set_course_nostr_ref(
@@ -201,6 +343,129 @@ pub mod fair_credit {
         handlers::course::set_course_nostr_ref(ctx, nostr_d_tag, nostr_author_pubkey, force)
     }
 
+    pub fn set_course_validity_period(
+        ctx: Context<SetCourseValidityPeriod>,
+        validity_period: Option<i64>,
+    ) -> Result<()> {
+        handlers::course::set_course_validity_period(ctx, validity_period)
+    }
+
+    pub fn set_course_endorsement_threshold(
+        ctx: Context<SetCourseEndorsementThreshold>,
+        threshold: u8,
+    ) -> Result<()> {
+        handlers::course::set_course_endorsement_threshold(ctx, threshold)
+    }
+
+    pub fn set_course_min_grade(
+        ctx: Context<SetCourseMinGrade>,
+        min_grade: Option<u8>,
+    ) -> Result<()> {
+        handlers::course::set_course_min_grade(ctx, min_grade)
+    }
+
+    pub fn set_course_enrollment_config(
+        ctx: Context<SetCourseEnrollmentConfig>,
+        mode: types::EnrollmentMode,
+        capacity: Option<u32>,
+        opens_at: Option<i64>,
+        closes_at: Option<i64>,
+    ) -> Result<()> {
+        handlers::course::set_course_enrollment_config(ctx, mode, capacity, opens_at, closes_at)
+    }
+
+    pub fn add_course_prerequisite(ctx: Context<AddCoursePrerequisite>) -> Result<()> {
+        handlers::course::add_course_prerequisite(ctx)
+    }
+
+    pub fn remove_course_prerequisite(
+        ctx: Context<RemoveCoursePrerequisite>,
+        prerequisite: Pubkey,
+    ) -> Result<()> {
+        handlers::course::remove_course_prerequisite(ctx, prerequisite)
+    }
+
+    pub fn create_degree(
+        ctx: Context<CreateDegree>,
+        degree_id: String,
+        name: String,
+        description: String,
+        workload_required: u32,
+    ) -> Result<()> {
+        handlers::degree::create_degree(ctx, degree_id, name, description, workload_required)
+    }
+
+    pub fn add_degree_required_course(ctx: Context<AddDegreeCourse>) -> Result<()> {
+        handlers::degree::add_degree_required_course(ctx)
+    }
+
+    pub fn add_degree_elective_pool(
+        ctx: Context<UpdateDegree>,
+        name: String,
+        min_courses: u8,
+    ) -> Result<()> {
+        handlers::degree::add_degree_elective_pool(ctx, name, min_courses)
+    }
+
+    pub fn add_degree_elective_course(ctx: Context<AddDegreeCourse>, pool_index: u8) -> Result<()> {
+        handlers::degree::add_degree_elective_course(ctx, pool_index)
+    }
+
+    pub fn remove_degree_course(ctx: Context<UpdateDegree>, course: Pubkey) -> Result<()> {
+        handlers::degree::remove_degree_course(ctx, course)
+    }
+
+    pub fn publish_degree(ctx: Context<UpdateDegree>) -> Result<()> {
+        handlers::degree::publish_degree(ctx)
+    }
+
+    pub fn claim_degree_credential(ctx: Context<ClaimDegreeCredential>) -> Result<()> {
+        handlers::degree::claim_degree_credential(ctx)
+    }
+
+    pub fn revoke_degree_credential(ctx: Context<RevokeDegreeCredential>) -> Result<()> {
+        handlers::degree::revoke_degree_credential(ctx)
+    }
+
+    pub fn invalidate_degree_credential(ctx: Context<InvalidateDegreeCredential>) -> Result<()> {
+        handlers::degree::invalidate_degree_credential(ctx)
+    }
+
+    pub fn enroll_student(ctx: Context<EnrollStudent>) -> Result<()> {
+        handlers::enrollment::enroll_student(ctx)
+    }
+
+    pub fn withdraw_enrollment(ctx: Context<WithdrawEnrollment>) -> Result<()> {
+        handlers::enrollment::withdraw_enrollment(ctx)
+    }
+
+    pub fn initialize_course_progress(ctx: Context<InitializeCourseProgress>) -> Result<()> {
+        handlers::progress::initialize_course_progress(ctx)
+    }
+
+    pub fn update_course_progress(ctx: Context<UpdateCourseProgress>) -> Result<()> {
+        handlers::progress::update_course_progress(ctx)
+    }
+
+    pub fn add_course_endorser(ctx: Context<ManageCourseEndorser>) -> Result<()> {
+        handlers::course::add_course_endorser(ctx)
+    }
+
+    pub fn remove_course_endorser(ctx: Context<ManageCourseEndorser>) -> Result<()> {
+        handlers::course::remove_course_endorser(ctx)
+    }
+
+    pub fn set_course_staff_role(
+        ctx: Context<ManageCourseStaff>,
+        role: Option<types::StaffRole>,
+    ) -> Result<()> {
+        handlers::course::set_course_staff_role(ctx, role)
+    }
+
+    pub fn remove_course_staff_role(ctx: Context<ManageCourseStaff>) -> Result<()> {
+        handlers::course::remove_course_staff_role(ctx)
+    }
+
     pub fn close_course(ctx: Context<CloseCourse>) -> Result<()> {
         handlers::course::close_course(ctx)
     }
@@ -238,6 +503,23 @@ pub mod fair_credit {
         handlers::resource::update_resource_data(ctx, name, workload, tags)
     }
 
+    pub fn set_resource_max_attempts(
+        ctx: Context<SetResourceMaxAttempts>,
+        max_attempts: Option<u8>,
+    ) -> Result<()> {
+        handlers::resource::set_resource_max_attempts(ctx, max_attempts)
+    }
+
+    pub fn set_resource_deadlines(
+        ctx: Context<SetResourceDeadlines>,
+        opens_at: Option<i64>,
+        due_at: Option<i64>,
+        closes_at: Option<i64>,
+        late_penalty: Option<types::LatePenaltyPolicy>,
+    ) -> Result<()> {
+        handlers::resource::set_resource_deadlines(ctx, opens_at, due_at, closes_at, late_penalty)
+    }
+
     pub fn set_resource_nostr_ref(
         ctx: Context<SetResourceNostrRef>,
         nostr_d_tag: String,
@@ -294,11 +576,10 @@ pub mod fair_credit {
 
     pub fn create_submission(
         ctx: Context<CreateSubmission>,
-        submission_timestamp: i64,
         assets: Vec<Pubkey>,
         evidence_assets: Vec<Pubkey>,
     ) -> Result<()> {
-        handlers::resource::create_submission(ctx, submission_timestamp, assets, evidence_assets)
+        handlers::resource::create_submission(ctx, assets, evidence_assets)
     }
 
     pub fn grade_submission(
@@ -309,6 +590,44 @@ pub mod fair_credit {
         handlers::resource::grade_submission(ctx, grade, feedback)
     }
 
+    pub fn return_submission(ctx: Context<ReturnSubmission>, feedback: String) -> Result<()> {
+        handlers::resource::return_submission(ctx, feedback)
+    }
+
+    pub fn accept_submission(ctx: Context<AcceptSubmission>) -> Result<()> {
+        handlers::resource::accept_submission(ctx)
+    }
+
+    pub fn resubmit_submission(
+        ctx: Context<ResubmitSubmission>,
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+    ) -> Result<()> {
+        handlers::resource::resubmit_submission(ctx, assets, evidence_assets)
+    }
+
+    pub fn create_rubric(
+        ctx: Context<CreateRubric>,
+        criteria: Vec<state::RubricCriterion>,
+    ) -> Result<()> {
+        handlers::rubric::create_rubric(ctx, criteria)
+    }
+
+    pub fn update_rubric(
+        ctx: Context<UpdateRubric>,
+        criteria: Vec<state::RubricCriterion>,
+    ) -> Result<()> {
+        handlers::rubric::update_rubric(ctx, criteria)
+    }
+
+    pub fn grade_submission_with_rubric(
+        ctx: Context<GradeSubmissionWithRubric>,
+        levels: Vec<u8>,
+        feedback: Option<String>,
+    ) -> Result<()> {
+        handlers::rubric::grade_submission_with_rubric(ctx, levels, feedback)
+    }
+
     pub fn set_submission_nostr_ref(
         ctx: Context<SetSubmissionNostrRef>,
         nostr_d_tag: String,
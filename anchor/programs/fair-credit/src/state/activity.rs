@@ -23,6 +23,9 @@ pub struct Activity {
     pub status: ActivityStatus,
     pub resource_kind: Option<ResourceKind>,
     pub grade: Option<f64>,
+    /// Wallet that recorded the grade
+    pub graded_by: Option<Pubkey>,
+    pub graded_at: Option<i64>,
     #[max_len(10, 32)]
     pub assets: Vec<String>, // Asset IDs
     #[max_len(10, 32)]
@@ -49,9 +52,14 @@ impl Activity {
         Ok(())
     }
 
-    pub fn update_grade(&mut self, grade: f64) -> Result<()> {
+    pub fn update_grade(&mut self, grade: f64, graded_by: Pubkey) -> Result<()> {
+        require_gte!(grade, 0.0, ActivityError::InvalidGrade);
+        require!(grade <= 100.0, ActivityError::InvalidGrade);
         self.grade = Some(grade);
-        self.updated = Clock::get()?.unix_timestamp;
+        self.graded_by = Some(graded_by);
+        let now = Clock::get()?.unix_timestamp;
+        self.graded_at = Some(now);
+        self.updated = now;
         Ok(())
     }
 
@@ -1,4 +1,7 @@
-use crate::types::{CourseError, CourseStatus};
+use crate::state::{Credential, Provider};
+use crate::types::{
+    CollegeError, CourseError, CourseStatus, CourseStatusActor, EnrollmentMode, StaffRole,
+};
 use anchor_lang::prelude::*;
 
 /// One module in a course: points to a resource and its weight (percentage).
@@ -6,6 +9,15 @@ use anchor_lang::prelude::*;
 pub struct CourseModule {
     pub resource: Pubkey,
     pub percentage: u8, // 0-100
+    /// Workload of the module resource when the module was added or last updated
+    pub workload: u32,
+}
+
+/// Course-level override of a provider staff member's role; `None` removes their access to the course
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct CourseStaffOverride {
+    pub wallet: Pubkey,
+    pub role: Option<StaffRole>,
 }
 
 #[account]
@@ -26,7 +38,10 @@ pub struct Course {
     /// List of modules: each points to a resource (PDA) and has a percentage weight
     #[max_len(20)]
     pub modules: Vec<CourseModule>,
+    /// Set by `finalize_modules` once module weights total 100%; cleared by any module change
+    pub modules_finalized: bool,
     pub workload_required: u32,
+    /// Sum of module workloads, kept in sync by the module instructions
     pub workload: u32,
     #[max_len(32)]
     pub college_id: String,
@@ -40,26 +55,100 @@ pub struct Course {
     /// Credential PDAs approved by this provider for this course
     #[max_len(200)]
     pub approved_credentials: Vec<Pubkey>,
+    /// Default validity period (seconds) applied to credentials on approval; None means no expiry
+    pub validity_period: Option<i64>,
+    /// Provider endorsers allowed to endorse this course's credentials (empty = any provider endorser)
+    #[max_len(10)]
+    pub endorsers: Vec<Pubkey>,
+    /// Number of distinct endorsements required before a credential becomes Endorsed
+    pub endorsement_threshold: u8,
+    /// Minimum weighted course grade (0-100) a student needs before creating a credential
+    pub min_grade: Option<u8>,
+    /// Whether students enroll themselves or are enrolled by the provider
+    pub enrollment_mode: EnrollmentMode,
+    /// Maximum number of enrolled students; None means unlimited
+    pub enrollment_capacity: Option<u32>,
+    /// Enrollment opens at this timestamp; None means open from acceptance
+    pub enrollment_opens_at: Option<i64>,
+    /// Enrollment closes at this timestamp; None means no deadline
+    pub enrollment_closes_at: Option<i64>,
+    /// Number of enrollment accounts currently open for this course
+    pub enrolled_count: u32,
+    /// Courses whose Verified or Minted credential a student must hold to enroll or get a credential
+    #[max_len(5)]
+    pub prerequisites: Vec<Pubkey>,
+    /// Per-course staff roles taking precedence over the provider's staff registry
+    #[max_len(10)]
+    pub staff_overrides: Vec<CourseStaffOverride>,
 }
 
 impl Course {
     pub const SEED_PREFIX: &'static str = "course";
+    pub const MAX_REJECTION_REASON_LEN: usize = 200;
+    pub const MAX_MODULES: usize = 20;
+    pub const MAX_PREREQUISITES: usize = 5;
+    pub const MAX_STAFF_OVERRIDES: usize = 10;
+    /// Total module weight (percent) required by `finalize_modules`
+    pub const FULL_MODULE_WEIGHT: u16 = 100;
+
+    /// Sum of all module weights (percent)
+    pub fn module_weight_total(&self) -> u16 {
+        self.modules.iter().map(|m| m.percentage as u16).sum()
+    }
+
+    /// Sum of all module workloads
+    pub fn module_workload_total(&self) -> u32 {
+        let total: u64 = self.modules.iter().map(|m| m.workload as u64).sum();
+        total.min(u32::MAX as u64) as u32
+    }
+
+    fn ensure_modules_editable(&self) -> Result<()> {
+        require!(
+            self.status == CourseStatus::Draft,
+            CourseError::ModulesLocked
+        );
+        Ok(())
+    }
 
-    pub fn add_module(&mut self, resource: Pubkey, percentage: u8) -> Result<()> {
-        require!(self.modules.len() < 20, CourseError::TooManyModules);
+    pub fn add_module(&mut self, resource: Pubkey, percentage: u8, workload: u32) -> Result<()> {
+        self.ensure_modules_editable()?;
+        require!(
+            self.modules.len() < Self::MAX_MODULES,
+            CourseError::TooManyModules
+        );
         require!(percentage <= 100, CourseError::InvalidProgress);
+        require!(
+            !self.modules.iter().any(|m| m.resource == resource),
+            CourseError::DuplicateModule
+        );
+        require!(
+            self.module_weight_total() + percentage as u16 <= Self::FULL_MODULE_WEIGHT,
+            CourseError::ModuleWeightExceeded
+        );
         self.modules.push(CourseModule {
             resource,
             percentage,
+            workload,
         });
+        self.workload = self.module_workload_total();
+        self.modules_finalized = false;
         self.updated = Clock::get()?.unix_timestamp;
         Ok(())
     }
 
-    pub fn update_module(&mut self, resource: Pubkey, percentage: u8) -> Result<()> {
+    pub fn update_module(&mut self, resource: Pubkey, percentage: u8, workload: u32) -> Result<()> {
+        self.ensure_modules_editable()?;
         require!(percentage <= 100, CourseError::InvalidProgress);
+        let total = self.module_weight_total();
         if let Some(module) = self.modules.iter_mut().find(|m| m.resource == resource) {
+            require!(
+                total - module.percentage as u16 + percentage as u16 <= Self::FULL_MODULE_WEIGHT,
+                CourseError::ModuleWeightExceeded
+            );
             module.percentage = percentage;
+            module.workload = workload;
+            self.workload = self.module_workload_total();
+            self.modules_finalized = false;
             self.updated = Clock::get()?.unix_timestamp;
             Ok(())
         } else {
@@ -68,24 +157,87 @@ impl Course {
     }
 
     pub fn remove_module(&mut self, resource: Pubkey) -> Result<()> {
+        self.ensure_modules_editable()?;
         let initial_len = self.modules.len();
         self.modules.retain(|m| m.resource != resource);
         if self.modules.len() == initial_len {
             return err!(CourseError::ModuleNotFound);
         }
+        self.workload = self.module_workload_total();
+        self.modules_finalized = false;
+        self.updated = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Take an enrollment slot; the course must be Accepted, inside its window and below capacity
+    pub fn reserve_enrollment(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.status == CourseStatus::Accepted
+                && !matches!(self.enrollment_opens_at, Some(opens) if now < opens)
+                && !matches!(self.enrollment_closes_at, Some(closes) if now > closes),
+            CourseError::EnrollmentClosed
+        );
+        require!(
+            !matches!(self.enrollment_capacity, Some(capacity) if self.enrolled_count >= capacity),
+            CourseError::EnrollmentFull
+        );
+        self.enrolled_count += 1;
+        self.updated = now;
+        Ok(())
+    }
+
+    /// Free the slot of a withdrawn enrollment
+    pub fn release_enrollment(&mut self, now: i64) {
+        self.enrolled_count = self.enrolled_count.saturating_sub(1);
+        self.updated = now;
+    }
+
+    /// Lock in the module layout; weights must total exactly 100%
+    pub fn finalize_modules(&mut self) -> Result<()> {
+        self.ensure_modules_editable()?;
+        require!(
+            !self.modules.is_empty() && self.module_weight_total() == Self::FULL_MODULE_WEIGHT,
+            CourseError::InvalidModuleWeightTotal
+        );
+        require!(
+            self.workload >= self.workload_required,
+            CourseError::InsufficientModuleWorkload
+        );
+        self.modules_finalized = true;
         self.updated = Clock::get()?.unix_timestamp;
         Ok(())
     }
 
-    pub fn update_status(
+    /// Apply a status change allowed by the transition table; returns the previous status.
+    /// A rejection (InReview -> Draft) must carry a reason; any other transition clears it.
+    pub fn transition_status(
         &mut self,
         status: CourseStatus,
+        actor: CourseStatusActor,
         rejection_reason: Option<String>,
-    ) -> Result<()> {
-        self.status = status;
-        self.rejection_reason = rejection_reason;
+    ) -> Result<CourseStatus> {
+        require!(
+            self.status.can_transition(&status, actor),
+            CourseError::IllegalStatusTransition
+        );
+        if self.status == CourseStatus::Draft && status == CourseStatus::InReview {
+            require!(self.modules_finalized, CourseError::ModulesNotFinalized);
+        }
+        let is_rejection = self.status == CourseStatus::InReview && status == CourseStatus::Draft;
+        if is_rejection {
+            let reason = rejection_reason
+                .as_deref()
+                .ok_or(CourseError::RejectionReasonRequired)?;
+            require!(
+                !reason.trim().is_empty() && reason.len() <= Self::MAX_REJECTION_REASON_LEN,
+                CourseError::RejectionReasonRequired
+            );
+        }
+
+        let previous = std::mem::replace(&mut self.status, status);
+        self.rejection_reason = if is_rejection { rejection_reason } else { None };
         self.updated = Clock::get()?.unix_timestamp;
-        Ok(())
+        Ok(previous)
     }
 
     /// Add a credential to the approved list (provider confirms after endorser signs)
@@ -103,6 +255,148 @@ impl Course {
         Ok(())
     }
 
+    /// Remove a credential from the approved list (e.g. after revocation)
+    pub fn remove_approved_credential(&mut self, credential_pubkey: &Pubkey) -> Result<()> {
+        self.approved_credentials.retain(|c| c != credential_pubkey);
+        self.updated = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Restrict endorsement of this course's credentials to the given endorser
+    pub fn add_endorser(&mut self, endorser: Pubkey) -> Result<()> {
+        if !self.endorsers.contains(&endorser) {
+            require!(self.endorsers.len() < 10, CourseError::TooManyEndorsers);
+            self.endorsers.push(endorser);
+            self.updated = Clock::get()?.unix_timestamp;
+        }
+        Ok(())
+    }
+
+    pub fn remove_endorser(&mut self, endorser: &Pubkey) -> Result<()> {
+        self.endorsers.retain(|e| e != endorser);
+        self.updated = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Override a staff member's role for this course (or remove their access with `None`)
+    pub fn set_staff_override(&mut self, wallet: Pubkey, role: Option<StaffRole>) -> Result<()> {
+        if let Some(entry) = self.staff_overrides.iter_mut().find(|o| o.wallet == wallet) {
+            entry.role = role;
+            return Ok(());
+        }
+        require!(
+            self.staff_overrides.len() < Self::MAX_STAFF_OVERRIDES,
+            CourseError::TooManyStaffOverrides
+        );
+        self.staff_overrides
+            .push(CourseStaffOverride { wallet, role });
+        Ok(())
+    }
+
+    pub fn remove_staff_override(&mut self, wallet: &Pubkey) -> Result<()> {
+        let len = self.staff_overrides.len();
+        self.staff_overrides.retain(|o| &o.wallet != wallet);
+        require!(
+            self.staff_overrides.len() < len,
+            CourseError::StaffOverrideNotFound
+        );
+        Ok(())
+    }
+
+    /// Course override for a wallet: `Some(role)` when overridden, `None` to use the provider role
+    pub fn staff_override(&self, wallet: &Pubkey) -> Option<Option<StaffRole>> {
+        self.staff_overrides
+            .iter()
+            .find(|o| &o.wallet == wallet)
+            .map(|o| o.role)
+    }
+
+    /// Check course-level endorser scoping; an empty list defers to the provider's endorsers
+    pub fn allows_endorser(&self, endorser: &Pubkey) -> bool {
+        self.endorsers.is_empty() || self.endorsers.contains(endorser)
+    }
+
+    /// Effective endorsement threshold (at least one endorsement is always required)
+    pub fn required_endorsements(&self) -> usize {
+        self.endorsement_threshold.max(1) as usize
+    }
+
+    pub fn add_prerequisite(&mut self, prerequisite: Pubkey) -> Result<()> {
+        require!(
+            !self.prerequisites.contains(&prerequisite),
+            CollegeError::PrerequisiteAlreadyExists
+        );
+        require!(
+            self.prerequisites.len() < Self::MAX_PREREQUISITES,
+            CollegeError::TooManyPrerequisites
+        );
+        self.prerequisites.push(prerequisite);
+        self.updated = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    pub fn remove_prerequisite(&mut self, prerequisite: &Pubkey) -> Result<()> {
+        let initial_len = self.prerequisites.len();
+        self.prerequisites.retain(|p| p != prerequisite);
+        require!(
+            self.prerequisites.len() != initial_len,
+            CourseError::PrerequisiteNotFound
+        );
+        self.updated = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Check that `credentials` (typically the instruction's remaining accounts) hold the
+    /// student's Verified or Minted, unexpired credential for every prerequisite course
+    pub fn check_prerequisites(
+        &self,
+        student: &Pubkey,
+        credentials: &[AccountInfo],
+        program_id: &Pubkey,
+        now: i64,
+    ) -> Result<()> {
+        for prerequisite in &self.prerequisites {
+            let (expected, _) = Pubkey::find_program_address(
+                &[
+                    Credential::SEED_PREFIX.as_bytes(),
+                    prerequisite.as_ref(),
+                    student.as_ref(),
+                ],
+                program_id,
+            );
+            let info = credentials
+                .iter()
+                .find(|info| info.key() == expected && info.owner == program_id)
+                .ok_or(CourseError::PrerequisiteNotMet)?;
+            let data = info.try_borrow_data()?;
+            let credential = Credential::try_deserialize(&mut &data[..])?;
+            require!(credential.is_valid(now), CourseError::PrerequisiteNotMet);
+        }
+        Ok(())
+    }
+
+    /// Course PDA derived from this course's provider and creation timestamp under `hub`
+    pub fn expected_address(&self, hub: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        let (provider, _) = Pubkey::find_program_address(
+            &[
+                Provider::SEED_PREFIX.as_bytes(),
+                hub.as_ref(),
+                self.provider.as_ref(),
+            ],
+            program_id,
+        );
+        Pubkey::find_program_address(
+            &[
+                Self::SEED_PREFIX.as_bytes(),
+                hub.as_ref(),
+                provider.as_ref(),
+                &self.creation_timestamp.to_le_bytes(),
+            ],
+            program_id,
+        )
+        .0
+    }
+
     pub fn is_nostr_ref_set(&self) -> bool {
         self.nostr_d_tag.is_some() || self.nostr_author_pubkey != [0u8; 32]
     }
@@ -0,0 +1,96 @@
+use crate::state::Course;
+use anchor_lang::prelude::*;
+
+/// Best graded submission recorded for one course module
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ModuleGrade {
+    /// Module resource
+    pub resource: Pubkey,
+    /// Submission the grade was taken from
+    pub submission: Pubkey,
+    /// Grade (0-100)
+    pub grade: f64,
+    /// Set once an accepted submission has been recorded for the module
+    pub completed: bool,
+}
+
+/// Per-student progress through a course.
+/// Module grades are fed in by `update_course_progress`, one graded submission at a time.
+#[account]
+#[derive(InitSpace)]
+pub struct CourseProgress {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    #[max_len(20)]
+    pub module_grades: Vec<ModuleGrade>,
+    /// Weighted course grade (0-100); modules without a graded submission count as zero
+    pub weighted_grade: f64,
+    /// Share of the course's module weight that has a graded submission (percent)
+    pub graded_weight: u8,
+    /// Workload of the modules completed with an accepted submission
+    pub completed_workload: u32,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl CourseProgress {
+    pub const SEED_PREFIX: &'static str = "course-progress";
+
+    /// Record a module grade (keeping the best grade per module) and refresh the weighted grade
+    /// and completed workload. A module stays completed once an accepted submission is recorded.
+    pub fn record_module_grade(
+        &mut self,
+        course: &Course,
+        resource: Pubkey,
+        submission: Pubkey,
+        grade: f64,
+        accepted: bool,
+    ) {
+        // Drop grades for resources that are no longer modules so the list stays bounded
+        self.module_grades
+            .retain(|g| course.modules.iter().any(|m| m.resource == g.resource));
+        match self
+            .module_grades
+            .iter_mut()
+            .find(|m| m.resource == resource)
+        {
+            Some(existing) => {
+                if grade > existing.grade {
+                    existing.submission = submission;
+                    existing.grade = grade;
+                }
+                existing.completed |= accepted;
+            }
+            None => self.module_grades.push(ModuleGrade {
+                resource,
+                submission,
+                grade,
+                completed: accepted,
+            }),
+        }
+        self.recompute(course);
+    }
+
+    /// Recompute the weighted grade and completed workload from the course's current modules
+    pub fn recompute(&mut self, course: &Course) {
+        let mut weighted = 0.0;
+        let mut graded_weight: u16 = 0;
+        let mut completed_workload: u64 = 0;
+        for module in &course.modules {
+            if let Some(entry) = self
+                .module_grades
+                .iter()
+                .find(|m| m.resource == module.resource)
+            {
+                weighted += entry.grade * module.percentage as f64 / 100.0;
+                graded_weight += module.percentage as u16;
+                if entry.completed {
+                    completed_workload += module.workload as u64;
+                }
+            }
+        }
+        self.weighted_grade = weighted;
+        self.graded_weight = graded_weight.min(100) as u8;
+        self.completed_workload = completed_workload.min(u32::MAX as u64) as u32;
+    }
+}
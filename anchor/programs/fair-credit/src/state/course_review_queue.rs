@@ -0,0 +1,63 @@
+use crate::types::HubError;
+use anchor_lang::prelude::*;
+
+/// Course waiting for hub review
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PendingCourse {
+    /// Course PDA
+    pub course: Pubkey,
+    /// Provider wallet that owns the course
+    pub provider: Pubkey,
+    /// Timestamp the course entered review
+    pub submitted_at: i64,
+}
+
+/// Courses currently InReview in a hub.
+/// Created together with the hub; entries are added when a course enters review and removed
+/// by `add_accepted_course` / `reject_course` / `close_course` (or `prune_course_review_queue`),
+/// so the hub can enumerate submissions directly.
+#[account]
+#[derive(InitSpace)]
+pub struct CourseReviewQueue {
+    pub hub: Pubkey,
+    pub updated_at: i64,
+    #[max_len(100)]
+    pub courses: Vec<PendingCourse>,
+}
+
+impl CourseReviewQueue {
+    pub const SEED_PREFIX: &'static str = "course-review-queue";
+    pub const MAX_PENDING: usize = 100;
+
+    pub fn enqueue(&mut self, course: Pubkey, provider: Pubkey, timestamp: i64) -> Result<()> {
+        if self.contains(&course) {
+            return Ok(());
+        }
+        require!(
+            self.courses.len() < Self::MAX_PENDING,
+            HubError::CourseReviewQueueFull
+        );
+        self.courses.push(PendingCourse {
+            course,
+            provider,
+            submitted_at: timestamp,
+        });
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
+    /// Remove a course from the queue; returns whether it was queued
+    pub fn remove(&mut self, course: &Pubkey, timestamp: i64) -> bool {
+        let before = self.courses.len();
+        self.courses.retain(|c| &c.course != course);
+        let removed = before != self.courses.len();
+        if removed {
+            self.updated_at = timestamp;
+        }
+        removed
+    }
+
+    pub fn contains(&self, course: &Pubkey) -> bool {
+        self.courses.iter().any(|c| &c.course == course)
+    }
+}
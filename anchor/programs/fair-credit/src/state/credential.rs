@@ -1,4 +1,4 @@
-use crate::types::{CredentialMetadata, CredentialStatus};
+use crate::types::{CredentialMetadata, CredentialStatus, Endorsement};
 use anchor_lang::prelude::*;
 
 /// Main credential data structure
@@ -12,7 +12,7 @@ pub struct Credential {
     pub updated: i64,
     /// Student wallet address
     pub student_wallet: Pubkey,
-    /// Mentor wallet address
+    /// Mentor wallet address (first endorser)
     pub mentor_wallet: Pubkey,
     /// Educational provider wallet address
     pub provider_wallet: Pubkey,
@@ -26,11 +26,24 @@ pub struct Credential {
     pub verification_count: u64,
     /// Current status
     pub status: CredentialStatus,
+    /// End of the validity window set on approval (None means the credential never expires)
+    pub valid_until: Option<i64>,
+    /// Endorser sign-offs collected towards the course endorsement threshold
+    #[max_len(5)]
+    pub endorsements: Vec<Endorsement>,
+    /// Times the student restarted the approval cycle with `renew_credential`
+    pub renewal_count: u16,
 }
 
 impl Credential {
     /// Seed prefix for PDA generation
     pub const SEED_PREFIX: &'static str = "credential";
+    /// Maximum number of endorsements stored on a credential
+    pub const MAX_ENDORSEMENTS: usize = 5;
+    /// Maximum endorsement message length (matches `Endorsement::message`)
+    pub const MAX_ENDORSEMENT_MESSAGE_LEN: usize = 300;
+    /// Renewal opens this long (30 days) before `valid_until`
+    pub const RENEWAL_WINDOW: i64 = 30 * 24 * 60 * 60;
 
     /// Update credential status
     pub fn update_status(&mut self, new_status: CredentialStatus) {
@@ -38,6 +51,46 @@ impl Credential {
         self.updated = Clock::get().unwrap().unix_timestamp;
     }
 
+    /// Whether approval counted this credential towards the provider's approved credentials;
+    /// a renewed credential was counted at its first approval
+    pub fn was_approved(&self) -> bool {
+        matches!(
+            self.status,
+            CredentialStatus::Verified | CredentialStatus::Minted
+        ) || self.renewal_count > 0
+    }
+
+    /// Check whether the validity window has passed
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.valid_until, Some(valid_until) if now > valid_until)
+    }
+
+    /// Whether the renewal window has opened: from `RENEWAL_WINDOW` before expiry onwards
+    pub fn is_renewal_open(&self, now: i64) -> bool {
+        self.valid_until
+            .is_some_and(|valid_until| now >= valid_until.saturating_sub(Self::RENEWAL_WINDOW))
+    }
+
+    /// Issued (Verified or Minted) and still inside its validity window
+    pub fn is_valid(&self, now: i64) -> bool {
+        matches!(
+            self.status,
+            CredentialStatus::Verified | CredentialStatus::Minted
+        ) && !self.is_expired(now)
+    }
+
+    /// Check whether the given wallet has already endorsed this credential
+    pub fn has_endorsed(&self, endorser: &Pubkey) -> bool {
+        self.endorsements.iter().any(|e| &e.endorser == endorser)
+    }
+
+    /// Drop all endorsements (e.g. when restarting the endorsement cycle)
+    pub fn clear_endorsements(&mut self) {
+        self.mentor_wallet = Pubkey::default();
+        self.metadata.mentor_endorsement = String::new();
+        self.endorsements.clear();
+    }
+
     /// Increment verification count
     pub fn increment_verification_count(&mut self) {
         self.verification_count += 1;
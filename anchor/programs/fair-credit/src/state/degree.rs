@@ -0,0 +1,204 @@
+use crate::types::CollegeError;
+use anchor_lang::prelude::*;
+
+/// Pool of elective courses of which a student must complete at least `min_courses`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ElectivePool {
+    #[max_len(32)]
+    pub name: String,
+    pub min_courses: u8,
+    #[max_len(10)]
+    pub courses: Vec<Pubkey>,
+}
+
+/// Degree programme owned by a provider: required courses, elective pools and a total workload.
+/// The structure is frozen once published; students can only claim a `DegreeCredential`
+/// on a published degree, once their course credentials satisfy the structure.
+#[account]
+#[derive(InitSpace)]
+pub struct Degree {
+    /// Provider PDA that owns the degree
+    pub provider: Pubkey,
+    /// Identifier used in PDA seeds; matches `Course.degree_id` / `Activity.degree_id`
+    #[max_len(32)]
+    pub degree_id: String,
+    #[max_len(64)]
+    pub name: String,
+    #[max_len(256)]
+    pub description: String,
+    #[max_len(10)]
+    pub required_courses: Vec<Pubkey>,
+    #[max_len(4)]
+    pub elective_pools: Vec<ElectivePool>,
+    /// Minimum workload summed over the degree courses a student completes
+    pub workload_required: u32,
+    /// Set by `publish_degree`; a published degree accepts claims but no structure changes
+    pub published: bool,
+    pub created: i64,
+    pub updated: i64,
+}
+
+impl Degree {
+    pub const SEED_PREFIX: &'static str = "degree";
+    pub const MAX_DEGREE_ID_LEN: usize = 32;
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_DESCRIPTION_LEN: usize = 256;
+    pub const MAX_POOL_NAME_LEN: usize = 32;
+    pub const MAX_REQUIRED_COURSES: usize = 10;
+    pub const MAX_ELECTIVE_POOLS: usize = 4;
+    pub const MAX_POOL_COURSES: usize = 10;
+
+    /// Whether the course is already part of the degree, as required course or elective
+    pub fn includes_course(&self, course: &Pubkey) -> bool {
+        self.required_courses.contains(course)
+            || self
+                .elective_pools
+                .iter()
+                .any(|pool| pool.courses.contains(course))
+    }
+
+    pub fn ensure_unpublished(&self) -> Result<()> {
+        require!(!self.published, CollegeError::DegreeAlreadyPublished);
+        Ok(())
+    }
+
+    /// Freeze the structure. The degree must require something, and every elective pool
+    /// must hold at least `min_courses` courses so it can be satisfied.
+    pub fn publish(&mut self) -> Result<()> {
+        self.ensure_unpublished()?;
+        require!(
+            !self.required_courses.is_empty() || !self.elective_pools.is_empty(),
+            CollegeError::DegreeEmpty
+        );
+        require!(
+            self.elective_pools
+                .iter()
+                .all(|pool| pool.courses.len() >= pool.min_courses as usize),
+            CollegeError::ElectivePoolTooSmall
+        );
+        self.published = true;
+        Ok(())
+    }
+
+    pub fn add_required_course(&mut self, course: Pubkey) -> Result<()> {
+        self.ensure_unpublished()?;
+        require!(
+            !self.includes_course(&course),
+            CollegeError::CourseAlreadyRequired
+        );
+        require!(
+            self.required_courses.len() < Self::MAX_REQUIRED_COURSES,
+            CollegeError::TooManyRequiredCourses
+        );
+        self.required_courses.push(course);
+        Ok(())
+    }
+
+    pub fn add_elective_pool(&mut self, name: String, min_courses: u8) -> Result<()> {
+        self.ensure_unpublished()?;
+        require!(
+            name.len() <= Self::MAX_POOL_NAME_LEN,
+            CollegeError::ElectivePoolNameTooLong
+        );
+        require!(
+            self.elective_pools.len() < Self::MAX_ELECTIVE_POOLS,
+            CollegeError::TooManyElectivePools
+        );
+        require!(
+            min_courses > 0 && min_courses as usize <= Self::MAX_POOL_COURSES,
+            CollegeError::InvalidElectiveMinimum
+        );
+        self.elective_pools.push(ElectivePool {
+            name,
+            min_courses,
+            courses: Vec::new(),
+        });
+        Ok(())
+    }
+
+    pub fn add_elective_course(&mut self, pool_index: u8, course: Pubkey) -> Result<()> {
+        self.ensure_unpublished()?;
+        require!(
+            !self.includes_course(&course),
+            CollegeError::ElectiveAlreadyExists
+        );
+        let pool = self
+            .elective_pools
+            .get_mut(pool_index as usize)
+            .ok_or(CollegeError::ElectivePoolNotFound)?;
+        require!(
+            pool.courses.len() < Self::MAX_POOL_COURSES,
+            CollegeError::TooManyElectiveCourses
+        );
+        pool.courses.push(course);
+        Ok(())
+    }
+
+    /// Remove a course from the required list and every elective pool
+    pub fn remove_course(&mut self, course: &Pubkey) -> Result<()> {
+        self.ensure_unpublished()?;
+        require!(
+            self.includes_course(course),
+            CollegeError::DegreeCourseNotFound
+        );
+        self.required_courses.retain(|c| c != course);
+        for pool in self.elective_pools.iter_mut() {
+            pool.courses.retain(|c| c != course);
+        }
+        Ok(())
+    }
+
+    /// Check completed courses (with their workloads) against the degree structure.
+    /// Only degree courses count towards the workload; returns the counted workload.
+    pub fn check_requirements(&self, completed: &[(Pubkey, u32)]) -> Result<u32> {
+        let is_completed = |course: &Pubkey| completed.iter().any(|(c, _)| c == course);
+
+        require!(
+            self.required_courses.iter().all(is_completed),
+            CollegeError::RequiredCourseMissing
+        );
+        for pool in &self.elective_pools {
+            let count = pool.courses.iter().filter(|c| is_completed(c)).count();
+            require!(
+                count >= pool.min_courses as usize,
+                CollegeError::ElectivePoolNotSatisfied
+            );
+        }
+
+        let workload: u64 = completed
+            .iter()
+            .filter(|(course, _)| self.includes_course(course))
+            .map(|(_, workload)| *workload as u64)
+            .sum();
+        let workload = workload.min(u32::MAX as u64) as u32;
+        require!(
+            workload >= self.workload_required,
+            CollegeError::DegreeWorkloadNotMet
+        );
+        Ok(workload)
+    }
+}
+
+/// Degree-level credential issued by `claim_degree_credential`
+#[account]
+#[derive(InitSpace)]
+pub struct DegreeCredential {
+    pub degree: Pubkey,
+    pub student: Pubkey,
+    /// Course credentials that satisfied the degree structure
+    #[max_len(50)]
+    pub course_credentials: Vec<Pubkey>,
+    /// Workload of the degree courses the student completed
+    pub total_workload: u32,
+    pub issued_at: i64,
+    /// Set when the degree credential is revoked; the PDA stays so it cannot be claimed again
+    pub revoked_at: Option<i64>,
+}
+
+impl DegreeCredential {
+    pub const SEED_PREFIX: &'static str = "degree-credential";
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// A student's active enrollment in a course.
+/// Required by `create_submission`, course-linked `create_activity` and `create_credential`;
+/// withdrawing closes the account.
+#[account]
+#[derive(InitSpace)]
+pub struct Enrollment {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    /// Student or provider wallet that created the enrollment
+    pub enrolled_by: Pubkey,
+    pub enrolled_at: i64,
+}
+
+impl Enrollment {
+    pub const SEED_PREFIX: &'static str = "enrollment";
+}
@@ -0,0 +1,257 @@
+use crate::state::HubConfig;
+use crate::types::GovernanceError;
+use anchor_lang::prelude::*;
+
+/// Member set that co-governs a hub.
+/// Once created, the governance PDA becomes `Hub.authority`, so hub-admin actions
+/// can only happen through proposals that reach the approval threshold.
+#[account]
+#[derive(InitSpace)]
+pub struct HubGovernance {
+    pub hub: Pubkey,
+    /// Member wallets allowed to propose and vote
+    #[max_len(10)]
+    pub members: Vec<Pubkey>,
+    /// Number of member approvals required to execute a proposal
+    pub threshold: u8,
+    /// Number of proposals created so far (used as the next proposal index)
+    pub proposal_count: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl HubGovernance {
+    pub const SEED_PREFIX: &'static str = "hub-governance";
+    pub const MAX_MEMBERS: usize = 10;
+
+    pub fn is_member(&self, wallet: &Pubkey) -> bool {
+        self.members.contains(wallet)
+    }
+
+    /// Validate a member set and threshold before storing them
+    pub fn validate(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= Self::MAX_MEMBERS,
+            GovernanceError::InvalidMemberCount
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                !members[..i].contains(member),
+                GovernanceError::DuplicateMember
+            );
+        }
+        require!(
+            threshold >= 1 && threshold as usize <= members.len(),
+            GovernanceError::InvalidThreshold
+        );
+        Ok(())
+    }
+
+    pub fn add_member(&mut self, member: Pubkey) -> Result<()> {
+        require!(!self.is_member(&member), GovernanceError::DuplicateMember);
+        require!(
+            self.members.len() < Self::MAX_MEMBERS,
+            GovernanceError::InvalidMemberCount
+        );
+        self.members.push(member);
+        Ok(())
+    }
+
+    pub fn remove_member(&mut self, member: &Pubkey) -> Result<()> {
+        require!(self.is_member(member), GovernanceError::NotMember);
+        require!(
+            self.members.len() > self.threshold as usize,
+            GovernanceError::InvalidThreshold
+        );
+        self.members.retain(|m| m != member);
+        Ok(())
+    }
+
+    pub fn set_threshold(&mut self, threshold: u8) -> Result<()> {
+        require!(
+            threshold >= 1 && threshold as usize <= self.members.len(),
+            GovernanceError::InvalidThreshold
+        );
+        self.threshold = threshold;
+        Ok(())
+    }
+}
+
+/// Hub-admin action carried by a governance proposal.
+/// Operations without a variant here (recognitions, hub-side credential revocation,
+/// revocation registries) are run by the wallet set with `SetOperator`; `close_hub`
+/// needs a wallet authority, so hand the hub over with `TransferAuthority` first.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub enum HubAction {
+    AddProvider {
+        provider_wallet: Pubkey,
+    },
+    RemoveProvider {
+        provider_wallet: Pubkey,
+    },
+    RejectProvider {
+        provider_wallet: Pubkey,
+    },
+    SuspendProvider {
+        provider_wallet: Pubkey,
+        #[max_len(200)]
+        note: Option<String>,
+    },
+    UnsuspendProvider {
+        provider_wallet: Pubkey,
+    },
+    SetProviderReputation {
+        provider_wallet: Pubkey,
+        reputation_score: u64,
+        #[max_len(200)]
+        note: Option<String>,
+    },
+    AddCourse {
+        course: Pubkey,
+    },
+    RemoveCourse {
+        course: Pubkey,
+    },
+    RejectCourse {
+        course: Pubkey,
+        #[max_len(200)]
+        reason: String,
+    },
+    UpdateConfig {
+        config: HubConfig,
+    },
+    CreateCourseList {
+        course_list_index: u16,
+    },
+    AddCourseToList {
+        course_list_index: u16,
+        course: Pubkey,
+    },
+    RemoveCourseFromList {
+        course_list_index: u16,
+        course: Pubkey,
+        remove_reference_if_empty: bool,
+    },
+    SetCourseListNext {
+        course_list_index: u16,
+        next_list: Option<Pubkey>,
+    },
+    /// Propose a new hub authority (e.g. to leave governance); it takes over with `accept_hub_authority`
+    TransferAuthority {
+        new_authority: Pubkey,
+    },
+    CancelAuthorityTransfer,
+    /// Set or clear the wallet allowed to run operator-level hub operations
+    SetOperator {
+        operator: Option<Pubkey>,
+    },
+    AddMember {
+        member: Pubkey,
+    },
+    RemoveMember {
+        member: Pubkey,
+    },
+    SetThreshold {
+        threshold: u8,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ProposalStatus {
+    Open,
+    Executed,
+    Cancelled,
+}
+
+/// Proposal to perform one hub-admin action
+#[account]
+#[derive(InitSpace)]
+pub struct HubProposal {
+    pub governance: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: HubAction,
+    /// Members that approved the proposal (the proposer approves on creation)
+    #[max_len(10)]
+    pub approvals: Vec<Pubkey>,
+    pub status: ProposalStatus,
+    pub created_at: i64,
+    pub executed_at: Option<i64>,
+}
+
+impl HubProposal {
+    pub const SEED_PREFIX: &'static str = "hub-proposal";
+
+    /// Record a member approval, first dropping approvals from removed members
+    /// so they cannot fill the approval list
+    pub fn approve(&mut self, member: Pubkey, governance: &HubGovernance) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Open,
+            GovernanceError::ProposalNotOpen
+        );
+        self.prune_approvals(governance);
+        require!(
+            !self.approvals.contains(&member),
+            GovernanceError::AlreadyApproved
+        );
+        require!(
+            self.approvals.len() < HubGovernance::MAX_MEMBERS,
+            GovernanceError::InvalidMemberCount
+        );
+        self.approvals.push(member);
+        Ok(())
+    }
+
+    /// Drop approvals from wallets that are no longer members
+    pub fn prune_approvals(&mut self, governance: &HubGovernance) {
+        self.approvals.retain(|a| governance.is_member(a));
+    }
+
+    /// Count approvals from wallets that are still members
+    pub fn approval_count(&self, governance: &HubGovernance) -> usize {
+        self.approvals
+            .iter()
+            .filter(|a| governance.is_member(a))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn governance(members: Vec<Pubkey>) -> HubGovernance {
+        HubGovernance {
+            hub: Pubkey::new_unique(),
+            members,
+            threshold: 1,
+            proposal_count: 0,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn approve_prunes_removed_members() {
+        let former: Vec<Pubkey> = (0..HubGovernance::MAX_MEMBERS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        let mut proposal = HubProposal {
+            governance: Pubkey::new_unique(),
+            index: 0,
+            proposer: former[0],
+            action: HubAction::CancelAuthorityTransfer,
+            approvals: former.clone(),
+            status: ProposalStatus::Open,
+            created_at: 0,
+            executed_at: None,
+        };
+        let member = Pubkey::new_unique();
+        let current = governance(vec![former[0], member]);
+
+        proposal.approve(member, &current).unwrap();
+        assert_eq!(proposal.approvals, vec![former[0], member]);
+        assert_eq!(proposal.approval_count(&current), 2);
+        assert!(proposal.approve(member, &current).is_err());
+    }
+}
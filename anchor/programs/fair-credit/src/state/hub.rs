@@ -7,7 +7,10 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct Hub {
-    /// Hub authority (can be multisig or DAO in future)
+    /// Hub identifier used as PDA seed (several hubs can run side by side)
+    #[max_len(32)]
+    pub hub_id: String,
+    /// Hub authority (a wallet, or the `HubGovernance` PDA once governance is enabled)
     pub authority: Pubkey,
     /// List of accepted provider wallets
     #[max_len(50)]
@@ -22,6 +25,11 @@ pub struct Hub {
     pub updated_at: i64,
     /// Hub configuration flags
     pub config: HubConfig,
+    /// Authority proposed by `transfer_hub_authority`, waiting to accept
+    pub pending_authority: Option<Pubkey>,
+    /// Wallet that may run hub-signed operations with no governance action
+    /// (recognitions, hub-side credential revocation, revocation registries)
+    pub operator: Option<Pubkey>,
 }
 
 /// Hub configuration
@@ -33,6 +41,13 @@ pub struct HubConfig {
     pub min_reputation_score: u64,
 }
 
+impl HubConfig {
+    /// Whether a provider with the given reputation joins the hub without manual review
+    pub fn auto_accepts(&self, reputation_score: u64) -> bool {
+        !self.require_provider_approval || reputation_score >= self.min_reputation_score
+    }
+}
+
 impl Default for HubConfig {
     fn default() -> Self {
         Self {
@@ -45,6 +60,8 @@ impl Default for HubConfig {
 impl Hub {
     /// Seed prefix for PDA generation
     pub const SEED_PREFIX: &'static str = "hub";
+    /// Maximum hub identifier length (a single PDA seed is at most 32 bytes)
+    pub const MAX_HUB_ID_LEN: usize = 32;
     /// Maximum number of references stored directly on the hub account.
     pub const MAX_ACCEPTED_REFERENCES: usize = 250;
 
@@ -64,6 +81,11 @@ impl Hub {
         Ok(())
     }
 
+    /// Whether a wallet may run operator-level hub operations (the authority or the operator)
+    pub fn can_operate(&self, wallet: &Pubkey) -> bool {
+        self.authority == *wallet || self.operator == Some(*wallet)
+    }
+
     /// Check if a provider is accepted
     pub fn is_provider_accepted(&self, provider: &Pubkey) -> bool {
         self.accepted_providers.contains(provider)
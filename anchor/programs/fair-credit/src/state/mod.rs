@@ -1,15 +1,35 @@
 pub mod activity;
 pub mod course;
 pub mod course_list;
+pub mod course_progress;
+pub mod course_review_queue;
 pub mod credential;
+pub mod degree;
+pub mod enrollment;
+pub mod governance;
 pub mod hub;
 pub mod provider;
+pub mod provider_queue;
+pub mod recognition;
 pub mod resource;
+pub mod revocation;
+pub mod rubric;
+pub mod verification;
 
 pub use activity::*;
 pub use course::*;
 pub use course_list::*;
+pub use course_progress::*;
+pub use course_review_queue::*;
 pub use credential::*;
+pub use degree::*;
+pub use enrollment::*;
+pub use governance::*;
 pub use hub::*;
 pub use provider::*;
+pub use provider_queue::*;
+pub use recognition::*;
 pub use resource::*;
+pub use revocation::*;
+pub use rubric::*;
+pub use verification::*;
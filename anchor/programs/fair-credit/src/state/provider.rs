@@ -1,5 +1,14 @@
+use crate::state::Course;
+use crate::types::{ProviderError, StaffCapability, StaffRole, TrustInputs, TrustLevel};
 use anchor_lang::prelude::*;
 
+/// Staff wallet registered with a provider and its role across the provider's courses
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct StaffMember {
+    pub wallet: Pubkey,
+    pub role: StaffRole,
+}
+
 /// Educational provider state.
 /// Provider creates Courses (Hub must accept them to be usable) and manages its own Endorsers (no Hub acceptance).
 #[account]
@@ -27,11 +36,39 @@ pub struct Provider {
     /// Endorser wallets set by this provider (no Hub acceptance required)
     #[max_len(100)]
     pub endorsers: Vec<Pubkey>,
+    /// Teaching staff and their provider-wide roles; courses may override them
+    #[max_len(50)]
+    pub staff: Vec<StaffMember>,
+    /// Whether the hub authority has suspended this provider
+    pub suspended: bool,
+    /// Timestamp of the current suspension (None when not suspended)
+    pub suspended_at: Option<i64>,
+    /// Hub authority note explaining the current suspension
+    #[max_len(200)]
+    pub suspension_note: Option<String>,
+    /// Number of times this provider has been suspended
+    pub suspension_count: u32,
+    /// Reputation score assigned by the hub authority (0-100)
+    pub reputation_score: u64,
+    /// Credentials approved across this provider's courses
+    pub approved_credentials_count: u32,
+    /// Credentials revoked across this provider's courses
+    pub revoked_credentials_count: u32,
+    /// Trust level derived from the facts above (refreshed by `refresh_provider_trust_level`)
+    pub trust_level: TrustLevel,
+    /// Timestamp of the last trust level refresh
+    pub trust_level_updated_at: i64,
 }
 
 impl Provider {
     /// Seed prefix for PDA generation
     pub const SEED_PREFIX: &'static str = "provider";
+    /// Maximum length of suspension and reputation notes
+    pub const MAX_NOTE_LEN: usize = 200;
+    /// Maximum reputation score
+    pub const MAX_REPUTATION_SCORE: u64 = 100;
+    /// Maximum number of staff members
+    pub const MAX_STAFF: usize = 50;
 
     /// Add an endorser wallet
     pub fn add_endorser(&mut self, endorser: Pubkey) -> Result<()> {
@@ -41,9 +78,120 @@ impl Provider {
         Ok(())
     }
 
+    /// Check if a wallet is one of this provider's endorsers
+    pub fn is_endorser(&self, endorser: &Pubkey) -> bool {
+        self.endorsers.contains(endorser)
+    }
+
+    /// Assign a staff role, replacing the wallet's current role
+    pub fn set_staff_role(&mut self, wallet: Pubkey, role: StaffRole) -> Result<()> {
+        if let Some(member) = self.staff.iter_mut().find(|m| m.wallet == wallet) {
+            member.role = role;
+            return Ok(());
+        }
+        require!(
+            self.staff.len() < Self::MAX_STAFF,
+            ProviderError::TooManyStaff
+        );
+        self.staff.push(StaffMember { wallet, role });
+        Ok(())
+    }
+
+    pub fn remove_staff(&mut self, wallet: &Pubkey) -> Result<()> {
+        let len = self.staff.len();
+        self.staff.retain(|m| &m.wallet != wallet);
+        require!(self.staff.len() < len, ProviderError::StaffNotFound);
+        Ok(())
+    }
+
+    /// Effective role of a wallet, taking the course's override into account when given
+    pub fn staff_role(&self, wallet: &Pubkey, course: Option<&Course>) -> Option<StaffRole> {
+        match course.and_then(|course| course.staff_override(wallet)) {
+            Some(role) => role,
+            None => self
+                .staff
+                .iter()
+                .find(|m| &m.wallet == wallet)
+                .map(|m| m.role),
+        }
+    }
+
+    /// Check if a wallet may perform an action: the provider wallet may do anything,
+    /// endorsers may grade, staff only what their effective role allows
+    pub fn has_capability(
+        &self,
+        wallet: &Pubkey,
+        course: Option<&Course>,
+        capability: StaffCapability,
+    ) -> bool {
+        *wallet == self.wallet
+            || (capability == StaffCapability::Grade && self.is_endorser(wallet))
+            || self
+                .staff_role(wallet, course)
+                .is_some_and(|role| role.allows(capability))
+    }
+
     /// Remove an endorser wallet
     pub fn remove_endorser(&mut self, endorser: &Pubkey) -> Result<()> {
         self.endorsers.retain(|e| e != endorser);
         Ok(())
     }
+
+    /// Suspend the provider with an optional note
+    pub fn suspend(&mut self, note: Option<String>, timestamp: i64) -> Result<()> {
+        require!(!self.suspended, ProviderError::AlreadySuspended);
+        Self::check_note(&note)?;
+        self.suspended = true;
+        self.suspended_at = Some(timestamp);
+        self.suspension_note = note;
+        self.suspension_count = self.suspension_count.saturating_add(1);
+        Ok(())
+    }
+
+    /// Lift the current suspension
+    pub fn unsuspend(&mut self) -> Result<()> {
+        require!(self.suspended, ProviderError::NotSuspended);
+        self.suspended = false;
+        self.suspended_at = None;
+        self.suspension_note = None;
+        Ok(())
+    }
+
+    /// Set the reputation score (0-100)
+    pub fn set_reputation(&mut self, score: u64, note: &Option<String>) -> Result<()> {
+        require!(
+            score <= Self::MAX_REPUTATION_SCORE,
+            ProviderError::InvalidReputationScore
+        );
+        Self::check_note(note)?;
+        self.reputation_score = score;
+        Ok(())
+    }
+
+    /// Recompute the trust level; returns the previous level when it changed
+    pub fn refresh_trust_level(&mut self, now: i64) -> Option<TrustLevel> {
+        let level = TrustLevel::compute(&TrustInputs {
+            age_seconds: now.saturating_sub(self.registered_at),
+            approved_credentials: self.approved_credentials_count,
+            revoked_credentials: self.revoked_credentials_count,
+            suspension_count: self.suspension_count,
+            suspended: self.suspended,
+            reputation_score: self.reputation_score,
+        });
+        self.trust_level_updated_at = now;
+
+        if level == self.trust_level {
+            return None;
+        }
+        let previous = self.trust_level;
+        self.trust_level = level;
+        Some(previous)
+    }
+
+    fn check_note(note: &Option<String>) -> Result<()> {
+        if let Some(note) = note {
+            require!(note.len() <= Self::MAX_NOTE_LEN, ProviderError::NoteTooLong);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+/// Provider waiting for hub review
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PendingProvider {
+    /// Provider wallet address
+    pub wallet: Pubkey,
+    /// Registration timestamp
+    pub requested_at: i64,
+}
+
+/// Queue of registered providers that were not accepted automatically.
+/// Created together with the hub; the hub authority reviews entries with
+/// `add_accepted_provider` / `reject_pending_provider`, or clears several with `prune_provider_queue`.
+/// When full, a new registration evicts the oldest entry so registrations cannot lock the queue.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingProviderQueue {
+    pub hub: Pubkey,
+    pub updated_at: i64,
+    #[max_len(100)]
+    pub providers: Vec<PendingProvider>,
+}
+
+impl PendingProviderQueue {
+    pub const SEED_PREFIX: &'static str = "provider-queue";
+    pub const MAX_PENDING: usize = 100;
+
+    /// Queue a provider; returns the oldest entry when it had to be evicted to make room
+    pub fn enqueue(&mut self, wallet: Pubkey, timestamp: i64) -> Option<PendingProvider> {
+        if self.contains(&wallet) {
+            return None;
+        }
+        let evicted = if self.providers.len() >= Self::MAX_PENDING {
+            Some(self.providers.remove(0))
+        } else {
+            None
+        };
+        self.providers.push(PendingProvider {
+            wallet,
+            requested_at: timestamp,
+        });
+        self.updated_at = timestamp;
+        evicted
+    }
+
+    /// Remove a provider from the queue; returns whether it was queued
+    pub fn remove(&mut self, wallet: &Pubkey) -> Result<bool> {
+        let before = self.providers.len();
+        self.providers.retain(|p| &p.wallet != wallet);
+        let removed = before != self.providers.len();
+        if removed {
+            self.updated_at = Clock::get()?.unix_timestamp;
+        }
+        Ok(removed)
+    }
+
+    pub fn contains(&self, wallet: &Pubkey) -> bool {
+        self.providers.iter().any(|p| &p.wallet == wallet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_queue_evicts_oldest_entry() {
+        let mut queue = PendingProviderQueue {
+            hub: Pubkey::new_unique(),
+            updated_at: 0,
+            providers: Vec::new(),
+        };
+        let first = Pubkey::new_unique();
+        assert!(queue.enqueue(first, 0).is_none());
+        for i in 1..PendingProviderQueue::MAX_PENDING as i64 {
+            assert!(queue.enqueue(Pubkey::new_unique(), i).is_none());
+        }
+        assert!(queue.enqueue(first, 1_000).is_none());
+
+        let newest = Pubkey::new_unique();
+        let evicted = queue.enqueue(newest, 1_000).expect("oldest entry evicted");
+        assert_eq!(evicted.wallet, first);
+        assert_eq!(queue.providers.len(), PendingProviderQueue::MAX_PENDING);
+        assert!(!queue.contains(&first));
+        assert!(queue.contains(&newest));
+        assert_eq!(queue.updated_at, 1_000);
+    }
+}
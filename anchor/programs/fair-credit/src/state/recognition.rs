@@ -0,0 +1,60 @@
+use crate::types::HubError;
+use anchor_lang::prelude::*;
+
+/// What a recognition record covers in the source hub
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RecognitionScope {
+    /// A single course accepted in the source hub
+    Course,
+    /// Every course of a provider accepted in the source hub
+    Provider,
+}
+
+/// Transfer-credit style equivalence recorded by one hub for a course or provider of another hub.
+/// Credentials covered by a recognition can be verified against the recognising hub
+/// with `verify_recognized_credential`.
+#[account]
+#[derive(InitSpace)]
+pub struct HubRecognition {
+    /// Hub that recognises the subject
+    pub hub: Pubkey,
+    /// Hub in which the subject was accepted
+    pub source_hub: Pubkey,
+    pub scope: RecognitionScope,
+    /// Course PDA or Provider PDA in the source hub
+    pub subject: Pubkey,
+    /// Workload conversion factor in basis points (10_000 = 1:1); None keeps workload unchanged
+    pub workload_factor_bps: Option<u16>,
+    /// Hub authority that recorded the recognition
+    pub recognized_by: Pubkey,
+    pub recognized_at: i64,
+}
+
+impl HubRecognition {
+    pub const SEED_PREFIX: &'static str = "hub-recognition";
+    /// Conversion factor equal to a 1:1 workload equivalence
+    pub const BPS_DENOMINATOR: u16 = 10_000;
+    /// Largest accepted conversion factor (4x)
+    pub const MAX_WORKLOAD_FACTOR_BPS: u16 = 40_000;
+
+    pub fn validate_workload_factor(workload_factor_bps: Option<u16>) -> Result<()> {
+        if let Some(factor) = workload_factor_bps {
+            require!(
+                factor > 0 && factor <= Self::MAX_WORKLOAD_FACTOR_BPS,
+                HubError::InvalidWorkloadFactor
+            );
+        }
+        Ok(())
+    }
+
+    /// Workload credited in the recognising hub for `workload` earned in the source hub
+    pub fn converted_workload(&self, workload: u32) -> u32 {
+        match self.workload_factor_bps {
+            Some(factor) => {
+                let converted = workload as u64 * factor as u64 / Self::BPS_DENOMINATOR as u64;
+                converted.min(u32::MAX as u64) as u32
+            }
+            None => workload,
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::types::{ResourceError, ResourceKind, ResourceStatus, SubmissionStatus};
+use crate::state::RubricScore;
+use crate::types::{
+    LatePenaltyPolicy, ResourceError, ResourceKind, ResourceStatus, SubmissionStatus,
+};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -17,6 +20,16 @@ pub struct Resource {
     pub external_id: Option<String>,
     /// Estimated workload in minutes
     pub workload: Option<u32>,
+    /// Maximum number of attempts per submission (first submission included); None means unlimited
+    pub max_attempts: Option<u8>,
+    /// Assignment resources only: submissions are rejected before `opens_at` and after `closes_at`;
+    /// submissions after `due_at` are late and graded with `late_penalty`
+    pub opens_at: Option<i64>,
+    pub due_at: Option<i64>,
+    pub closes_at: Option<i64>,
+    pub late_penalty: Option<LatePenaltyPolicy>,
+    /// Rubric account (PDA) once `create_rubric` has run; grading then goes through the rubric
+    pub rubric: Option<Pubkey>,
     /// Course account (PDA) this resource belongs to
     pub course: Pubkey,
     /// Attached asset PDAs
@@ -63,10 +76,24 @@ pub struct Asset {
     pub owner: Pubkey,
 }
 
+/// Earlier attempt of a submission, archived on resubmission
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, InitSpace)]
+pub struct SubmissionAttempt {
+    pub attempt: u8,
+    pub submitted_at: i64,
+    pub late: bool,
+    pub grade: Option<f64>,
+    pub graded_by: Option<Pubkey>,
+    pub graded_at: Option<i64>,
+}
+
+/// A student's work on a resource, seeded by (resource, student) so there is one per student.
+/// Every attempt after the first is made with `resubmit_submission`, so `Resource.max_attempts`
+/// bounds all of a student's attempts on the resource.
 #[account]
 #[derive(InitSpace)]
 pub struct Submission {
-    /// Submission timestamp (also part of PDA seeds)
+    /// Submission timestamp of the first attempt
     pub submitted_at: i64,
     /// Last update timestamp
     pub updated: i64,
@@ -94,6 +121,18 @@ pub struct Submission {
     /// Optional Walrus blob identifier for the submission content bundle
     #[max_len(128)]
     pub walrus_blob_id: Option<String>,
+    /// Current attempt number, starting at 1
+    pub attempt: u8,
+    /// When the current attempt was submitted (`submitted_at` stays at the first attempt)
+    pub attempt_submitted_at: i64,
+    /// Most recent earlier attempts, oldest dropped first
+    #[max_len(5)]
+    pub attempts: Vec<SubmissionAttempt>,
+    /// Whether the current attempt was submitted after the resource's due date
+    pub late: bool,
+    /// Per-criterion breakdown when the current grade came from the resource's rubric
+    #[max_len(8)]
+    pub rubric_scores: Vec<RubricScore>,
 }
 
 impl Resource {
@@ -123,6 +162,59 @@ impl Resource {
     pub fn is_nostr_ref_set(&self) -> bool {
         self.nostr_d_tag.is_some() || self.nostr_author_pubkey != [0u8; 32]
     }
+
+    pub fn is_assignment(&self) -> bool {
+        matches!(
+            self.kind,
+            ResourceKind::Assignment | ResourceKind::AssignmentSummative
+        )
+    }
+
+    /// Set the submission window and late policy; each timestamp is optional but they must be ordered
+    pub fn set_deadlines(
+        &mut self,
+        opens_at: Option<i64>,
+        due_at: Option<i64>,
+        closes_at: Option<i64>,
+        late_penalty: Option<LatePenaltyPolicy>,
+    ) -> Result<()> {
+        require!(self.is_assignment(), ResourceError::DeadlinesNotSupported);
+        let ordered = |earlier: Option<i64>, later: Option<i64>| !matches!((earlier, later), (Some(a), Some(b)) if a > b);
+        require!(
+            ordered(opens_at, due_at) && ordered(due_at, closes_at) && ordered(opens_at, closes_at),
+            ResourceError::InvalidDeadlines
+        );
+        require!(
+            !matches!(late_penalty, Some(policy) if !policy.is_valid()),
+            ResourceError::InvalidLatePenalty
+        );
+        self.opens_at = opens_at;
+        self.due_at = due_at;
+        self.closes_at = closes_at;
+        self.late_penalty = late_penalty;
+        Ok(())
+    }
+
+    /// Check that a submission at `now` is inside the window; returns whether it is late
+    pub fn check_submission_window(&self, now: i64) -> Result<bool> {
+        require!(
+            !matches!(self.opens_at, Some(opens_at) if now < opens_at),
+            ResourceError::SubmissionWindowNotOpen
+        );
+        require!(
+            !matches!(self.closes_at, Some(closes_at) if now > closes_at),
+            ResourceError::SubmissionWindowClosed
+        );
+        Ok(matches!(self.due_at, Some(due_at) if now > due_at))
+    }
+
+    /// Late penalty (percent of the grade) for work submitted at `submitted_at`
+    pub fn late_penalty_percent(&self, submitted_at: i64) -> u8 {
+        match (self.due_at, self.late_penalty) {
+            (Some(due_at), Some(policy)) => policy.penalty_percent(submitted_at - due_at),
+            _ => 0,
+        }
+    }
 }
 
 impl Asset {
@@ -154,16 +246,28 @@ impl Asset {
 
 impl Submission {
     pub const SEED_PREFIX: &'static str = "submission";
+    pub const MAX_ATTEMPT_HISTORY: usize = 5;
 
+    /// Grade the current attempt; `late_penalty_percent` reduces the recorded grade
     pub fn grade_submission(
         &mut self,
         grade: f64,
         feedback: Option<String>,
         graded_by: Pubkey,
+        late_penalty_percent: u8,
     ) -> Result<()> {
         require_gte!(grade, 0.0, ResourceError::InvalidGrade);
         require!(grade <= 100.0, ResourceError::InvalidGrade);
-        self.grade = Some(grade);
+        require!(
+            matches!(
+                self.status,
+                SubmissionStatus::Submitted | SubmissionStatus::Graded
+            ),
+            ResourceError::InvalidSubmissionStatus
+        );
+        let penalty = late_penalty_percent.min(100) as f64;
+        self.grade = Some(grade * (100.0 - penalty) / 100.0);
+        self.rubric_scores = Vec::new();
         self.feedback = feedback;
         self.graded_by = Some(graded_by);
         self.graded_at = Some(Clock::get()?.unix_timestamp);
@@ -173,6 +277,13 @@ impl Submission {
     }
 
     pub fn return_for_revision(&mut self, feedback: String) -> Result<()> {
+        require!(
+            matches!(
+                self.status,
+                SubmissionStatus::Submitted | SubmissionStatus::Graded
+            ),
+            ResourceError::InvalidSubmissionStatus
+        );
         self.feedback = Some(feedback);
         self.updated = Clock::get()?.unix_timestamp;
         self.status = SubmissionStatus::Returned;
@@ -189,6 +300,51 @@ impl Submission {
         Ok(())
     }
 
+    /// Start a new attempt on a returned submission: archive the current attempt with its grade,
+    /// replace the assets and clear the grade. `max_attempts` comes from the resource.
+    pub fn resubmit(
+        &mut self,
+        assets: Vec<Pubkey>,
+        evidence_assets: Vec<Pubkey>,
+        max_attempts: Option<u8>,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.status == SubmissionStatus::Returned,
+            ResourceError::SubmissionNotReturned
+        );
+        require!(
+            !matches!(max_attempts, Some(max) if self.attempt >= max),
+            ResourceError::MaxAttemptsReached
+        );
+        require!(assets.len() <= 10, ResourceError::TooManyAssets);
+        require!(evidence_assets.len() <= 10, ResourceError::TooManyAssets);
+
+        if self.attempts.len() >= Self::MAX_ATTEMPT_HISTORY {
+            self.attempts.remove(0);
+        }
+        self.attempts.push(SubmissionAttempt {
+            attempt: self.attempt,
+            submitted_at: self.attempt_submitted_at,
+            late: self.late,
+            grade: self.grade,
+            graded_by: self.graded_by,
+            graded_at: self.graded_at,
+        });
+
+        self.attempt = self.attempt.saturating_add(1);
+        self.attempt_submitted_at = now;
+        self.assets = assets;
+        self.evidence_assets = evidence_assets;
+        self.grade = None;
+        self.graded_by = None;
+        self.graded_at = None;
+        self.rubric_scores = Vec::new();
+        self.status = SubmissionStatus::Submitted;
+        self.updated = now;
+        Ok(())
+    }
+
     pub fn add_asset(&mut self, asset: Pubkey) -> Result<()> {
         require!(self.assets.len() < 10, ResourceError::TooManyAssets);
         self.assets.push(asset);
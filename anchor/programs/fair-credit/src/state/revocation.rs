@@ -0,0 +1,91 @@
+use crate::types::{CredentialError, RevocationReason};
+use anchor_lang::prelude::*;
+
+/// One revoked credential in a course's revocation registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RevocationEntry {
+    pub credential: Pubkey,
+    pub student: Pubkey,
+    pub reason: RevocationReason,
+    /// Provider or hub authority wallet that revoked the credential
+    pub revoked_by: Pubkey,
+    pub revoked_at: i64,
+}
+
+/// Per-course log of the most recent revocations.
+/// Verifiers can fetch a single account instead of scanning every credential of the course;
+/// the credential's own `Revoked` status stays authoritative, so the registry is optional
+/// and never blocks a revocation. Once full, the oldest entry makes room for the newest.
+#[account]
+#[derive(InitSpace)]
+pub struct RevocationRegistry {
+    /// Course account (PDA) this registry belongs to
+    pub course: Pubkey,
+    pub created_at: i64,
+    pub updated_at: i64,
+    #[max_len(100)]
+    pub entries: Vec<RevocationEntry>,
+}
+
+impl RevocationRegistry {
+    /// Seed prefix for PDA generation
+    pub const SEED_PREFIX: &'static str = "revocation-registry";
+    pub const MAX_ENTRIES: usize = 100;
+
+    /// Record a revoked credential, dropping the oldest entry when the registry is full
+    pub fn add_entry(&mut self, entry: RevocationEntry) -> Result<()> {
+        require!(
+            !self.is_revoked(&entry.credential),
+            CredentialError::AlreadyRevoked
+        );
+        if self.entries.len() >= Self::MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.updated_at = entry.revoked_at;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Check if a credential is listed in the registry
+    pub fn is_revoked(&self, credential: &Pubkey) -> bool {
+        self.entries.iter().any(|e| &e.credential == credential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(revoked_at: i64) -> RevocationEntry {
+        RevocationEntry {
+            credential: Pubkey::new_unique(),
+            student: Pubkey::new_unique(),
+            reason: RevocationReason::IssuedInError,
+            revoked_by: Pubkey::new_unique(),
+            revoked_at,
+        }
+    }
+
+    #[test]
+    fn full_registry_drops_oldest_entry() {
+        let mut registry = RevocationRegistry {
+            course: Pubkey::new_unique(),
+            created_at: 0,
+            updated_at: 0,
+            entries: Vec::new(),
+        };
+        for i in 0..RevocationRegistry::MAX_ENTRIES as i64 {
+            registry.add_entry(entry(i)).unwrap();
+        }
+        let oldest = registry.entries[0].credential;
+        let newest = entry(1_000);
+        let newest_credential = newest.credential;
+
+        registry.add_entry(newest.clone()).unwrap();
+        assert_eq!(registry.entries.len(), RevocationRegistry::MAX_ENTRIES);
+        assert!(!registry.is_revoked(&oldest));
+        assert!(registry.is_revoked(&newest_credential));
+        assert_eq!(registry.updated_at, 1_000);
+        assert!(registry.add_entry(newest).is_err());
+    }
+}
@@ -0,0 +1,206 @@
+use crate::types::ResourceError;
+use anchor_lang::prelude::*;
+
+/// Score level of a rubric criterion, e.g. "Proficient" worth 3 points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct RubricLevel {
+    #[max_len(32)]
+    pub name: String,
+    pub points: u8,
+}
+
+/// Weighted rubric criterion; `weight` is the criterion's share of the grade in percent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct RubricCriterion {
+    #[max_len(64)]
+    pub name: String,
+    pub weight: u8,
+    #[max_len(5)]
+    pub levels: Vec<RubricLevel>,
+}
+
+impl RubricCriterion {
+    pub fn max_points(&self) -> u8 {
+        self.levels.iter().map(|l| l.points).max().unwrap_or(0)
+    }
+}
+
+/// Per-criterion result stored on a graded submission.
+/// Weight and points are copied from the rubric so the grade can be recomputed later.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct RubricScore {
+    pub level: u8,
+    pub points: u8,
+    pub max_points: u8,
+    pub weight: u8,
+}
+
+/// Rubric of a summative assignment resource (one per resource).
+/// Submissions of a resource with a rubric are graded with `grade_submission_with_rubric`.
+#[account]
+#[derive(InitSpace)]
+pub struct Rubric {
+    pub resource: Pubkey,
+    #[max_len(8)]
+    pub criteria: Vec<RubricCriterion>,
+    /// Submissions graded with this rubric; criteria are frozen once any grade exists
+    pub graded_count: u32,
+    pub created: i64,
+    pub updated: i64,
+}
+
+impl Rubric {
+    pub const SEED_PREFIX: &'static str = "rubric";
+    pub const MAX_CRITERIA: usize = 8;
+    pub const MAX_LEVELS: usize = 5;
+    /// Criterion weights must add up to this total (percent)
+    pub const TOTAL_WEIGHT: u16 = 100;
+
+    /// Replace the criteria after checking weights and score levels
+    pub fn set_criteria(&mut self, criteria: Vec<RubricCriterion>) -> Result<()> {
+        require!(
+            !criteria.is_empty() && criteria.len() <= Self::MAX_CRITERIA,
+            ResourceError::InvalidRubricCriteria
+        );
+        for criterion in &criteria {
+            require!(
+                criterion.weight > 0
+                    && !criterion.levels.is_empty()
+                    && criterion.levels.len() <= Self::MAX_LEVELS
+                    && criterion.max_points() > 0,
+                ResourceError::InvalidRubricCriteria
+            );
+        }
+        let total_weight: u16 = criteria.iter().map(|c| c.weight as u16).sum();
+        require!(
+            total_weight == Self::TOTAL_WEIGHT,
+            ResourceError::InvalidRubricWeights
+        );
+        self.criteria = criteria;
+        Ok(())
+    }
+
+    /// Whether any submission has been graded with this rubric
+    pub fn is_in_use(&self) -> bool {
+        self.graded_count > 0
+    }
+
+    /// Count a submission graded with this rubric
+    pub fn record_grade(&mut self) {
+        self.graded_count = self.graded_count.saturating_add(1);
+    }
+
+    /// Score one chosen level per criterion (in criterion order).
+    /// Each criterion contributes `weight * points / max_points`, rounded down to hundredths,
+    /// so the same levels always give the same grade (0-100).
+    pub fn score(&self, levels: &[u8]) -> Result<(f64, Vec<RubricScore>)> {
+        require!(
+            levels.len() == self.criteria.len(),
+            ResourceError::RubricScoreMismatch
+        );
+        let mut scores = Vec::with_capacity(levels.len());
+        let mut hundredths: u32 = 0;
+        for (criterion, &level) in self.criteria.iter().zip(levels) {
+            let chosen = criterion
+                .levels
+                .get(level as usize)
+                .ok_or(ResourceError::InvalidRubricLevel)?;
+            let max_points = criterion.max_points();
+            hundredths += criterion.weight as u32 * chosen.points as u32 * 100 / max_points as u32;
+            scores.push(RubricScore {
+                level,
+                points: chosen.points,
+                max_points,
+                weight: criterion.weight,
+            });
+        }
+        Ok((hundredths as f64 / 100.0, scores))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(name: &str, points: u8) -> RubricLevel {
+        RubricLevel {
+            name: name.to_string(),
+            points,
+        }
+    }
+
+    fn criterion(name: &str, weight: u8) -> RubricCriterion {
+        RubricCriterion {
+            name: name.to_string(),
+            weight,
+            levels: vec![
+                level("Missing", 0),
+                level("Partial", 2),
+                level("Complete", 3),
+            ],
+        }
+    }
+
+    fn empty_rubric() -> Rubric {
+        Rubric {
+            resource: Pubkey::new_unique(),
+            criteria: Vec::new(),
+            graded_count: 0,
+            created: 0,
+            updated: 0,
+        }
+    }
+
+    #[test]
+    fn rubric_weights_must_total_100() {
+        let mut rubric = empty_rubric();
+        assert!(rubric
+            .set_criteria(vec![criterion("Analysis", 60), criterion("Style", 30)])
+            .is_err());
+        assert!(rubric.set_criteria(Vec::new()).is_err());
+
+        let mut no_points = criterion("Style", 40);
+        no_points.levels = vec![level("Missing", 0)];
+        assert!(rubric
+            .set_criteria(vec![criterion("Analysis", 60), no_points])
+            .is_err());
+
+        rubric
+            .set_criteria(vec![criterion("Analysis", 60), criterion("Style", 40)])
+            .unwrap();
+        assert_eq!(rubric.criteria.len(), 2);
+    }
+
+    #[test]
+    fn rubric_score_is_weighted_and_deterministic() {
+        let mut rubric = empty_rubric();
+        rubric
+            .set_criteria(vec![criterion("Analysis", 60), criterion("Style", 40)])
+            .unwrap();
+
+        let (grade, scores) = rubric.score(&[2, 1]).unwrap();
+        // 60 * 3/3 + 40 * 2/3 = 86.666.. rounded down to hundredths
+        assert_eq!(grade, 86.66);
+        assert_eq!(scores[1].points, 2);
+        assert_eq!(scores[1].max_points, 3);
+        assert_eq!(rubric.score(&[2, 1]).unwrap().0, grade);
+
+        assert_eq!(rubric.score(&[2, 2]).unwrap().0, 100.0);
+        assert_eq!(rubric.score(&[0, 0]).unwrap().0, 0.0);
+        assert!(rubric.score(&[2]).is_err());
+        assert!(rubric.score(&[2, 3]).is_err());
+    }
+
+    #[test]
+    fn grading_locks_the_rubric() {
+        let mut rubric = empty_rubric();
+        rubric
+            .set_criteria(vec![criterion("Analysis", 60), criterion("Style", 40)])
+            .unwrap();
+        assert!(!rubric.is_in_use());
+
+        rubric.record_grade();
+        assert!(rubric.is_in_use());
+        assert_eq!(rubric.graded_count, 1);
+    }
+}
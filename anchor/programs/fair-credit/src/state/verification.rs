@@ -0,0 +1,48 @@
+use crate::types::{VerificationFrequency, VerificationStats};
+use anchor_lang::prelude::*;
+
+/// Single entry in the on-chain verification trail
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct VerificationEntry {
+    /// Verifier wallet (None for anonymous verifications)
+    pub verifier: Option<Pubkey>,
+    /// Verification timestamp
+    pub verified_at: i64,
+}
+
+/// Per-credential verification statistics.
+/// Created alongside the credential and updated by every `verify_credential` call.
+#[account]
+#[derive(InitSpace)]
+pub struct CredentialVerificationStats {
+    /// Credential account (PDA) these statistics belong to
+    pub credential: Pubkey,
+    /// Aggregated verification statistics
+    pub stats: VerificationStats,
+    /// Frequency bucket derived from the total verification count
+    pub frequency: VerificationFrequency,
+    /// Most recent verifications, oldest first
+    #[max_len(10)]
+    pub recent_verifications: Vec<VerificationEntry>,
+}
+
+impl CredentialVerificationStats {
+    /// Seed prefix for PDA generation
+    pub const SEED_PREFIX: &'static str = "verification-stats";
+    /// Maximum number of entries kept in the verification trail
+    pub const MAX_RECENT_VERIFICATIONS: usize = 10;
+
+    /// Record a verification and refresh the derived frequency bucket
+    pub fn record(&mut self, verifier: Option<Pubkey>, timestamp: i64) {
+        self.stats.update(verifier.is_none(), timestamp);
+        self.frequency = VerificationFrequency::from_count(self.stats.total_verifications);
+
+        if self.recent_verifications.len() >= Self::MAX_RECENT_VERIFICATIONS {
+            self.recent_verifications.remove(0);
+        }
+        self.recent_verifications.push(VerificationEntry {
+            verifier,
+            verified_at: timestamp,
+        });
+    }
+}
@@ -7,3 +7,42 @@ pub enum CourseStatus {
     Accepted,
     Archived,
 }
+
+/// Who may create enrollments for a course
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum EnrollmentMode {
+    /// Students enroll themselves
+    SelfEnroll,
+    /// Only the course provider enrolls students
+    ProviderEnroll,
+}
+
+/// Who requests a course status change
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CourseStatusActor {
+    /// Course provider via `update_course_status`
+    Provider,
+    /// Hub authority via hub course instructions
+    Hub,
+}
+
+impl CourseStatus {
+    /// Course status transition table:
+    /// - Draft -> InReview: provider submits the course for review
+    /// - InReview -> Accepted: hub accepts the course
+    /// - InReview -> Draft: hub rejects the course with a reason
+    /// - Accepted -> InReview: hub removes the course from its accepted list
+    /// - Accepted -> Archived: provider retires the course
+    pub fn can_transition(&self, to: &CourseStatus, actor: CourseStatusActor) -> bool {
+        use CourseStatus::*;
+        use CourseStatusActor::*;
+        matches!(
+            (self, to, actor),
+            (Draft, InReview, Provider)
+                | (InReview, Accepted, Hub)
+                | (InReview, Draft, Hub)
+                | (Accepted, InReview, Hub)
+                | (Accepted, Archived, Provider)
+        )
+    }
+}
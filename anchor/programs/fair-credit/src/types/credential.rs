@@ -25,16 +25,45 @@ pub struct CredentialMetadata {
     pub activities: Vec<Pubkey>,
 }
 
+/// Single endorser sign-off recorded on a credential
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Endorsement {
+    /// Endorser wallet
+    pub endorser: Pubkey,
+    /// Endorsement content
+    #[max_len(300)]
+    pub message: String,
+    /// Endorsement timestamp
+    pub timestamp: i64,
+}
+
 /// Credential status enumeration
 /// Tracks status changes throughout the credential's lifecycle
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum CredentialStatus {
     /// Pending - Credential created but not yet endorsed by mentor
     Pending,
-    /// Endorsed - Mentor has provided endorsement but NFT not yet minted
+    /// Endorsed - Course endorsement threshold reached but NFT not yet minted
     Endorsed,
     /// Minted - NFT successfully minted to student wallet
     Minted,
     /// Verified - Credential has been verified by third parties
     Verified,
+    /// Revoked - Credential withdrawn by the provider or hub authority
+    Revoked,
+}
+
+/// Structured reason recorded when a credential is revoked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub enum RevocationReason {
+    /// Academic misconduct discovered after issuance
+    AcademicMisconduct,
+    /// Credential was issued by mistake
+    IssuedInError,
+    /// Fraudulent claim or forged evidence
+    Fraud,
+    /// Replaced by a newer credential
+    Superseded,
+    /// Any other reason (details kept off-chain)
+    Other,
 }
@@ -6,6 +6,14 @@ pub enum ActivityError {
     TooManyAssets,
     #[msg("Creation timestamp must be within ±5 minutes of current time")]
     InvalidCreationTimestamp,
+    #[msg("Grade must be between 0 and 100")]
+    InvalidGrade,
+    #[msg("Course account does not match the activity's course")]
+    CourseMismatch,
+    #[msg("Only the provider or staff allowed to grade may grade")]
+    UnauthorizedGrader,
+    #[msg("Only the student or staff allowed to leave feedback may add feedback")]
+    UnauthorizedFeedback,
 }
 
 #[error_code]
@@ -30,16 +38,64 @@ pub enum CourseError {
     NostrRefAlreadySet,
     #[msg("Module not found")]
     ModuleNotFound,
+    #[msg("Validity period must be positive")]
+    InvalidValidityPeriod,
+    #[msg("Too many endorsers for course")]
+    TooManyEndorsers,
+    #[msg("Endorser must be registered with the provider first")]
+    EndorserNotRegistered,
+    #[msg("Endorsement threshold must be between 1 and 5")]
+    InvalidEndorsementThreshold,
+    #[msg("Course status transition is not allowed")]
+    IllegalStatusTransition,
+    #[msg("Rejecting a course requires a reason of at most 200 characters")]
+    RejectionReasonRequired,
+    #[msg("Resource does not belong to this course")]
+    ResourceNotInCourse,
+    #[msg("Resource is already a module of this course")]
+    DuplicateModule,
+    #[msg("Module weights cannot exceed 100% in total")]
+    ModuleWeightExceeded,
+    #[msg("Module weights must total exactly 100% to finalize")]
+    InvalidModuleWeightTotal,
+    #[msg("Course modules can only change while the course is in Draft")]
+    ModulesLocked,
+    #[msg("Course modules must be finalized before review")]
+    ModulesNotFinalized,
+    #[msg("Minimum grade must be between 0 and 100")]
+    InvalidMinGrade,
+    #[msg("Course requires a course progress account for this student")]
+    CourseProgressRequired,
+    #[msg("Student has not reached the course's minimum grade")]
+    MinimumGradeNotMet,
+    #[msg("Student has not completed the course's required workload")]
+    WorkloadNotCompleted,
+    #[msg("Module workload is below the course's required workload")]
+    InsufficientModuleWorkload,
+    #[msg("Course is not open for enrollment")]
+    EnrollmentClosed,
+    #[msg("Course enrollment capacity reached")]
+    EnrollmentFull,
+    #[msg("Enrollment capacity must be positive and cover current enrollments")]
+    InvalidEnrollmentCapacity,
+    #[msg("Enrollment window must open before it closes")]
+    InvalidEnrollmentWindow,
+    #[msg("A course cannot be its own prerequisite")]
+    InvalidPrerequisite,
+    #[msg("Prerequisite not found")]
+    PrerequisiteNotFound,
+    #[msg("Student does not hold a valid credential for every prerequisite course")]
+    PrerequisiteNotMet,
+    #[msg("Too many staff overrides for course")]
+    TooManyStaffOverrides,
+    #[msg("Staff override not found")]
+    StaffOverrideNotFound,
 }
 
 #[error_code]
 pub enum StudentError {
-    #[msg("Student already enrolled in this degree/course")]
-    AlreadyEnrolled,
     #[msg("Too many degrees for student")]
     TooManyDegrees,
-    #[msg("Too many courses for student")]
-    TooManyCourses,
     #[msg("Invalid email format")]
     InvalidEmail,
     #[msg("Too many skills")]
@@ -52,6 +108,10 @@ pub enum StudentError {
     TooManyCertifications,
     #[msg("Duplicate certification")]
     DuplicateCertification,
+    #[msg("Student is not enrolled in this course")]
+    NotEnrolled,
+    #[msg("Signer cannot manage enrollments for this course")]
+    EnrollmentNotAllowed,
 }
 
 #[error_code]
@@ -72,6 +132,42 @@ pub enum ResourceError {
     UnauthorizedResourceAuthority,
     #[msg("Nostr reference already set; use force=true to override")]
     NostrRefAlreadySet,
+    #[msg("Only the provider or staff allowed to grade may grade")]
+    UnauthorizedGrader,
+    #[msg("Submission is not in a state that allows this action")]
+    InvalidSubmissionStatus,
+    #[msg("Only returned submissions can be resubmitted")]
+    SubmissionNotReturned,
+    #[msg("Maximum number of attempts reached for this resource")]
+    MaxAttemptsReached,
+    #[msg("Maximum attempts must be at least 1")]
+    InvalidMaxAttempts,
+    #[msg("Deadlines can only be set on assignment resources")]
+    DeadlinesNotSupported,
+    #[msg("Resource must open before it is due and be due before it closes")]
+    InvalidDeadlines,
+    #[msg("Late penalty percentages must be at most 100")]
+    InvalidLatePenalty,
+    #[msg("Resource is not open for submissions yet")]
+    SubmissionWindowNotOpen,
+    #[msg("Resource no longer accepts submissions")]
+    SubmissionWindowClosed,
+    #[msg("Rubrics can only be defined for summative assignments")]
+    RubricNotSupported,
+    #[msg("Rubric needs 1-8 criteria, each with a positive weight and 1-5 levels worth points")]
+    InvalidRubricCriteria,
+    #[msg("Rubric criterion weights must add up to 100")]
+    InvalidRubricWeights,
+    #[msg("Exactly one score level is required per rubric criterion")]
+    RubricScoreMismatch,
+    #[msg("Score level does not exist for this rubric criterion")]
+    InvalidRubricLevel,
+    #[msg("Resource has a rubric; grade with grade_submission_with_rubric")]
+    RubricGradingRequired,
+    #[msg("A course module's workload can only change while the course is a draft")]
+    ModuleWorkloadLocked,
+    #[msg("Rubric cannot change once a submission has been graded with it")]
+    RubricInUse,
 }
 
 #[error_code]
@@ -102,6 +198,42 @@ pub enum CollegeError {
     FacultyCourseAlreadyExists,
     #[msg("Too many qualifications")]
     TooManyQualifications,
+    #[msg("Degree id must be 1-32 bytes")]
+    InvalidDegreeId,
+    #[msg("Too many elective pools")]
+    TooManyElectivePools,
+    #[msg("Elective pool minimum must be between 1 and 10 courses")]
+    InvalidElectiveMinimum,
+    #[msg("Elective pool not found")]
+    ElectivePoolNotFound,
+    #[msg("Course is not part of this degree")]
+    DegreeCourseNotFound,
+    #[msg("Degree credentials must be passed as (credential, course) pairs of valid credentials held by the student")]
+    InvalidDegreeCredential,
+    #[msg("A required degree course has not been completed")]
+    RequiredCourseMissing,
+    #[msg("An elective pool's minimum course count has not been met")]
+    ElectivePoolNotSatisfied,
+    #[msg("Completed degree courses do not cover the degree workload")]
+    DegreeWorkloadNotMet,
+    #[msg("Degree name must be at most 64 bytes")]
+    DegreeNameTooLong,
+    #[msg("Degree description must be at most 256 bytes")]
+    DegreeDescriptionTooLong,
+    #[msg("Elective pool name must be at most 32 bytes")]
+    ElectivePoolNameTooLong,
+    #[msg("Degree is published; its structure can no longer change")]
+    DegreeAlreadyPublished,
+    #[msg("Degree is not published")]
+    DegreeNotPublished,
+    #[msg("Degree needs at least one required course or elective pool")]
+    DegreeEmpty,
+    #[msg("An elective pool has fewer courses than its minimum")]
+    ElectivePoolTooSmall,
+    #[msg("Degree credential already revoked")]
+    DegreeCredentialAlreadyRevoked,
+    #[msg("Course credential is not part of this degree credential or has not been revoked")]
+    CourseCredentialNotRevoked,
 }
 
 #[error_code]
@@ -118,6 +250,12 @@ pub enum ProviderError {
     NoteTooLong,
     #[msg("Invalid reputation score (must be 0-100)")]
     InvalidReputationScore,
+    #[msg("Provider is suspended")]
+    ProviderSuspended,
+    #[msg("Too many staff members")]
+    TooManyStaff,
+    #[msg("Staff member not found")]
+    StaffNotFound,
 }
 
 #[error_code]
@@ -136,6 +274,36 @@ pub enum CredentialError {
     ActivityNotOwnedByStudent,
     #[msg("Activity already linked to this credential")]
     ActivityAlreadyLinked,
+    #[msg("Credential must be approved by the provider before it can be verified")]
+    NotIssued,
+    #[msg("Credential has already been revoked")]
+    AlreadyRevoked,
+    #[msg("Only the provider or hub authority can revoke this credential")]
+    UnauthorizedRevocation,
+    #[msg("NFT mint, token account and token program are required when the credential has an NFT")]
+    MissingNftAccounts,
+    #[msg("NFT accounts do not match the credential")]
+    NftAccountMismatch,
+    #[msg("Credential validity period has passed")]
+    Expired,
+    #[msg("Only issued credentials with a validity window can be renewed")]
+    NotRenewable,
+    #[msg("Credential can only be renewed within 30 days of its expiry or after it")]
+    RenewalNotOpen,
+    #[msg("Signer is not an endorser of this credential's provider")]
+    EndorserNotAllowed,
+    #[msg("Endorser is not allowed to endorse credentials for this course")]
+    EndorserNotAllowedForCourse,
+    #[msg("Students cannot endorse their own credential")]
+    SelfEndorsement,
+    #[msg("Endorser has already endorsed this credential")]
+    AlreadyEndorsedBy,
+    #[msg("Credential is no longer collecting endorsements")]
+    EndorsementClosed,
+    #[msg("Too many endorsements on this credential")]
+    TooManyEndorsements,
+    #[msg("Endorsement message too long")]
+    EndorsementMessageTooLong,
 }
 
 #[error_code]
@@ -160,4 +328,50 @@ pub enum HubError {
     CourseListFull,
     #[msg("Course list reference required")]
     CourseListReferenceRequired,
+    #[msg("Provider is not in the pending queue")]
+    ProviderNotPending,
+    #[msg("Provider does not meet the hub's automatic acceptance criteria")]
+    ProviderApprovalRequired,
+    #[msg("Proposed authority must differ from the current authority")]
+    InvalidPendingAuthority,
+    #[msg("Signer is not the pending hub authority")]
+    NotPendingAuthority,
+    #[msg("No hub authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("Hub id must be 1-32 bytes")]
+    InvalidHubId,
+    #[msg("Course was not created under this hub")]
+    CourseNotInHub,
+    #[msg("Recognition must reference a different source hub")]
+    RecognitionSameHub,
+    #[msg("Workload conversion factor must be between 1 and 40000 basis points")]
+    InvalidWorkloadFactor,
+    #[msg("Credential is not covered by this recognition")]
+    CredentialNotRecognized,
+    #[msg("Course review queue is full")]
+    CourseReviewQueueFull,
+}
+
+#[error_code]
+pub enum GovernanceError {
+    #[msg("Signer is not a governance member")]
+    NotMember,
+    #[msg("Governance needs between 1 and 10 members")]
+    InvalidMemberCount,
+    #[msg("Governance member listed twice")]
+    DuplicateMember,
+    #[msg("Threshold must be between 1 and the number of members")]
+    InvalidThreshold,
+    #[msg("Member has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal is not open")]
+    ProposalNotOpen,
+    #[msg("Proposal has not reached the approval threshold")]
+    ThresholdNotReached,
+    #[msg("Only the proposer can cancel this proposal")]
+    NotProposer,
+    #[msg("Account required by the proposal action is missing")]
+    MissingActionAccount,
+    #[msg("Account does not match the proposal action")]
+    ActionAccountMismatch,
 }
@@ -4,7 +4,18 @@ use anchor_lang::prelude::*;
 
 /// Trust level enumeration
 /// Based on provider's historical performance and reputation score
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Debug,
+    InitSpace,
+)]
 pub enum TrustLevel {
     /// New institution - Recently registered or few credentials issued
     New,
@@ -16,4 +27,212 @@ pub enum TrustLevel {
     High,
     /// Excellent - Highest level of trust
     Excellent,
-} 
\ No newline at end of file
+} 
+
+/// On-chain facts a provider's trust level is derived from
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TrustInputs {
+    /// Seconds since the provider registered
+    pub age_seconds: i64,
+    /// Credentials approved across the provider's courses
+    pub approved_credentials: u32,
+    /// Credentials revoked across the provider's courses
+    pub revoked_credentials: u32,
+    /// Number of hub suspensions so far
+    pub suspension_count: u32,
+    /// Whether the provider is currently suspended
+    pub suspended: bool,
+    /// Hub-assigned reputation score (0-100)
+    pub reputation_score: u64,
+}
+
+/// Minimum requirements for one trust level
+struct TrustRequirement {
+    level: TrustLevel,
+    min_age_days: i64,
+    min_approved: u32,
+    min_reputation: u64,
+    /// Maximum share of approved credentials that were later revoked (percent)
+    max_revocation_pct: u32,
+    max_suspensions: u32,
+}
+
+/// Highest level first; a provider gets the first level whose requirements it meets
+const TRUST_REQUIREMENTS: [TrustRequirement; 4] = [
+    TrustRequirement {
+        level: TrustLevel::Excellent,
+        min_age_days: 365,
+        min_approved: 100,
+        min_reputation: 90,
+        max_revocation_pct: 1,
+        max_suspensions: 0,
+    },
+    TrustRequirement {
+        level: TrustLevel::High,
+        min_age_days: 180,
+        min_approved: 50,
+        min_reputation: 75,
+        max_revocation_pct: 2,
+        max_suspensions: 1,
+    },
+    TrustRequirement {
+        level: TrustLevel::Good,
+        min_age_days: 90,
+        min_approved: 20,
+        min_reputation: 60,
+        max_revocation_pct: 5,
+        max_suspensions: 2,
+    },
+    TrustRequirement {
+        level: TrustLevel::Fair,
+        min_age_days: 30,
+        min_approved: 5,
+        min_reputation: 40,
+        max_revocation_pct: 10,
+        max_suspensions: u32::MAX,
+    },
+];
+
+impl TrustLevel {
+    /// Derive the trust level from on-chain facts.
+    /// A currently suspended provider is capped at `Fair`.
+    pub fn compute(inputs: &TrustInputs) -> Self {
+        let age_days = inputs.age_seconds.max(0) / 86_400;
+        let revoked = inputs.revoked_credentials as u64;
+        let approved = inputs.approved_credentials as u64;
+
+        let level = TRUST_REQUIREMENTS
+            .iter()
+            .find(|req| {
+                age_days >= req.min_age_days
+                    && inputs.approved_credentials >= req.min_approved
+                    && inputs.reputation_score >= req.min_reputation
+                    && revoked * 100 <= approved * req.max_revocation_pct as u64
+                    && inputs.suspension_count <= req.max_suspensions
+            })
+            .map(|req| req.level)
+            .unwrap_or(TrustLevel::New);
+
+        if inputs.suspended {
+            level.min(TrustLevel::Fair)
+        } else {
+            level
+        }
+    }
+}
+
+/// Teaching staff role a provider assigns to a wallet, optionally overridden per course
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum StaffRole {
+    /// Grades activities and submissions and leaves feedback
+    Instructor,
+    /// Leaves feedback on activities
+    TeachingAssistant,
+    /// Endorses credentials
+    Reviewer,
+    /// Manages course settings, modules and resources
+    Admin,
+}
+
+/// Action a staff role may be allowed to perform
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StaffCapability {
+    Grade,
+    Feedback,
+    Endorse,
+    ManageCourse,
+}
+
+impl StaffRole {
+    pub fn allows(&self, capability: StaffCapability) -> bool {
+        use StaffCapability::*;
+        use StaffRole::*;
+        matches!(
+            (self, capability),
+            (Instructor, Grade | Feedback)
+                | (TeachingAssistant, Feedback)
+                | (Reviewer, Endorse)
+                | (Admin, ManageCourse)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+
+    fn established() -> TrustInputs {
+        TrustInputs {
+            age_seconds: 400 * DAY,
+            approved_credentials: 200,
+            revoked_credentials: 1,
+            suspension_count: 0,
+            suspended: false,
+            reputation_score: 95,
+        }
+    }
+
+    #[test]
+    fn new_provider_is_new() {
+        assert_eq!(TrustLevel::compute(&TrustInputs::default()), TrustLevel::New);
+    }
+
+    #[test]
+    fn established_provider_is_excellent() {
+        assert_eq!(TrustLevel::compute(&established()), TrustLevel::Excellent);
+    }
+
+    #[test]
+    fn each_fact_lowers_the_level() {
+        let young = TrustInputs {
+            age_seconds: 100 * DAY,
+            ..established()
+        };
+        assert_eq!(TrustLevel::compute(&young), TrustLevel::Good);
+
+        let low_reputation = TrustInputs {
+            reputation_score: 45,
+            ..established()
+        };
+        assert_eq!(TrustLevel::compute(&low_reputation), TrustLevel::Fair);
+
+        let many_revocations = TrustInputs {
+            revoked_credentials: 30,
+            ..established()
+        };
+        assert_eq!(TrustLevel::compute(&many_revocations), TrustLevel::New);
+
+        let suspended_before = TrustInputs {
+            suspension_count: 1,
+            ..established()
+        };
+        assert_eq!(TrustLevel::compute(&suspended_before), TrustLevel::High);
+    }
+
+    #[test]
+    fn current_suspension_caps_at_fair() {
+        let suspended = TrustInputs {
+            suspended: true,
+            suspension_count: 1,
+            ..established()
+        };
+        assert_eq!(TrustLevel::compute(&suspended), TrustLevel::Fair);
+    }
+
+    #[test]
+    fn staff_roles_map_to_capabilities() {
+        use StaffCapability::*;
+
+        assert!(StaffRole::Instructor.allows(Grade));
+        assert!(StaffRole::Instructor.allows(Feedback));
+        assert!(!StaffRole::Instructor.allows(ManageCourse));
+        assert!(StaffRole::TeachingAssistant.allows(Feedback));
+        assert!(!StaffRole::TeachingAssistant.allows(Grade));
+        assert!(StaffRole::Reviewer.allows(Endorse));
+        assert!(!StaffRole::Reviewer.allows(Grade));
+        assert!(StaffRole::Admin.allows(ManageCourse));
+        assert!(!StaffRole::Admin.allows(Endorse));
+    }
+}
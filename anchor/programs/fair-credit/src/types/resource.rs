@@ -15,4 +15,30 @@ pub enum SubmissionStatus {
     Graded,
     Returned,
     Accepted,
-} 
\ No newline at end of file
+}
+
+/// Late-penalty policy for assignment resources: the grade is reduced by `percent_per_day`
+/// for each started day past the due date, capped at `max_percent`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct LatePenaltyPolicy {
+    pub percent_per_day: u8,
+    pub max_percent: u8,
+}
+
+impl LatePenaltyPolicy {
+    const DAY: i64 = 86_400;
+
+    pub fn is_valid(&self) -> bool {
+        self.percent_per_day <= 100 && self.max_percent <= 100
+    }
+
+    /// Penalty (percent of the grade) for work handed in `late_by` seconds after the due date
+    pub fn penalty_percent(&self, late_by: i64) -> u8 {
+        if late_by <= 0 {
+            return 0;
+        }
+        let days_started = (late_by + Self::DAY - 1) / Self::DAY;
+        let penalty = days_started.saturating_mul(self.percent_per_day as i64);
+        penalty.min(self.max_percent.min(100) as i64) as u8
+    }
+}
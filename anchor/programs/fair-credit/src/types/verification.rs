@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 /// Verification frequency enumeration
 /// Categorizes credential attention level based on verification count
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, InitSpace)]
 pub enum VerificationFrequency {
     /// First verification
     First,
@@ -16,9 +16,22 @@ pub enum VerificationFrequency {
     VeryHigh,
 }
 
+impl VerificationFrequency {
+    /// Derive the frequency bucket from a total verification count
+    pub fn from_count(total_verifications: u64) -> Self {
+        match total_verifications {
+            0 | 1 => Self::First,
+            2..=5 => Self::Low,
+            6..=20 => Self::Medium,
+            21..=100 => Self::High,
+            _ => Self::VeryHigh,
+        }
+    }
+}
+
 /// Verification statistics data structure
 /// Used for aggregating and analyzing verification data
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct VerificationStats {
     /// Total verification count
     pub total_verifications: u64,